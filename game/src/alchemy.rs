@@ -0,0 +1,183 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::{
+    rng::game_rng,
+    tr,
+    {items::ConsumableItem, session::PlayerCharacter},
+};
+
+/// Chance for a normal enemy or a treasure chest to drop an ingredient.
+pub const INGREDIENT_DROP_RATE: f64 = 0.50;
+pub const INGREDIENT_AMOUNT_BOSS_ENEMY: u32 = 2;
+pub const INGREDIENT_AMOUNT_ANCIENT_ENEMY: u32 = 3;
+
+pub const RECIPE_ELIXIR_OF_STRENGTH: Recipe = Recipe {
+    name: "item.elixir_of_strength.name",
+    ingredients: &[(Ingredient::Bloodroot, 2), (Ingredient::FireSalt, 1)],
+    create_item: ConsumableItem::new_elixir_of_strength,
+};
+pub const RECIPE_ANTIDOTE: Recipe = Recipe {
+    name: "item.antidote.name",
+    ingredients: &[(Ingredient::Moonpetal, 2), (Ingredient::SpiderVenom, 1)],
+    create_item: ConsumableItem::new_antidote,
+};
+pub const RECIPE_FIRE_BOMB: Recipe = Recipe {
+    name: "item.fire_bomb.name",
+    ingredients: &[(Ingredient::FireSalt, 2), (Ingredient::SpiderVenom, 1)],
+    create_item: ConsumableItem::new_fire_bomb,
+};
+pub const RECIPE_RESURRECTION_DRAUGHT: Recipe = Recipe {
+    name: "item.resurrection_draught.name",
+    ingredients: &[
+        (Ingredient::PhoenixFeather, 1),
+        (Ingredient::Moonpetal, 2),
+        (Ingredient::Bloodroot, 2),
+    ],
+    create_item: ConsumableItem::new_resurrection_draught,
+};
+pub const RECIPES: [Recipe; 4] = [
+    RECIPE_ELIXIR_OF_STRENGTH,
+    RECIPE_ANTIDOTE,
+    RECIPE_FIRE_BOMB,
+    RECIPE_RESURRECTION_DRAUGHT,
+];
+
+/// Alchemy ingredient dropped by enemies and treasure chests.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ingredient {
+    Bloodroot,
+    Moonpetal,
+    FireSalt,
+    SpiderVenom,
+    PhoenixFeather,
+}
+
+impl fmt::Display for Ingredient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Bloodroot => tr!("ingredient.bloodroot"),
+                Self::Moonpetal => tr!("ingredient.moonpetal"),
+                Self::FireSalt => tr!("ingredient.fire_salt"),
+                Self::SpiderVenom => tr!("ingredient.spider_venom"),
+                Self::PhoenixFeather => tr!("ingredient.phoenix_feather"),
+            }
+        )
+    }
+}
+
+/// Recipe that combines ingredients into a consumable item.
+pub struct Recipe {
+    /// Message ID of the recipe name.
+    pub name: &'static str,
+    /// Ingredients and the required amounts.
+    pub ingredients: &'static [(Ingredient, u32)],
+    pub create_item: fn() -> ConsumableItem,
+}
+
+impl Recipe {
+    pub fn can_brew(&self, character: &PlayerCharacter) -> bool {
+        self.ingredients
+            .iter()
+            .all(|(ingredient, amount)| character.get_ingredient_amount(ingredient) >= *amount)
+    }
+
+    /// Consumes the ingredients and gives the brewed item.
+    /// Returns the brewed item if the player had enough ingredients.
+    pub fn brew(&self, character: &mut PlayerCharacter) -> Option<ConsumableItem> {
+        if !self.can_brew(character) {
+            return None;
+        }
+        for (ingredient, amount) in self.ingredients {
+            character.consume_ingredient(ingredient, *amount);
+        }
+        let item = (self.create_item)();
+        character.give_consumable(&item, 1);
+        Some(item)
+    }
+}
+
+/// Phoenix Feather is rarer than the other ingredients.
+pub fn random_ingredient() -> Ingredient {
+    let mut rng = game_rng();
+    let rand_num = rng.gen_range(0..=8);
+    match rand_num {
+        0 | 1 => Ingredient::Bloodroot,
+        2 | 3 => Ingredient::Moonpetal,
+        4 | 5 => Ingredient::FireSalt,
+        6 | 7 => Ingredient::SpiderVenom,
+        _ => Ingredient::PhoenixFeather,
+    }
+}
+
+/// Gives random ingredients to the character.
+/// Returns the given ingredients and their amounts.
+pub fn give_random_ingredients(
+    character: &mut PlayerCharacter,
+    amount: u32,
+) -> Vec<(Ingredient, u32)> {
+    let mut ingredients: Vec<(Ingredient, u32)> = Vec::new();
+    for _ in 0..amount {
+        let ingredient = random_ingredient();
+        character.give_ingredient(ingredient, 1);
+        match ingredients
+            .iter_mut()
+            .find(|(given, _)| *given == ingredient)
+        {
+            Some((_, given_amount)) => *given_amount += 1,
+            None => ingredients.push((ingredient, 1)),
+        }
+    }
+    ingredients
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{character::CharacterClass, test_util::test_character};
+
+    #[test]
+    fn brew_consumes_ingredients() {
+        let mut character = test_character(CharacterClass::Mage);
+        character.give_ingredient(Ingredient::Bloodroot, 3);
+        character.give_ingredient(Ingredient::FireSalt, 1);
+
+        assert!(RECIPE_ELIXIR_OF_STRENGTH.brew(&mut character).is_some());
+        assert_eq!(character.get_ingredient_amount(&Ingredient::Bloodroot), 1);
+        assert_eq!(character.get_ingredient_amount(&Ingredient::FireSalt), 0);
+        assert!(!character
+            .data
+            .inventory
+            .ingredients
+            .contains_key(&Ingredient::FireSalt));
+        let consumables: Vec<&ConsumableItem> =
+            character.data.inventory.consumables.values().collect();
+        assert_eq!(consumables.len(), 1);
+        assert_eq!(consumables[0].amount_in_inventory, 1);
+    }
+
+    #[test]
+    fn brew_without_enough_ingredients_consumes_nothing() {
+        let mut character = test_character(CharacterClass::Mage);
+        character.give_ingredient(Ingredient::FireSalt, 2);
+
+        assert!(!RECIPE_FIRE_BOMB.can_brew(&character));
+        assert!(RECIPE_FIRE_BOMB.brew(&mut character).is_none());
+        assert_eq!(character.get_ingredient_amount(&Ingredient::FireSalt), 2);
+        assert!(character.data.inventory.consumables.is_empty());
+    }
+
+    #[test]
+    fn random_ingredients_add_up_to_amount() {
+        let mut character = test_character(CharacterClass::Mage);
+        let given = give_random_ingredients(&mut character, 10);
+        assert_eq!(given.iter().map(|(_, amount)| amount).sum::<u32>(), 10);
+        for (ingredient, amount) in given {
+            assert_eq!(character.get_ingredient_amount(&ingredient), amount);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use chrono::Utc;
+
+use crate::{
+    enemy::{generate_random_boss_enemy, generate_random_normal_enemy, Enemy},
+    fight::FightOptions,
+    game_data::ArenaScore,
+    session::PlayerCharacter,
+};
+
+/// Every wave that is a multiple of this is a boss wave.
+pub const ARENA_BOSS_WAVE_INTERVAL: u32 = 5;
+pub const ARENA_POINTS_PER_WAVE: u32 = 100;
+/// Extra points for surviving a boss wave.
+pub const ARENA_POINTS_PER_BOSS_WAVE: u32 = 400;
+
+/// Arena fights don't heal the player, give rewards or kill the character.
+pub const ARENA_FIGHT_OPTIONS: FightOptions = FightOptions {
+    restore_at_start: false,
+    rewards: false,
+    character_death: false,
+};
+
+pub fn is_boss_wave(wave: u32) -> bool {
+    wave.is_multiple_of(ARENA_BOSS_WAVE_INTERVAL)
+}
+
+/// Enemies get one level stronger after every boss wave.
+/// The first waves have the level of the character's current dungeon floor.
+pub fn get_arena_enemy_level(wave: u32, dungeon_floor: u32) -> u32 {
+    dungeon_floor + wave.saturating_sub(1) / ARENA_BOSS_WAVE_INTERVAL
+}
+
+pub fn generate_arena_enemy(wave: u32, dungeon_floor: u32) -> Enemy {
+    let level = get_arena_enemy_level(wave, dungeon_floor);
+    if is_boss_wave(wave) {
+        generate_random_boss_enemy(level)
+    } else {
+        generate_random_normal_enemy(level)
+    }
+}
+
+pub fn get_arena_score(waves_survived: u32) -> u32 {
+    ARENA_POINTS_PER_WAVE * waves_survived
+        + ARENA_POINTS_PER_BOSS_WAVE * (waves_survived / ARENA_BOSS_WAVE_INTERVAL)
+}
+
+pub fn create_arena_score(character: &PlayerCharacter, waves_survived: u32) -> ArenaScore {
+    ArenaScore {
+        character_name: character.data.metadata.name.clone(),
+        class: character.data.metadata.class.clone(),
+        character_level: character.data.stats.general_stats.character_level,
+        waves_survived,
+        score: get_arena_score(waves_survived),
+        recorded_at: Utc::now().timestamp(),
+    }
+}
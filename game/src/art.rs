@@ -0,0 +1,223 @@
+use std::io;
+
+use crate::{
+    character::CharacterClass,
+    enemy::{
+        ANCIENT_ENEMY_NAME_KNIGHT, ANCIENT_ENEMY_NAME_MAGE, BOSS_ENEMY_NAME_FIRE_DRAGON,
+        BOSS_ENEMY_NAME_OGRE_KING, BOSS_ENEMY_NAME_UNDEAD_SORCERER, NORMAL_ENEMY_NAME_GOBLIN,
+        NORMAL_ENEMY_NAME_GOLEM, NORMAL_ENEMY_NAME_OGRE, NORMAL_ENEMY_NAME_SKELETON,
+    },
+    frontend::{move_to, size},
+    print_text,
+};
+
+/// ASCII art shown in menus. Each line is one row.
+pub type Art = &'static [&'static str];
+
+/// Columns between the art and the text on its left.
+pub const ART_MARGIN: u16 = 4;
+
+/// Title of the game shown in the main menu.
+pub const ART_TITLE: Art = &[
+    "||||||||  ||||||  |||||    |||     |||  ||  |||   ||   ||||||   ||           |||||    ||||||    ||||||  ",
+    "   ||     ||      ||   ||  ||||| |||||  ||  ||||  ||  ||    ||  ||           ||   ||  ||   ||  ||       ",
+    "   ||     ||||||  |||||    ||  |||  ||  ||  || || ||  ||||||||  ||           |||||    ||||||   ||  |||  ",
+    "   ||     ||      ||   ||  ||       ||  ||  ||  ||||  ||    ||  ||           ||   ||  ||       ||    || ",
+    "   ||     ||||||  ||   ||  ||       ||  ||  ||   |||  ||    ||  ||||||       ||   ||  ||        ||||||",
+];
+
+// Enemy portraits are at most 8 rows so they fit next to the fighters' stats.
+
+pub const ART_SKELETON: Art = &[
+    r"    .-.",
+    r"   (o.o)",
+    r"    |=|",
+    r"   __|__",
+    r"  /.=|=.\",
+    r" / .=|=. \",
+    r"   (_=_)",
+    r"   /   \",
+];
+
+pub const ART_GOBLIN: Art = &[
+    r" \        /",
+    r"  \.-''-./",
+    r"  / o  o \",
+    r" (   __   )",
+    r"  \ \__/ /",
+    r"   '----'",
+    r"   /|  |\",
+    r"  / |  | \",
+];
+
+pub const ART_OGRE: Art = &[
+    r"    ______",
+    r"   /      \",
+    r"  | O    O |",
+    r"  |   /\   |",
+    r"  |  ====  |",
+    r"   \______/",
+    r"  /|      |\",
+    r" / |______| \",
+];
+
+pub const ART_GOLEM: Art = &[
+    r"   [######]",
+    r"   [ o  o ]",
+    r"   [  __  ]",
+    r" [##########]",
+    r" [#|######|#]",
+    r" [#|######|#]",
+    r"   [##][##]",
+    r"   [##][##]",
+];
+
+pub const ART_OGRE_KING: Art = &[
+    r"   _/\/\/\_",
+    r"  |        |",
+    r"  | O    O |",
+    r"  |   /\   |",
+    r"  |  ====  |",
+    r"   \______/",
+    r"  /|      |\",
+    r" / |______| \",
+];
+
+pub const ART_FIRE_DRAGON: Art = &[
+    r"       /\    /\",
+    r"      /  \__/  \",
+    r" ~~~ <  o    o  >",
+    r"~~~~  \   vv   /",
+    r" ~~~   \______/",
+    r"      /|/    \|\",
+    r"     /_/      \_\",
+];
+
+pub const ART_UNDEAD_SORCERER: Art = &[
+    r"      /\",
+    r"     /  \",
+    r"    / ** \",
+    r"   (  --  )",
+    r"   /|\  /|\",
+    r"  / | \/ | \*",
+    r"    |    |",
+    r"   /______\",
+];
+
+pub const ART_ANCIENT_KNIGHT: Art = &[
+    r"     ,^,",
+    r"    [=|=]",
+    r"    [ - ]",
+    r"  __/| |\__",
+    r" |  |=+=|  |",
+    r" |  | | |  |",
+    r"    /   \",
+    r"   /_| |_\",
+];
+
+pub const ART_ANCIENT_MAGE: Art = &[
+    r"       *",
+    r"      /^\",
+    r"     /   \",
+    r"    /_____\",
+    r"    ( o o )",
+    r"    /|~~~|\",
+    r"   / | ~ | \",
+    r"     /___\",
+];
+
+pub const ART_MAGE: Art = &[
+    r"    /\",
+    r"   /__\",
+    r"   (oo)",
+    r"  /|~~|\*",
+    r"   |__|",
+    r"   /  \",
+];
+
+pub const ART_CLERIC: Art = &[
+    r"   _+_",
+    r"   (oo)",
+    r"  /|++|\",
+    r"   |  |o",
+    r"   |__|",
+    r"   /  \",
+];
+
+pub const ART_ASSASSIN: Art = &[
+    r"   .--.",
+    r"  (-==-)",
+    r"  /|  |\",
+    r" / |  | >-",
+    r"   |__|",
+    r"   /  \",
+];
+
+pub const ART_WARRIOR: Art = &[
+    r"   ____",
+    r"   [oo]",
+    r"  /|##|\",
+    r" / |##| \|",
+    r"   |__|",
+    r"   /  \",
+];
+
+pub const ART_KNIGHT: Art = &[
+    r"   _||_",
+    r"   [==]",
+    r"  /|++|\",
+    r" [#|++| |",
+    r"   |__|",
+    r"   /  \",
+];
+
+/// Returns the portrait of the enemy with the name.
+/// Returns None if the enemy doesn't have one.
+pub fn get_enemy_art(name: &str) -> Option<Art> {
+    match name {
+        NORMAL_ENEMY_NAME_SKELETON => Some(ART_SKELETON),
+        NORMAL_ENEMY_NAME_GOBLIN => Some(ART_GOBLIN),
+        NORMAL_ENEMY_NAME_OGRE => Some(ART_OGRE),
+        NORMAL_ENEMY_NAME_GOLEM => Some(ART_GOLEM),
+        BOSS_ENEMY_NAME_OGRE_KING => Some(ART_OGRE_KING),
+        BOSS_ENEMY_NAME_FIRE_DRAGON => Some(ART_FIRE_DRAGON),
+        BOSS_ENEMY_NAME_UNDEAD_SORCERER => Some(ART_UNDEAD_SORCERER),
+        ANCIENT_ENEMY_NAME_KNIGHT => Some(ART_ANCIENT_KNIGHT),
+        ANCIENT_ENEMY_NAME_MAGE => Some(ART_ANCIENT_MAGE),
+        _ => None,
+    }
+}
+
+pub fn get_class_art(class: &CharacterClass) -> Art {
+    match class {
+        CharacterClass::Mage => ART_MAGE,
+        CharacterClass::Cleric => ART_CLERIC,
+        CharacterClass::Assassin => ART_ASSASSIN,
+        CharacterClass::Warrior => ART_WARRIOR,
+        CharacterClass::Knight => ART_KNIGHT,
+    }
+}
+
+/// Width of the widest line.
+pub fn get_art_width(art: Art) -> usize {
+    art.iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Draws the art with its top left corner at the row and column.
+/// Nothing is drawn if the art doesn't fit the terminal. Returns true if the art was drawn.
+pub fn display_art(art: Art, row: u16, column: u16) -> io::Result<bool> {
+    let (width, height) = size()?;
+    let art_width = get_art_width(art);
+    if column as usize + art_width > width as usize || row as usize + art.len() > height as usize {
+        return Ok(false);
+    }
+    for (i, line) in art.iter().enumerate() {
+        move_to(column, row + i as u16)?;
+        print_text!("{}", line)?;
+    }
+
+    Ok(true)
+}
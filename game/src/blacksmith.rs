@@ -0,0 +1,494 @@
+use crate::{
+    items::{
+        generate_item_name, num_enchantments, random_armor_enchantment, random_ring_enchantment,
+        random_weapon_enchantment, ArmorItem, Enchantment, ItemName, ItemRarity, ItemSet, RingItem,
+        WeaponItem, ENCHANTMENT_BASE_VALUES,
+    },
+    session::PlayerCharacter,
+};
+
+pub const UPGRADE_BASE_GOLD_COST: u32 = 100;
+pub const UPGRADE_BASE_MATERIAL_COST: u32 = 2;
+pub const REROLL_BASE_GOLD_COST: u32 = 150;
+pub const REROLL_BASE_MATERIAL_COST: u32 = 3;
+pub const RAISE_RARITY_BASE_GOLD_COST: u32 = 300;
+pub const RAISE_RARITY_BASE_MATERIAL_COST: u32 = 5;
+
+pub const UPGRADE_WEAPON_DAMAGE: u32 = 3;
+pub const UPGRADE_ARMOR_HEALTH: u32 = 8;
+pub const UPGRADE_ARMOR_DEFENSE: u32 = 2;
+pub const UPGRADE_RING_MANA: u32 = 5;
+
+/// Gold and crafting materials required by a blacksmith service.
+pub struct BlacksmithCost {
+    pub gold: u32,
+    pub materials: u32,
+}
+
+impl BlacksmithCost {
+    pub fn can_afford(&self, character: &PlayerCharacter) -> bool {
+        character.data.currency.gold >= self.gold
+            && character.data.inventory.crafting_materials >= self.materials
+    }
+
+    fn pay(&self, character: &mut PlayerCharacter) {
+        character.data.currency.gold -= self.gold;
+        character.data.inventory.crafting_materials -= self.materials;
+    }
+}
+
+/// Returns the amount of crafting materials received from salvaging an item.
+pub fn get_item_salvage_value(rarity: &ItemRarity) -> u32 {
+    match rarity {
+        ItemRarity::Common => 1,
+        ItemRarity::Uncommon => 2,
+        ItemRarity::Rare => 4,
+        ItemRarity::Epic => 6,
+        ItemRarity::Legendary => 8,
+        ItemRarity::Mythical => 10,
+        _ => 0,
+    }
+}
+
+/// Returns the rarity an item gets when its rarity is raised.
+/// Returns None if the rarity cannot be raised.
+pub fn get_next_item_rarity(rarity: &ItemRarity) -> Option<ItemRarity> {
+    match rarity {
+        ItemRarity::Common => Some(ItemRarity::Uncommon),
+        ItemRarity::Uncommon => Some(ItemRarity::Rare),
+        ItemRarity::Rare => Some(ItemRarity::Epic),
+        ItemRarity::Epic => Some(ItemRarity::Legendary),
+        ItemRarity::Legendary => Some(ItemRarity::Mythical),
+        _ => None,
+    }
+}
+
+pub fn get_upgrade_cost(item_level: u32) -> BlacksmithCost {
+    BlacksmithCost {
+        gold: UPGRADE_BASE_GOLD_COST * item_level.max(1),
+        materials: UPGRADE_BASE_MATERIAL_COST + item_level / 2,
+    }
+}
+
+pub fn get_reroll_cost(rarity: &ItemRarity) -> BlacksmithCost {
+    let enchantments = num_enchantments(rarity) as u32;
+    BlacksmithCost {
+        gold: REROLL_BASE_GOLD_COST * enchantments.max(1),
+        materials: REROLL_BASE_MATERIAL_COST,
+    }
+}
+
+/// Cost rises with each enchantment slot the item already has.
+/// Returns None if the rarity cannot be raised.
+pub fn get_raise_rarity_cost(rarity: &ItemRarity) -> Option<BlacksmithCost> {
+    let next_rarity = get_next_item_rarity(rarity)?;
+    let enchantments = num_enchantments(&next_rarity) as u32;
+    Some(BlacksmithCost {
+        gold: RAISE_RARITY_BASE_GOLD_COST * enchantments,
+        materials: RAISE_RARITY_BASE_MATERIAL_COST * enchantments,
+    })
+}
+
+/// Returns the amount of crafting materials received.
+pub fn salvage_weapon(weapon: &WeaponItem, character: &mut PlayerCharacter) -> u32 {
+    if character.delete_weapon(&weapon.id) {
+        let materials = get_item_salvage_value(&weapon.rarity);
+        character.give_crafting_materials(materials);
+        return materials;
+    }
+    0
+}
+
+/// Returns the amount of crafting materials received.
+pub fn salvage_armor(armor: &ArmorItem, character: &mut PlayerCharacter) -> u32 {
+    if character.delete_armor(&armor.id) {
+        let materials = get_item_salvage_value(&armor.rarity);
+        character.give_crafting_materials(materials);
+        return materials;
+    }
+    0
+}
+
+/// Returns the amount of crafting materials received.
+pub fn salvage_ring(ring: &RingItem, character: &mut PlayerCharacter) -> u32 {
+    if character.delete_ring(&ring.id) {
+        let materials = get_item_salvage_value(&ring.rarity);
+        character.give_crafting_materials(materials);
+        return materials;
+    }
+    0
+}
+
+/// Returns true if player has enough gold and materials and the item was upgraded.
+pub fn upgrade_weapon(id: &str, character: &mut PlayerCharacter) -> bool {
+    let cost = match character.data.inventory.weapons.get(id) {
+        Some(weapon) => get_upgrade_cost(weapon.level),
+        None => return false,
+    };
+    if !cost.can_afford(character) {
+        return false;
+    }
+    modify_weapon(id, character, |weapon| {
+        weapon.level += 1;
+        weapon.stats.damage += UPGRADE_WEAPON_DAMAGE;
+    });
+    cost.pay(character);
+    true
+}
+
+/// Returns true if player has enough gold and materials and the item was upgraded.
+pub fn upgrade_armor(id: &str, character: &mut PlayerCharacter) -> bool {
+    let cost = match character.data.inventory.armors.get(id) {
+        Some(armor) => get_upgrade_cost(armor.level),
+        None => return false,
+    };
+    if !cost.can_afford(character) {
+        return false;
+    }
+    modify_armor(id, character, |armor| {
+        armor.level += 1;
+        armor.stats.health += UPGRADE_ARMOR_HEALTH;
+        armor.stats.defense += UPGRADE_ARMOR_DEFENSE;
+    });
+    cost.pay(character);
+    true
+}
+
+/// Returns true if player has enough gold and materials and the item was upgraded.
+pub fn upgrade_ring(id: &str, character: &mut PlayerCharacter) -> bool {
+    let cost = match character.data.inventory.rings.get(id) {
+        Some(ring) => get_upgrade_cost(ring.level),
+        None => return false,
+    };
+    if !cost.can_afford(character) {
+        return false;
+    }
+    modify_ring(id, character, |ring| {
+        ring.level += 1;
+        ring.stats.mana += UPGRADE_RING_MANA;
+    });
+    cost.pay(character);
+    true
+}
+
+/// Replaces the enchantment at `index` with a new random enchantment.
+/// Returns true if player has enough gold and materials and the enchantment was rerolled.
+pub fn reroll_weapon_enchantment(id: &str, index: usize, character: &mut PlayerCharacter) -> bool {
+    let cost = match character.data.inventory.weapons.get(id) {
+        Some(weapon) if index < weapon.enchantments.len() => get_reroll_cost(&weapon.rarity),
+        _ => return false,
+    };
+    if !cost.can_afford(character) {
+        return false;
+    }
+    modify_weapon(id, character, |weapon| {
+        weapon.enchantments[index] =
+            random_weapon_enchantment(&ENCHANTMENT_BASE_VALUES, weapon.level);
+    });
+    cost.pay(character);
+    true
+}
+
+/// Replaces the enchantment at `index` with a new random enchantment.
+/// Returns true if player has enough gold and materials and the enchantment was rerolled.
+pub fn reroll_armor_enchantment(id: &str, index: usize, character: &mut PlayerCharacter) -> bool {
+    let cost = match character.data.inventory.armors.get(id) {
+        Some(armor) if index < armor.enchantments.len() => get_reroll_cost(&armor.rarity),
+        _ => return false,
+    };
+    if !cost.can_afford(character) {
+        return false;
+    }
+    modify_armor(id, character, |armor| {
+        armor.enchantments[index] = random_armor_enchantment(&ENCHANTMENT_BASE_VALUES, armor.level);
+    });
+    cost.pay(character);
+    true
+}
+
+/// Replaces the enchantment at `index` with a new random enchantment.
+/// Returns true if player has enough gold and materials and the enchantment was rerolled.
+pub fn reroll_ring_enchantment(id: &str, index: usize, character: &mut PlayerCharacter) -> bool {
+    let cost = match character.data.inventory.rings.get(id) {
+        Some(ring) if index < ring.enchantments.len() => get_reroll_cost(&ring.rarity),
+        _ => return false,
+    };
+    if !cost.can_afford(character) {
+        return false;
+    }
+    modify_ring(id, character, |ring| {
+        ring.enchantments[index] = random_ring_enchantment(&ENCHANTMENT_BASE_VALUES, ring.level);
+    });
+    cost.pay(character);
+    true
+}
+
+/// Raises the rarity of the item and adds a new enchantment slot.
+/// Returns true if player has enough gold and materials and the rarity was raised.
+pub fn raise_weapon_rarity(id: &str, character: &mut PlayerCharacter) -> bool {
+    let (cost, next_rarity) = match character.data.inventory.weapons.get(id) {
+        Some(weapon) => match (
+            get_raise_rarity_cost(&weapon.rarity),
+            get_next_item_rarity(&weapon.rarity),
+        ) {
+            (Some(cost), Some(next_rarity)) => (cost, next_rarity),
+            _ => return false,
+        },
+        None => return false,
+    };
+    if !cost.can_afford(character) {
+        return false;
+    }
+    modify_weapon(id, character, |weapon| {
+        weapon.rarity = next_rarity;
+        weapon.enchantments.push(random_weapon_enchantment(
+            &ENCHANTMENT_BASE_VALUES,
+            weapon.level,
+        ));
+    });
+    cost.pay(character);
+    true
+}
+
+/// Raises the rarity of the item and adds a new enchantment slot.
+/// Returns true if player has enough gold and materials and the rarity was raised.
+pub fn raise_armor_rarity(id: &str, character: &mut PlayerCharacter) -> bool {
+    let (cost, next_rarity) = match character.data.inventory.armors.get(id) {
+        Some(armor) => match (
+            get_raise_rarity_cost(&armor.rarity),
+            get_next_item_rarity(&armor.rarity),
+        ) {
+            (Some(cost), Some(next_rarity)) => (cost, next_rarity),
+            _ => return false,
+        },
+        None => return false,
+    };
+    if !cost.can_afford(character) {
+        return false;
+    }
+    modify_armor(id, character, |armor| {
+        armor.rarity = next_rarity;
+        armor.enchantments.push(random_armor_enchantment(
+            &ENCHANTMENT_BASE_VALUES,
+            armor.level,
+        ));
+    });
+    cost.pay(character);
+    true
+}
+
+/// Raises the rarity of the item and adds a new enchantment slot.
+/// Returns true if player has enough gold and materials and the rarity was raised.
+pub fn raise_ring_rarity(id: &str, character: &mut PlayerCharacter) -> bool {
+    let (cost, next_rarity) = match character.data.inventory.rings.get(id) {
+        Some(ring) => match (
+            get_raise_rarity_cost(&ring.rarity),
+            get_next_item_rarity(&ring.rarity),
+        ) {
+            (Some(cost), Some(next_rarity)) => (cost, next_rarity),
+            _ => return false,
+        },
+        None => return false,
+    };
+    if !cost.can_afford(character) {
+        return false;
+    }
+    modify_ring(id, character, |ring| {
+        ring.rarity = next_rarity;
+        ring.enchantments.push(random_ring_enchantment(
+            &ENCHANTMENT_BASE_VALUES,
+            ring.level,
+        ));
+    });
+    cost.pay(character);
+    true
+}
+
+/// Modifies an item in the inventory. If the item is equipped,
+/// it is unequipped first and equipped again afterwards so stat boosts stay correct.
+/// The name is generated again from the new enchantments.
+fn modify_weapon<F>(id: &str, character: &mut PlayerCharacter, modify: F)
+where
+    F: FnOnce(&mut WeaponItem),
+{
+    let equipped = character.equipped_items.weapon.as_deref() == Some(id);
+    let (current_health, current_mana) = save_current_health_and_mana(character);
+    if equipped {
+        character.unequip_weapon();
+    }
+    if let Some(weapon) = character.data.inventory.weapons.get_mut(id) {
+        modify(weapon);
+        if let Some(name) = regenerate_item_name(&weapon.enchantments, &weapon.set, &weapon.lore) {
+            weapon.name = Some(name);
+        }
+    }
+    if equipped {
+        character.equip_weapon(id);
+    }
+    restore_current_health_and_mana(character, current_health, current_mana);
+}
+
+/// Modifies an item in the inventory. If the item is equipped,
+/// it is unequipped first and equipped again afterwards so stat boosts stay correct.
+/// The name is generated again from the new enchantments.
+fn modify_armor<F>(id: &str, character: &mut PlayerCharacter, modify: F)
+where
+    F: FnOnce(&mut ArmorItem),
+{
+    let equipped = character.equipped_items.armor.as_deref() == Some(id);
+    let (current_health, current_mana) = save_current_health_and_mana(character);
+    if equipped {
+        character.unequip_armor();
+    }
+    if let Some(armor) = character.data.inventory.armors.get_mut(id) {
+        modify(armor);
+        if let Some(name) = regenerate_item_name(&armor.enchantments, &armor.set, &armor.lore) {
+            armor.name = Some(name);
+        }
+    }
+    if equipped {
+        character.equip_armor(id);
+    }
+    restore_current_health_and_mana(character, current_health, current_mana);
+}
+
+/// Modifies an item in the inventory. If the item is equipped,
+/// it is unequipped first and equipped again afterwards so stat boosts stay correct.
+/// The name is generated again from the new enchantments.
+fn modify_ring<F>(id: &str, character: &mut PlayerCharacter, modify: F)
+where
+    F: FnOnce(&mut RingItem),
+{
+    let equipped = character.equipped_items.ring.as_deref() == Some(id);
+    let (current_health, current_mana) = save_current_health_and_mana(character);
+    if equipped {
+        character.unequip_ring();
+    }
+    if let Some(ring) = character.data.inventory.rings.get_mut(id) {
+        modify(ring);
+        if let Some(name) = regenerate_item_name(&ring.enchantments, &ring.set, &ring.lore) {
+            ring.name = Some(name);
+        }
+    }
+    if equipped {
+        character.equip_ring(id);
+    }
+    restore_current_health_and_mana(character, current_health, current_mana);
+}
+
+/// Returns the new name of a modified item.
+/// Unique and set items keep their names and so do items without enchantments,
+/// because their name comes from the quality of the main stat roll.
+fn regenerate_item_name(
+    enchantments: &[Enchantment],
+    set: &Option<ItemSet>,
+    lore: &Option<String>,
+) -> Option<ItemName> {
+    if set.is_some() || lore.is_some() || enchantments.is_empty() {
+        return None;
+    }
+    // the stat quality only matters for items without enchantments
+    Some(generate_item_name(enchantments, 0.0))
+}
+
+fn save_current_health_and_mana(character: &PlayerCharacter) -> (u32, u32) {
+    (
+        character.temp_stats.current_health,
+        character.temp_stats.current_mana,
+    )
+}
+
+/// Unequipping an item may lower current health and mana.
+/// This restores them without going over the new maximum values.
+fn restore_current_health_and_mana(character: &mut PlayerCharacter, health: u32, mana: u32) {
+    character.temp_stats.current_health = health.min(character.get_total_health());
+    character.temp_stats.current_mana = mana.min(character.get_total_mana());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        character::CharacterClass,
+        items::{generate_random_weapon, generate_set_weapon, WEAPON_BASE_VALUES},
+        test_util::test_character,
+    };
+
+    fn rich_character() -> PlayerCharacter {
+        let mut character = test_character(CharacterClass::Warrior);
+        character.give_gold(100_000);
+        character.give_crafting_materials(1000);
+        character
+    }
+
+    #[test]
+    fn upgrade_cost_rises_with_level() {
+        let cost = get_upgrade_cost(0);
+        assert_eq!((cost.gold, cost.materials), (100, 2));
+        let cost = get_upgrade_cost(5);
+        assert_eq!((cost.gold, cost.materials), (500, 4));
+    }
+
+    #[test]
+    fn upgrade_requires_gold_and_materials() {
+        let mut character = test_character(CharacterClass::Warrior);
+        let id = character.equipped_items.weapon.clone().unwrap();
+        let cost = get_upgrade_cost(character.data.inventory.weapons[&id].level);
+        character.give_gold(cost.gold);
+        character.give_crafting_materials(cost.materials - 1);
+        assert!(!cost.can_afford(&character));
+        assert!(!upgrade_weapon(&id, &mut character));
+
+        character.give_crafting_materials(1);
+        let gold = character.data.currency.gold;
+        assert!(upgrade_weapon(&id, &mut character));
+        assert_eq!(character.data.currency.gold, gold - cost.gold);
+        assert_eq!(character.data.inventory.crafting_materials, 0);
+    }
+
+    #[test]
+    fn upgrading_equipped_item_updates_stat_boosts() {
+        let mut character = test_character(CharacterClass::Warrior);
+        character.give_gold(1000);
+        character.give_crafting_materials(10);
+        let id = character.equipped_items.weapon.clone().unwrap();
+        let damage = character.get_total_damage();
+        let health = character.temp_stats.current_health;
+
+        assert!(upgrade_weapon(&id, &mut character));
+        assert_eq!(character.equipped_items.weapon, Some(id));
+        assert_eq!(character.get_total_damage(), damage + UPGRADE_WEAPON_DAMAGE);
+        assert_eq!(character.temp_stats.current_health, health);
+    }
+
+    #[test]
+    fn raise_rarity_regenerates_name() {
+        let mut character = rich_character();
+        let mut weapon = generate_random_weapon(
+            ItemRarity::Rare,
+            WEAPON_BASE_VALUES,
+            1,
+            &CharacterClass::Warrior,
+        );
+        weapon.enchantments = vec![Enchantment::Thorns(1)];
+        weapon.name = Some(ItemName::Text("Old Name".to_string()));
+        character.give_weapon(&weapon);
+
+        assert!(raise_weapon_rarity(&weapon.id, &mut character));
+        let weapon = &character.data.inventory.weapons[&weapon.id];
+        assert_eq!(
+            weapon.name,
+            Some(generate_item_name(&weapon.enchantments, 0.0))
+        );
+    }
+
+    #[test]
+    fn reroll_keeps_set_item_name() {
+        let mut character = rich_character();
+        let weapon = generate_set_weapon(ItemSet::Dragonscale, 1, &CharacterClass::Warrior);
+        character.give_weapon(&weapon);
+
+        assert!(reroll_weapon_enchantment(&weapon.id, 0, &mut character));
+        assert_eq!(character.data.inventory.weapons[&weapon.id].name, None);
+    }
+}
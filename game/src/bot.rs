@@ -0,0 +1,973 @@
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead, Write};
+
+use crate::{
+    alchemy::Ingredient,
+    character::{create_player_character, CharacterClass},
+    drops::{
+        give_ancient_enemy_drops, give_boss_enemy_drops, give_normal_enemy_drops,
+        give_treasure_chest_drops, ItemDrop,
+    },
+    dungeon::{generate_random_dungeon_floor, DungeonFloor, RoomCoordinates, RoomKind},
+    enemy::{Enemy, EnemyKind},
+    fight::{
+        advance_fight, end_fight, enemy_defeated, player_died, start_fight, FightOptions,
+        FightStep, FightTurn,
+    },
+    items::{
+        get_item_display_name, get_item_level_display, get_item_purchase_value, CharacterItem,
+        CharacterItemOwned, ConsumableEffect, ItemRarity,
+    },
+    session::{PlayerCharacter, TemporaryStatBoosts},
+    shop::{buy_consumable, get_equipment_purchase_value},
+    tr,
+};
+
+pub const BOT_CHARACTER_NAME: &str = "Bot";
+pub const BOT_CLASSES: [CharacterClass; 5] = [
+    CharacterClass::Mage,
+    CharacterClass::Cleric,
+    CharacterClass::Assassin,
+    CharacterClass::Warrior,
+    CharacterClass::Knight,
+];
+
+/// Action sent by a bot as a line of JSON.
+/// E.g. `{"action":"move","direction":"up"}` or `{"action":"attack"}`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum BotAction {
+    /// Starts a new run with a new level 1 character.
+    NewCharacter {
+        class: CharacterClass,
+    },
+    Move {
+        direction: BotDirection,
+    },
+    OpenTreasureChest,
+    /// Goes to the next dungeon floor after the boss is defeated.
+    NextFloor,
+    EnterShop,
+    LeaveShop,
+    BuyEquipment {
+        index: usize,
+    },
+    BuyConsumable {
+        index: usize,
+    },
+    /// Sells a weapon, armor or ring with the ID.
+    Sell {
+        id: String,
+    },
+    /// Equips a weapon, armor or ring with the ID.
+    Equip {
+        id: String,
+    },
+    Attack,
+    UseSkill,
+    /// Uses the consumable with the display name.
+    UseItem {
+        name: String,
+    },
+    Quit,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BotDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BotState {
+    /// No character yet. A new character needs to be created.
+    NewCharacter,
+    Dungeon,
+    Fight,
+    Shop,
+    /// The character died. A new character can be created.
+    GameOver,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BotItemKind {
+    Weapon,
+    Armor,
+    Ring,
+}
+
+/// Everything the bot can see. Written as a line of JSON after every action.
+#[derive(Serialize)]
+pub struct BotObservation {
+    pub state: BotState,
+    /// What happened since the previous observation.
+    pub messages: Vec<String>,
+    /// Set if the previous action was invalid or couldn't be done.
+    /// Errors are not translated.
+    pub error: Option<String>,
+    pub player: Option<PlayerObservation>,
+    pub room: Option<RoomObservation>,
+    pub enemy: Option<EnemyObservation>,
+    pub shop: Option<ShopObservation>,
+    /// Actions that can be sent next.
+    pub actions: Vec<BotAction>,
+}
+
+#[derive(Serialize)]
+pub struct PlayerObservation {
+    pub name: String,
+    pub class: CharacterClass,
+    pub level: u32,
+    pub exp: u32,
+    pub required_exp: u32,
+    pub health: u32,
+    pub max_health: u32,
+    pub mana: u32,
+    pub max_mana: u32,
+    pub damage: u32,
+    pub defense: u32,
+    pub crit_hit_rate: f64,
+    pub gold: u32,
+    pub dungeon_floor: u32,
+    pub consumables: Vec<ConsumableObservation>,
+    pub equipment: Vec<EquipmentObservation>,
+}
+
+#[derive(Serialize)]
+pub struct ConsumableObservation {
+    pub name: String,
+    pub rarity: ItemRarity,
+    pub amount: u32,
+    pub effect: String,
+}
+
+#[derive(Serialize)]
+pub struct EquipmentObservation {
+    pub id: String,
+    pub kind: BotItemKind,
+    pub name: String,
+    pub rarity: ItemRarity,
+    pub level: u32,
+    pub equipped: bool,
+    pub locked: bool,
+}
+
+#[derive(Serialize)]
+pub struct RoomObservation {
+    pub floor: u32,
+    pub x: i32,
+    pub y: i32,
+    pub kind: String,
+    pub room_num: u32,
+    pub treasure: bool,
+    pub adjacents: Vec<BotDirection>,
+    /// True if the boss of the floor is still alive.
+    pub boss_alive: bool,
+}
+
+#[derive(Serialize)]
+pub struct EnemyObservation {
+    pub name: String,
+    pub kind: String,
+    pub level: u32,
+    pub health: u32,
+    pub max_health: u32,
+    pub damage: u32,
+    pub defense: u32,
+}
+
+#[derive(Serialize)]
+pub struct ShopObservation {
+    pub equipment: Vec<ShopOfferObservation>,
+    pub consumables: Vec<ShopOfferObservation>,
+}
+
+#[derive(Serialize)]
+pub struct ShopOfferObservation {
+    pub index: usize,
+    pub name: String,
+    pub rarity: ItemRarity,
+    pub price: u32,
+}
+
+/// Where the enemy of the current fight is.
+enum FightTarget {
+    Room,
+    Boss,
+}
+
+struct BotFight {
+    target: FightTarget,
+    turn: FightTurn,
+    /// Stat boosts the player had before the fight.
+    stat_boosts: TemporaryStatBoosts,
+}
+
+/// Game session played by a bot. The character is never saved.
+pub struct BotSession {
+    pub state: BotState,
+    pub character: Option<PlayerCharacter>,
+    pub dungeon_floor: Option<DungeonFloor>,
+    pub room: RoomCoordinates,
+    fight: Option<BotFight>,
+    messages: Vec<String>,
+}
+
+impl BotSession {
+    pub fn new() -> Self {
+        Self {
+            state: BotState::NewCharacter,
+            character: None,
+            dungeon_floor: None,
+            room: RoomCoordinates::new(0, 0),
+            fight: None,
+            messages: Vec::new(),
+        }
+    }
+
+    /// Returns the current observation.
+    /// Messages are cleared after they are observed.
+    pub fn observe(&mut self, error: Option<String>) -> BotObservation {
+        let room = match &self.dungeon_floor {
+            Some(dungeon_floor) => {
+                dungeon_floor
+                    .rooms
+                    .get(&self.room)
+                    .map(|room| RoomObservation {
+                        floor: dungeon_floor.floor,
+                        x: room.coords.x,
+                        y: room.coords.y,
+                        kind: format!("{:?}", room.kind),
+                        room_num: room.room_num,
+                        treasure: room.treasure,
+                        adjacents: get_adjacent_directions(dungeon_floor, &self.room),
+                        boss_alive: dungeon_floor.boss.is_some(),
+                    })
+            }
+            None => None,
+        };
+        let enemy = match self.state {
+            BotState::Fight => self.get_fight_enemy().map(observe_enemy),
+            _ => None,
+        };
+        let shop = match (self.state, &self.dungeon_floor) {
+            (BotState::Shop, Some(dungeon_floor)) => Some(ShopObservation {
+                equipment: dungeon_floor
+                    .shop_items
+                    .equipment
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| ShopOfferObservation {
+                        index,
+                        name: get_item_display_name(item.as_character_item()),
+                        rarity: get_equipment_rarity(item),
+                        price: get_equipment_purchase_value(item.as_character_item()),
+                    })
+                    .collect(),
+                consumables: dungeon_floor
+                    .shop_items
+                    .consumables
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| ShopOfferObservation {
+                        index,
+                        name: get_item_display_name(CharacterItem::Consumable(item)),
+                        rarity: item.rarity.clone(),
+                        price: get_item_purchase_value(&item.rarity),
+                    })
+                    .collect(),
+            }),
+            _ => None,
+        };
+
+        BotObservation {
+            state: self.state,
+            messages: std::mem::take(&mut self.messages),
+            error,
+            player: self.character.as_ref().map(observe_player),
+            room: match self.state {
+                BotState::NewCharacter | BotState::GameOver => None,
+                _ => room,
+            },
+            enemy,
+            shop,
+            actions: self.get_actions(),
+        }
+    }
+
+    /// Returns the actions that can be done in the current state.
+    pub fn get_actions(&self) -> Vec<BotAction> {
+        let mut actions = Vec::new();
+        match (&self.state, &self.character, &self.dungeon_floor) {
+            (BotState::Dungeon, Some(character), Some(dungeon_floor)) => {
+                for direction in get_adjacent_directions(dungeon_floor, &self.room) {
+                    actions.push(BotAction::Move { direction });
+                }
+                if let Some(room) = dungeon_floor.rooms.get(&self.room) {
+                    if room.treasure {
+                        actions.push(BotAction::OpenTreasureChest);
+                    }
+                    match room.kind {
+                        RoomKind::Start => actions.push(BotAction::EnterShop),
+                        RoomKind::Boss if dungeon_floor.boss.is_none() => {
+                            actions.push(BotAction::NextFloor)
+                        }
+                        _ => {}
+                    }
+                }
+                for item in get_equipment_items(character) {
+                    if !is_equipped(&item, character) {
+                        actions.push(BotAction::Equip {
+                            id: get_equipment_id(&item),
+                        });
+                    }
+                }
+            }
+            (BotState::Shop, Some(character), Some(dungeon_floor)) => {
+                let gold = character.data.currency.gold;
+                for (index, item) in dungeon_floor.shop_items.equipment.iter().enumerate() {
+                    if gold >= get_equipment_purchase_value(item.as_character_item()) {
+                        actions.push(BotAction::BuyEquipment { index });
+                    }
+                }
+                for (index, item) in dungeon_floor.shop_items.consumables.iter().enumerate() {
+                    if gold >= get_item_purchase_value(&item.rarity) {
+                        actions.push(BotAction::BuyConsumable { index });
+                    }
+                }
+                for item in get_equipment_items(character) {
+                    if !is_locked(&item) {
+                        actions.push(BotAction::Sell {
+                            id: get_equipment_id(&item),
+                        });
+                    }
+                }
+                actions.push(BotAction::LeaveShop);
+            }
+            (BotState::Fight, Some(character), _) => {
+                actions.push(BotAction::Attack);
+                if character.has_enough_mana_for_skill() {
+                    actions.push(BotAction::UseSkill);
+                }
+                let mut names: Vec<&String> = character
+                    .data
+                    .inventory
+                    .consumables
+                    .iter()
+                    // resurrection items are used automatically
+                    .filter(|(_, item)| item.effect_kind != ConsumableEffect::Resurrection)
+                    .map(|(name, _)| name)
+                    .collect();
+                names.sort();
+                for name in names {
+                    actions.push(BotAction::UseItem { name: name.clone() });
+                }
+            }
+            _ => {
+                for class in BOT_CLASSES {
+                    actions.push(BotAction::NewCharacter { class });
+                }
+            }
+        }
+        actions.push(BotAction::Quit);
+        actions
+    }
+
+    /// Returns an error message if the action is not available or fails.
+    pub fn handle_action(&mut self, action: BotAction) -> Result<(), String> {
+        if !self.get_actions().contains(&action) {
+            return Err("action is not available".to_string());
+        }
+        match action {
+            BotAction::NewCharacter { class } => {
+                self.character = Some(create_player_character(BOT_CHARACTER_NAME, class));
+                self.messages.push(tr!("bot.character_created"));
+                self.start_dungeon_floor(1);
+            }
+            BotAction::Move { direction } => self.move_to(direction)?,
+            BotAction::OpenTreasureChest => self.open_treasure_chest()?,
+            BotAction::NextFloor => {
+                let floor = self
+                    .get_character_mut()?
+                    .data
+                    .stats
+                    .general_stats
+                    .current_dungeon_floor;
+                self.start_dungeon_floor(floor);
+            }
+            BotAction::EnterShop => self.state = BotState::Shop,
+            BotAction::LeaveShop => self.state = BotState::Dungeon,
+            BotAction::BuyEquipment { index } => self.buy_equipment(index)?,
+            BotAction::BuyConsumable { index } => self.buy_consumable(index)?,
+            BotAction::Sell { id } => self.sell(&id)?,
+            BotAction::Equip { id } => self.equip(&id)?,
+            BotAction::Attack | BotAction::UseSkill | BotAction::UseItem { .. } => {
+                self.player_fight_action(action)?
+            }
+            BotAction::Quit => {}
+        }
+        Ok(())
+    }
+
+    fn get_character_mut(&mut self) -> Result<&mut PlayerCharacter, String> {
+        self.character
+            .as_mut()
+            .ok_or_else(|| "no character".to_string())
+    }
+
+    fn start_dungeon_floor(&mut self, floor: u32) {
+        if let Some(character) = &self.character {
+            self.dungeon_floor = Some(generate_random_dungeon_floor(
+                floor,
+                &character.data.metadata.class,
+            ));
+            self.room = RoomCoordinates::new(0, 0);
+            self.state = BotState::Dungeon;
+            self.messages.push(tr!("bot.entered_floor", floor = floor));
+        }
+    }
+
+    fn move_to(&mut self, direction: BotDirection) -> Result<(), String> {
+        let dungeon_floor = self
+            .dungeon_floor
+            .as_ref()
+            .ok_or_else(|| "no dungeon floor".to_string())?;
+        let room = dungeon_floor
+            .rooms
+            .get(&self.room)
+            .ok_or_else(|| "room not found".to_string())?;
+        let next = match direction {
+            BotDirection::Up => room.adjacents.up.clone(),
+            BotDirection::Down => room.adjacents.down.clone(),
+            BotDirection::Left => room.adjacents.left.clone(),
+            BotDirection::Right => room.adjacents.right.clone(),
+        }
+        .ok_or_else(|| "no room in that direction".to_string())?;
+        let fight_target = match dungeon_floor.rooms.get(&next) {
+            Some(room) if room.enemy.is_some() => Some(FightTarget::Room),
+            Some(room) if matches!(room.kind, RoomKind::Boss) && dungeon_floor.boss.is_some() => {
+                Some(FightTarget::Boss)
+            }
+            _ => None,
+        };
+        self.room = next;
+        if let Some(target) = fight_target {
+            self.start_fight(target)?;
+        }
+        Ok(())
+    }
+
+    fn start_fight(&mut self, target: FightTarget) -> Result<(), String> {
+        let stat_boosts = start_fight(self.get_character_mut()?, &FightOptions::default());
+        self.fight = Some(BotFight {
+            target,
+            turn: FightTurn::Player,
+            stat_boosts,
+        });
+        self.state = BotState::Fight;
+        if let Some(enemy) = self.get_fight_enemy() {
+            let text = tr!("fight.encountered_enemy", enemy = enemy.get_display_name());
+            self.messages.push(text);
+        }
+        Ok(())
+    }
+
+    fn get_fight_enemy(&self) -> Option<&Enemy> {
+        let dungeon_floor = self.dungeon_floor.as_ref()?;
+        match self.fight.as_ref()?.target {
+            FightTarget::Room => dungeon_floor.rooms.get(&self.room)?.enemy.as_ref(),
+            FightTarget::Boss => dungeon_floor.boss.as_ref(),
+        }
+    }
+
+    fn player_fight_action(&mut self, action: BotAction) -> Result<(), String> {
+        let (Some(character), Some(dungeon_floor), Some(fight)) = (
+            self.character.as_mut(),
+            self.dungeon_floor.as_mut(),
+            self.fight.as_mut(),
+        ) else {
+            return Err("not in a fight".to_string());
+        };
+        let enemy = match fight.target {
+            FightTarget::Room => dungeon_floor
+                .rooms
+                .get_mut(&self.room)
+                .and_then(|room| room.enemy.as_mut()),
+            FightTarget::Boss => dungeon_floor.boss.as_mut(),
+        }
+        .ok_or_else(|| "enemy not found".to_string())?;
+
+        match action {
+            BotAction::Attack => {
+                let (event, effect) = character.attack_enemy(enemy);
+                let event = event.to_string();
+                self.messages.push(event);
+                self.messages.push(effect);
+            }
+            BotAction::UseSkill => {
+                let (event, effect) = character.use_skill(enemy);
+                self.messages.push(event);
+                self.messages.push(effect);
+            }
+            BotAction::UseItem { name } => {
+                let item = character
+                    .data
+                    .inventory
+                    .consumables
+                    .get(&name)
+                    .cloned()
+                    .ok_or_else(|| "item not found".to_string())?;
+                let (item_name, _, effect) = item.use_item(character, enemy);
+                if item_name.is_empty() {
+                    return Err("the item can't be used now".to_string());
+                }
+                self.messages
+                    .push(tr!("bot.player_used_item", item = item_name));
+                self.messages.push(effect);
+            }
+            _ => return Err("not a fight action".to_string()),
+        }
+
+        // the enemy and companion act until it is the player's turn again
+        loop {
+            match advance_fight(enemy, character, &mut fight.turn) {
+                FightStep::Action(event, effect) => {
+                    self.messages.push(event);
+                    self.messages.push(effect);
+                }
+                FightStep::Resurrection(item_name, _, effect) => {
+                    self.messages
+                        .push(tr!("bot.player_used_item", item = item_name));
+                    self.messages.push(effect);
+                }
+                FightStep::PlayerTurn(restored_mana) => {
+                    if restored_mana > 0 {
+                        self.messages
+                            .push(tr!("combat_log.mana_regen", mana = restored_mana));
+                    }
+                    break;
+                }
+                FightStep::Victory => {
+                    self.messages.push(tr!("fight.victory"));
+                    let character_level = character.data.stats.general_stats.character_level;
+                    enemy_defeated(enemy, character);
+                    self.messages.extend(give_enemy_drops(enemy, character));
+                    if character.data.stats.general_stats.character_level > character_level {
+                        self.messages.push(tr!(
+                            "bot.level_reached",
+                            level = character.data.stats.general_stats.character_level
+                        ));
+                    }
+                    match fight.target {
+                        FightTarget::Room => {
+                            if let Some(room) = dungeon_floor.rooms.get_mut(&self.room) {
+                                room.enemy = None;
+                            }
+                        }
+                        FightTarget::Boss => {
+                            dungeon_floor.boss = None;
+                            character.dungeon_floor_completed(dungeon_floor.floor + 1);
+                        }
+                    }
+                    self.end_fight(BotState::Dungeon);
+                    break;
+                }
+                FightStep::Defeat => {
+                    player_died(character);
+                    self.messages.push(tr!("fight.died"));
+                    self.end_fight(BotState::GameOver);
+                    break;
+                }
+            }
+        }
+        self.messages.retain(|message| !message.is_empty());
+        Ok(())
+    }
+
+    fn end_fight(&mut self, state: BotState) {
+        if let (Some(fight), Some(character)) = (self.fight.take(), self.character.as_mut()) {
+            end_fight(character, fight.stat_boosts);
+        }
+        self.state = state;
+    }
+
+    fn open_treasure_chest(&mut self) -> Result<(), String> {
+        let (Some(character), Some(dungeon_floor)) =
+            (self.character.as_mut(), self.dungeon_floor.as_mut())
+        else {
+            return Err("no dungeon floor".to_string());
+        };
+        let drops = give_treasure_chest_drops(character, dungeon_floor.floor);
+        if let Some(room) = dungeon_floor.rooms.get_mut(&self.room) {
+            room.treasure = false;
+        }
+        self.messages.push(tr!("dungeon.treasure_chest_opened"));
+        self.messages
+            .push(tr!("bot.received_gold", gold = drops.gold));
+        self.messages
+            .push(get_item_drop_message(&drops.equipment_item));
+        self.messages
+            .extend(get_ingredient_drop_messages(&drops.ingredients));
+        Ok(())
+    }
+
+    fn buy_equipment(&mut self, index: usize) -> Result<(), String> {
+        let (Some(character), Some(dungeon_floor)) =
+            (self.character.as_mut(), self.dungeon_floor.as_mut())
+        else {
+            return Err("no dungeon floor".to_string());
+        };
+        let name = match dungeon_floor.shop_items.equipment.get(index) {
+            Some(item) => get_item_display_name(item.as_character_item()),
+            None => return Err("item not found".to_string()),
+        };
+        if !dungeon_floor.shop_items.buy_equipment(index, character) {
+            return Err("not enough gold".to_string());
+        }
+        self.messages.push(tr!("bot.bought", item = name));
+        Ok(())
+    }
+
+    fn buy_consumable(&mut self, index: usize) -> Result<(), String> {
+        let (Some(character), Some(dungeon_floor)) =
+            (self.character.as_mut(), self.dungeon_floor.as_mut())
+        else {
+            return Err("no dungeon floor".to_string());
+        };
+        let item = dungeon_floor
+            .shop_items
+            .consumables
+            .get(index)
+            .ok_or_else(|| "item not found".to_string())?;
+        if !buy_consumable(item, character) {
+            return Err("not enough gold".to_string());
+        }
+        self.messages.push(tr!(
+            "bot.bought",
+            item = get_item_display_name(CharacterItem::Consumable(item))
+        ));
+        Ok(())
+    }
+
+    fn sell(&mut self, id: &str) -> Result<(), String> {
+        let (Some(character), Some(dungeon_floor)) =
+            (self.character.as_mut(), self.dungeon_floor.as_mut())
+        else {
+            return Err("no dungeon floor".to_string());
+        };
+        let shop_items = &mut dungeon_floor.shop_items;
+        let inventory = &character.data.inventory;
+        let gold = if let Some(item) = inventory.weapons.get(id).cloned() {
+            shop_items.sell_weapon(&item, character)
+        } else if let Some(item) = inventory.armors.get(id).cloned() {
+            shop_items.sell_armor(&item, character)
+        } else if let Some(item) = inventory.rings.get(id).cloned() {
+            shop_items.sell_ring(&item, character)
+        } else {
+            return Err("item not found".to_string());
+        };
+        self.messages.push(tr!("bot.sold", gold = gold));
+        Ok(())
+    }
+
+    fn equip(&mut self, id: &str) -> Result<(), String> {
+        let character = self.get_character_mut()?;
+        let inventory = &character.data.inventory;
+        let equipped = if inventory.weapons.contains_key(id) {
+            character.equip_weapon(id)
+        } else if inventory.armors.contains_key(id) {
+            character.equip_armor(id)
+        } else if inventory.rings.contains_key(id) {
+            character.equip_ring(id)
+        } else {
+            false
+        };
+        if !equipped {
+            return Err("item not found".to_string());
+        }
+        self.messages.push(tr!("bot.equipped"));
+        Ok(())
+    }
+}
+
+impl Default for BotSession {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Plays the game with line-delimited JSON. Reads actions from `input` and
+/// writes an observation to `output` after every action until the bot quits or the input ends.
+/// The bot doesn't use the frontend, so `output` only gets the JSON lines.
+pub fn run_bot(input: impl BufRead, output: &mut impl Write) -> io::Result<()> {
+    let mut session = BotSession::new();
+
+    write_observation(output, &session.observe(None))?;
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let error = match serde_json::from_str::<BotAction>(&line) {
+            Ok(BotAction::Quit) => break,
+            Ok(action) => session.handle_action(action).err(),
+            Err(e) => Some(format!("invalid action: {}", e)),
+        };
+        write_observation(output, &session.observe(error))?;
+    }
+
+    Ok(())
+}
+
+fn write_observation(out: &mut impl Write, observation: &BotObservation) -> io::Result<()> {
+    writeln!(out, "{}", serde_json::to_string(observation)?)?;
+    out.flush()
+}
+
+fn get_adjacent_directions(
+    dungeon_floor: &DungeonFloor,
+    coords: &RoomCoordinates,
+) -> Vec<BotDirection> {
+    let mut directions = Vec::new();
+    if let Some(room) = dungeon_floor.rooms.get(coords) {
+        if room.adjacents.up.is_some() {
+            directions.push(BotDirection::Up);
+        }
+        if room.adjacents.down.is_some() {
+            directions.push(BotDirection::Down);
+        }
+        if room.adjacents.left.is_some() {
+            directions.push(BotDirection::Left);
+        }
+        if room.adjacents.right.is_some() {
+            directions.push(BotDirection::Right);
+        }
+    }
+    directions
+}
+
+fn observe_player(character: &PlayerCharacter) -> PlayerObservation {
+    let general_stats = &character.data.stats.general_stats;
+    let mut consumables: Vec<ConsumableObservation> = character
+        .data
+        .inventory
+        .consumables
+        .iter()
+        .map(|(name, item)| ConsumableObservation {
+            name: name.clone(),
+            rarity: item.rarity.clone(),
+            amount: item.amount_in_inventory,
+            effect: item.effect.clone(),
+        })
+        .collect();
+    consumables.sort_by(|a, b| a.name.cmp(&b.name));
+
+    PlayerObservation {
+        name: character.data.metadata.name.clone(),
+        class: character.data.metadata.class.clone(),
+        level: general_stats.character_level,
+        exp: general_stats.current_exp,
+        required_exp: general_stats.required_exp,
+        health: character.temp_stats.current_health,
+        max_health: character.get_total_health(),
+        mana: character.temp_stats.current_mana,
+        max_mana: character.get_total_mana(),
+        damage: character.get_total_damage(),
+        defense: character.get_total_defense(),
+        crit_hit_rate: character.get_total_crit_hit_rate(),
+        gold: character.data.currency.gold,
+        dungeon_floor: general_stats.current_dungeon_floor,
+        consumables,
+        equipment: get_equipment_items(character)
+            .iter()
+            .map(|item| observe_equipment(item, character))
+            .collect(),
+    }
+}
+
+fn observe_equipment(
+    item: &CharacterItemOwned,
+    character: &PlayerCharacter,
+) -> EquipmentObservation {
+    let (kind, level, locked) = match item {
+        CharacterItemOwned::Weapon(item) => (BotItemKind::Weapon, item.level, item.locked),
+        CharacterItemOwned::Armor(item) => (BotItemKind::Armor, item.level, item.locked),
+        CharacterItemOwned::Ring(item) => (BotItemKind::Ring, item.level, item.locked),
+        _ => (BotItemKind::Ring, 0, false),
+    };
+    EquipmentObservation {
+        id: get_equipment_id(item),
+        kind,
+        name: get_item_display_name(item.as_character_item()),
+        rarity: get_equipment_rarity(item),
+        level,
+        equipped: is_equipped(item, character),
+        locked,
+    }
+}
+
+fn observe_enemy(enemy: &Enemy) -> EnemyObservation {
+    EnemyObservation {
+        name: enemy.get_display_name(),
+        kind: format!("{:?}", enemy.kind),
+        level: enemy.level,
+        health: enemy.stats.current_health,
+        max_health: enemy.stats.max_health,
+        damage: enemy.get_total_damage(),
+        defense: enemy.get_total_defense(),
+    }
+}
+
+/// Returns the weapons, armors and rings in the order they were received.
+fn get_equipment_items(character: &PlayerCharacter) -> Vec<CharacterItemOwned> {
+    let inventory = &character.data.inventory;
+    let mut items = Vec::new();
+    for item in inventory.weapons.values() {
+        items.push(CharacterItemOwned::Weapon(item.clone()));
+    }
+    for item in inventory.armors.values() {
+        items.push(CharacterItemOwned::Armor(item.clone()));
+    }
+    for item in inventory.rings.values() {
+        items.push(CharacterItemOwned::Ring(item.clone()));
+    }
+    items.sort_by_cached_key(|item| item.get_receive_order_key());
+    items
+}
+
+fn get_equipment_id(item: &CharacterItemOwned) -> String {
+    item.get_receive_order_key().1
+}
+
+fn get_equipment_rarity(item: &CharacterItemOwned) -> ItemRarity {
+    match item {
+        CharacterItemOwned::Weapon(item) => item.rarity.clone(),
+        CharacterItemOwned::Armor(item) => item.rarity.clone(),
+        CharacterItemOwned::Ring(item) => item.rarity.clone(),
+        CharacterItemOwned::Consumable(item) => item.rarity.clone(),
+        CharacterItemOwned::Unknown => ItemRarity::Unknown,
+    }
+}
+
+fn is_equipped(item: &CharacterItemOwned, character: &PlayerCharacter) -> bool {
+    match item {
+        CharacterItemOwned::Weapon(item) => item.is_equipped(character),
+        CharacterItemOwned::Armor(item) => item.is_equipped(character),
+        CharacterItemOwned::Ring(item) => item.is_equipped(character),
+        _ => false,
+    }
+}
+
+fn is_locked(item: &CharacterItemOwned) -> bool {
+    match item {
+        CharacterItemOwned::Weapon(item) => item.locked,
+        CharacterItemOwned::Armor(item) => item.locked,
+        CharacterItemOwned::Ring(item) => item.locked,
+        _ => false,
+    }
+}
+
+/// Gives the drops of the defeated enemy. Returns messages telling what was received.
+fn give_enemy_drops(enemy: &Enemy, character: &mut PlayerCharacter) -> Vec<String> {
+    let mut messages = Vec::new();
+    match enemy.kind {
+        EnemyKind::Normal => {
+            let drops = give_normal_enemy_drops(character, enemy.level);
+            messages.push(tr!(
+                "bot.received_gold_and_exp",
+                gold = drops.gold,
+                exp = drops.exp
+            ));
+            messages.push(get_item_drop_message(&drops.equipment_item));
+            messages.extend(get_ingredient_drop_messages(&drops.ingredients));
+        }
+        EnemyKind::Boss => {
+            let drops = give_boss_enemy_drops(character, enemy.level, enemy.name);
+            messages.push(tr!(
+                "bot.received_gold_and_exp",
+                gold = drops.gold,
+                exp = drops.exp
+            ));
+            messages.push(tr!(
+                "bot.received_item",
+                item = drops.consumable_item.name,
+                amount = drops.consumable_item_amount
+            ));
+            for item in &drops.equipment_items {
+                messages.push(get_item_drop_message(item));
+            }
+            if drops.ancient_ruins_key {
+                messages.push(tr!("bot.received_ancient_ruins_key"));
+            }
+            messages.extend(get_ingredient_drop_messages(&drops.ingredients));
+        }
+        EnemyKind::Ancient => {
+            let drops = give_ancient_enemy_drops(character, enemy.level);
+            messages.push(tr!(
+                "bot.received_gold_and_exp",
+                gold = drops.gold,
+                exp = drops.exp
+            ));
+            messages.push(tr!(
+                "bot.received_item",
+                item = drops.consumable_item.name,
+                amount = drops.consumable_item_amount
+            ));
+            messages.push(get_item_drop_message(&drops.equipment_item));
+            messages.extend(get_ingredient_drop_messages(&drops.ingredients));
+        }
+    }
+    messages
+}
+
+fn get_item_drop_message(item: &ItemDrop) -> String {
+    tr!(
+        "bot.received_equipment",
+        item = item.name,
+        level = get_item_level_display(item.lvl)
+    )
+}
+
+fn get_ingredient_drop_messages(ingredients: &[(Ingredient, u32)]) -> Vec<String> {
+    ingredients
+        .iter()
+        .map(|(ingredient, amount)| tr!("bot.received_item", item = ingredient, amount = amount))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_observation_after_every_action() {
+        let input = concat!(
+            "{\"action\":\"new_character\",\"class\":\"Warrior\"}\n",
+            "\n",
+            "{\"action\":\"fly\"}\n",
+            "{\"action\":\"quit\"}\n",
+            "{\"action\":\"attack\"}\n",
+        );
+        let mut output = Vec::new();
+        run_bot(input.as_bytes(), &mut output).unwrap();
+
+        let observations: Vec<serde_json::Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(observations.len(), 3);
+        assert_eq!(observations[0]["state"], "new_character");
+        assert_eq!(observations[1]["state"], "dungeon");
+        assert_eq!(observations[1]["player"]["class"], "Warrior");
+        assert!(observations[2]["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("invalid action"));
+    }
+}
@@ -4,25 +4,25 @@ use crate::{
     config::GameConfig,
     game_data::{CharacterData, CombatStats},
     items::create_starter_weapon,
+    rng::game_rng,
     session::{Player, PlayerCharacter},
+    tr,
     validation::MAX_GAME_CHARACTERS,
 };
-use rand::{thread_rng, Rng};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 pub const BASE_EXP_MIN: u32 = 100;
 pub const BASE_EXP_MAX: u32 = 125;
 
 pub const SKILL_MANA_COST: u32 = 60;
-pub const SKILL_DESCRIPTION_MAGIC_PROJECTILE: &str =
-    "Deals 24% of the enemy's maximum health as pure damage";
-pub const SKILL_DESCRIPTION_RECOVER: &str = "Restores 45% of the player's maximum health points";
-pub const SKILL_DESCRIPTION_STEALTH: &str =
-    "Increases the player's critical damage multiplier by 0.4 for the rest of the fight";
-pub const SKILL_DESCRIPTION_BATTLE_CRY: &str =
-    "Increases the player's damage by 30% for the rest of the fight";
-pub const SKILL_DESCRIPTION_ARMOR_UP: &str =
-    "Increases the player's defense equal to the player's level for the rest of the fight";
+/// Maximum chance to evade enemy attacks.
+pub const MAX_EVASION: f64 = 0.50;
+pub const SKILL_DESCRIPTION_MAGIC_PROJECTILE: &str = "skill.magic_projectile.description";
+pub const SKILL_DESCRIPTION_RECOVER: &str = "skill.recover.description";
+pub const SKILL_DESCRIPTION_STEALTH: &str = "skill.stealth.description";
+pub const SKILL_DESCRIPTION_BATTLE_CRY: &str = "skill.battle_cry.description";
+pub const SKILL_DESCRIPTION_ARMOR_UP: &str = "skill.armor_up.description";
 
 pub const CLASS_MAGE_STARTING_STATS: CombatStats = CombatStats {
     max_health: 80,
@@ -104,7 +104,7 @@ pub struct CharacterStatsIncreaseLevelUp {
     pub damage: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CharacterClass {
     Mage,
     Cleric,
@@ -113,6 +113,22 @@ pub enum CharacterClass {
     Knight,
 }
 
+impl fmt::Display for CharacterClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Mage => tr!("class.mage"),
+                Self::Cleric => tr!("class.cleric"),
+                Self::Assassin => tr!("class.assassin"),
+                Self::Warrior => tr!("class.warrior"),
+                Self::Knight => tr!("class.knight"),
+            }
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum CharacterSkill {
     /// Mage
@@ -133,11 +149,11 @@ impl fmt::Display for CharacterSkill {
             f,
             "{}",
             match self {
-                Self::MagicProjectile => "Magic Projectile",
-                Self::Recover => "Recover",
-                Self::Stealth => "Stealth",
-                Self::BattleCry => "Battle Cry",
-                Self::ArmorUp => "Armor Up",
+                Self::MagicProjectile => tr!("skill.magic_projectile"),
+                Self::Recover => tr!("skill.recover"),
+                Self::Stealth => tr!("skill.stealth"),
+                Self::BattleCry => tr!("skill.battle_cry"),
+                Self::ArmorUp => tr!("skill.armor_up"),
             }
         )
     }
@@ -157,14 +173,14 @@ pub fn get_character_skill(class: &CharacterClass) -> CharacterSkill {
     }
 }
 
-pub fn get_character_skill_description(skill: &CharacterSkill) -> &str {
-    match skill {
+pub fn get_character_skill_description(skill: &CharacterSkill) -> String {
+    tr!(match skill {
         CharacterSkill::MagicProjectile => SKILL_DESCRIPTION_MAGIC_PROJECTILE,
         CharacterSkill::Recover => SKILL_DESCRIPTION_RECOVER,
         CharacterSkill::Stealth => SKILL_DESCRIPTION_STEALTH,
         CharacterSkill::BattleCry => SKILL_DESCRIPTION_BATTLE_CRY,
         CharacterSkill::ArmorUp => SKILL_DESCRIPTION_ARMOR_UP,
-    }
+    })
 }
 
 pub fn get_class_starting_stats(class: &CharacterClass) -> CombatStats {
@@ -183,12 +199,7 @@ pub fn create_new_game_character(
     player: &mut Player,
     cfg: &GameConfig,
 ) {
-    let character = CharacterData::new(name, class);
-    let mut player_character = PlayerCharacter::new(&character);
-    let weapon = create_starter_weapon(&player_character.data.metadata.class);
-    player_character.give_weapon(&weapon);
-    player_character.equip_weapon(&weapon.id);
-
+    let mut player_character = create_player_character(name, class);
     if cfg.dev_mode {
         player_character.give_test_items();
         player_character.give_gold(1500);
@@ -198,6 +209,16 @@ pub fn create_new_game_character(
     player.character = Some(player_character);
 }
 
+/// Creates a new level 1 character with the class's starter weapon equipped.
+pub fn create_player_character(name: &str, class: CharacterClass) -> PlayerCharacter {
+    let character = CharacterData::new(name, class);
+    let mut player_character = PlayerCharacter::new(&character);
+    let weapon = create_starter_weapon(&player_character.data.metadata.class);
+    player_character.give_weapon(&weapon);
+    player_character.equip_weapon(&weapon.id);
+    player_character
+}
+
 pub fn load_game_character(name: &str, player: &mut Player) {
     if let Some(character_data) = player.data.characters.get(name) {
         let mut character = PlayerCharacter::new(character_data);
@@ -228,7 +249,7 @@ pub fn delete_game_character(name: &str, player: &mut Player) -> bool {
 }
 
 pub fn random_exp_amount(min_exp: u32, max_exp: u32, multiplier: u32) -> u32 {
-    let mut rng = thread_rng();
+    let mut rng = game_rng();
     let base_exp = rng.gen_range(min_exp..=max_exp);
     return base_exp * multiplier;
 }
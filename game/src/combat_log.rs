@@ -0,0 +1,153 @@
+use chrono::Utc;
+use std::{
+    cell::Cell,
+    fs::OpenOptions,
+    io::{self, Write},
+    ops::Range,
+    path::PathBuf,
+};
+
+use crate::{
+    enemy::Enemy,
+    game_data::{get_config_subdir, SUBDIR_NAME},
+    layout::wrap_text,
+    replay::is_replay_session,
+    session::PlayerCharacter,
+    tr,
+    util::timestamp_to_datetime,
+};
+
+const COMBAT_LOG_FILE_NAME: &str = "combat_log.txt";
+
+thread_local! {
+    static COMBAT_LOG_FILE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Event in a fight.
+pub struct CombatLogEntry {
+    /// Player turn when the event happened. Starts from 1.
+    pub turn: u32,
+    pub text: String,
+}
+
+/// Every event of the current fight in the order they happened.
+pub struct CombatLog {
+    pub entries: Vec<CombatLogEntry>,
+    pub turn: u32,
+    /// Number of rows scrolled up from the newest events.
+    pub scroll: usize,
+}
+
+impl CombatLog {
+    /// Starts the log with the fighters of the fight.
+    pub fn new(enemy: &Enemy, character: &PlayerCharacter) -> Self {
+        let mut log = Self {
+            entries: Vec::new(),
+            turn: 1,
+            scroll: 0,
+        };
+        log.add(&tr!(
+            "combat_log.fight_started",
+            name = character.data.metadata.name,
+            level = character.data.stats.general_stats.character_level,
+            class = character.data.metadata.class,
+            enemy = enemy.get_display_name()
+        ));
+        log
+    }
+
+    /// Adds an event and scrolls to the newest events.
+    /// Empty texts are skipped.
+    pub fn add(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        self.entries.push(CombatLogEntry {
+            turn: self.turn,
+            text: text.to_string(),
+        });
+        self.scroll = 0;
+    }
+
+    /// Adds an action and its effect as one event.
+    pub fn add_action(&mut self, event: &str, effect: &str) {
+        let text = [event, effect]
+            .iter()
+            .filter(|text| !text.is_empty())
+            .copied()
+            .collect::<Vec<&str>>()
+            .join(" ");
+        self.add(&text);
+    }
+
+    pub fn next_turn(&mut self) {
+        self.turn += 1;
+    }
+
+    pub fn scroll_up(&mut self, rows: usize) {
+        self.scroll += rows;
+    }
+
+    pub fn scroll_down(&mut self, rows: usize) {
+        self.scroll = self.scroll.saturating_sub(rows);
+    }
+
+    /// Lines of the events wrapped to the width, e.g. `[3] Player attacked!`.
+    pub fn get_lines(&self, width: usize) -> Vec<String> {
+        let mut lines = Vec::new();
+        for entry in &self.entries {
+            let prefix = format!("[{}] ", entry.turn);
+            let indent = " ".repeat(prefix.chars().count());
+            let width = width.saturating_sub(prefix.chars().count());
+            for (i, line) in wrap_text(&entry.text, width).into_iter().enumerate() {
+                if i == 0 {
+                    lines.push(format!("{}{}", prefix, line));
+                } else {
+                    lines.push(format!("{}{}", indent, line));
+                }
+            }
+        }
+        lines
+    }
+
+    /// Range of the lines that fit in the rows with the current scroll.
+    /// Keeps the scroll within the lines.
+    pub fn get_visible_lines(&mut self, len: usize, rows: usize) -> Range<usize> {
+        let rows = rows.max(1);
+        self.scroll = self.scroll.min(len.saturating_sub(rows));
+        let end = len - self.scroll;
+        end.saturating_sub(rows)..end
+    }
+
+    /// Appends the fight to the combat log file if it is enabled.
+    /// Fights in replay sessions are not written.
+    pub fn write_to_file(&self) -> io::Result<()> {
+        if !is_combat_log_file_enabled() || is_replay_session() {
+            return Ok(());
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(get_combat_log_file_path()?)?;
+        writeln!(file, "{}", timestamp_to_datetime(Utc::now().timestamp()))?;
+        for entry in &self.entries {
+            writeln!(file, "[{}] {}", entry.turn, entry.text)?;
+        }
+        writeln!(file)?;
+
+        Ok(())
+    }
+}
+
+/// Sets if fights are appended to the combat log file.
+pub fn set_combat_log_file_enabled(enabled: bool) {
+    COMBAT_LOG_FILE.with(|current| current.set(enabled));
+}
+
+pub fn is_combat_log_file_enabled() -> bool {
+    COMBAT_LOG_FILE.with(|enabled| enabled.get())
+}
+
+pub fn get_combat_log_file_path() -> io::Result<PathBuf> {
+    Ok(get_config_subdir(SUBDIR_NAME)?.join(COMBAT_LOG_FILE_NAME))
+}
@@ -0,0 +1,312 @@
+use core::fmt;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enemy::Enemy, fight::is_critical_hit, rng::game_rng, session::PlayerCharacter, tr,
+    util::is_chance_success,
+};
+
+pub const COMPANION_STARTING_REQUIRED_EXP: u32 = 100;
+/// Chance for the companion to use its ability instead of attacking.
+pub const COMPANION_ABILITY_CHANCE: f64 = 0.35;
+/// Chance for the enemy to attack the companion instead of the player.
+pub const COMPANION_TARGETED_CHANCE: f64 = 0.20;
+/// Portion of the player's maximum health restored by the Heal ability.
+pub const COMPANION_HEAL_PERCENTAGE: f64 = 0.12;
+pub const COMPANION_BITE_DAMAGE_MULTIPLIER: f64 = 2.0;
+pub const COMPANION_BASE_PRICE: u32 = 300;
+pub const COMPANION_PRICE_PER_LEVEL: u32 = 100;
+
+pub const COMPANION_WOLF_STATS: CompanionStats = CompanionStats {
+    max_health: 40,
+    current_health: 40,
+    defense: 1,
+    damage: 8,
+};
+pub const COMPANION_FAIRY_STATS: CompanionStats = CompanionStats {
+    max_health: 30,
+    current_health: 30,
+    defense: 0,
+    damage: 4,
+};
+pub const COMPANION_GOLEM_STATS: CompanionStats = CompanionStats {
+    max_health: 70,
+    current_health: 70,
+    defense: 3,
+    damage: 5,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum CompanionKind {
+    Wolf,
+    Fairy,
+    Golem,
+}
+
+impl fmt::Display for CompanionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Wolf => tr!("companion.wolf"),
+            Self::Fairy => tr!("companion.fairy"),
+            Self::Golem => tr!("companion.golem"),
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompanionAbility {
+    /// Deals extra damage to the enemy.
+    Bite,
+    /// Restores the player's health.
+    Heal,
+    /// Makes the enemy attack the companion on its next turn.
+    Taunt,
+}
+
+impl fmt::Display for CompanionAbility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Bite => tr!("companion.ability.bite"),
+            Self::Heal => tr!("companion.ability.heal"),
+            Self::Taunt => tr!("companion.ability.taunt"),
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CompanionStats {
+    pub max_health: u32,
+    pub current_health: u32,
+    pub defense: u32,
+    pub damage: u32,
+}
+
+/// Companion that fights alongside the player.
+/// Companions level up from the same EXP the player gains.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Companion {
+    pub kind: CompanionKind,
+    pub level: u32,
+    pub current_exp: u32,
+    pub required_exp: u32,
+    pub stats: CompanionStats,
+    /// True if the enemy attacks the companion on its next turn.
+    #[serde(skip)]
+    pub taunting: bool,
+}
+
+impl Companion {
+    pub fn new(kind: CompanionKind, level: u32) -> Self {
+        let stats = match kind {
+            CompanionKind::Wolf => COMPANION_WOLF_STATS,
+            CompanionKind::Fairy => COMPANION_FAIRY_STATS,
+            CompanionKind::Golem => COMPANION_GOLEM_STATS,
+        };
+        let mut companion = Self {
+            kind,
+            level: 1,
+            current_exp: 0,
+            required_exp: COMPANION_STARTING_REQUIRED_EXP,
+            stats,
+            taunting: false,
+        };
+        while companion.level < level {
+            companion.level_up();
+            companion.required_exp = (companion.required_exp as f32 * 1.2).round() as u32;
+        }
+        companion.stats.current_health = companion.stats.max_health;
+        companion
+    }
+
+    pub fn get_ability(&self) -> CompanionAbility {
+        match self.kind {
+            CompanionKind::Wolf => CompanionAbility::Bite,
+            CompanionKind::Fairy => CompanionAbility::Heal,
+            CompanionKind::Golem => CompanionAbility::Taunt,
+        }
+    }
+
+    pub fn get_ability_description(&self) -> String {
+        match self.get_ability() {
+            CompanionAbility::Bite => tr!(
+                "companion.ability.bite.description",
+                multiplier = COMPANION_BITE_DAMAGE_MULTIPLIER
+            ),
+            CompanionAbility::Heal => tr!(
+                "companion.ability.heal.description",
+                percent = COMPANION_HEAL_PERCENTAGE * 100.0
+            ),
+            CompanionAbility::Taunt => tr!("companion.ability.taunt.description"),
+        }
+    }
+
+    pub fn get_display_name(&self) -> String {
+        tr!(
+            "companion.display_name",
+            name = self.kind,
+            level = self.level
+        )
+    }
+
+    pub fn get_purchase_value(&self) -> u32 {
+        COMPANION_BASE_PRICE + COMPANION_PRICE_PER_LEVEL * self.level
+    }
+
+    /// Returns true if the companion leveled up.
+    pub fn gain_exp(&mut self, exp: u32) -> bool {
+        let level = self.level;
+        self.current_exp += exp;
+        while self.current_exp >= self.required_exp {
+            self.current_exp -= self.required_exp;
+            self.required_exp = (self.required_exp as f32 * 1.2).round() as u32;
+            self.level_up();
+        }
+        self.level > level
+    }
+
+    fn level_up(&mut self) {
+        self.level += 1;
+        match self.kind {
+            CompanionKind::Wolf => {
+                self.stats.max_health += 8;
+                self.stats.damage += 3;
+            }
+            CompanionKind::Fairy => {
+                self.stats.max_health += 6;
+                self.stats.damage += 1;
+            }
+            CompanionKind::Golem => {
+                self.stats.max_health += 14;
+                self.stats.damage += 1;
+                self.stats.defense += 1;
+            }
+        }
+    }
+
+    /// Fully heals the companion at the start of fights.
+    pub fn prepare_for_fight(&mut self) {
+        self.stats.current_health = self.stats.max_health;
+        self.taunting = false;
+    }
+
+    /// Knocked out companions don't act until the next fight.
+    pub fn is_knocked_out(&self) -> bool {
+        self.stats.current_health == 0
+    }
+
+    /// Returns the amount of damage taken.
+    pub fn take_damage(&mut self, damage: u32) -> u32 {
+        let reduced_damage = damage.saturating_sub(self.stats.defense);
+        if reduced_damage >= self.stats.current_health {
+            self.stats.current_health = 0;
+        } else {
+            self.stats.current_health -= reduced_damage;
+        }
+        reduced_damage
+    }
+
+    /// Returns true if the enemy attacks the companion on its turn.
+    pub fn is_targeted(&self) -> bool {
+        !self.is_knocked_out() && (self.taunting || is_chance_success(COMPANION_TARGETED_CHANCE))
+    }
+
+    /// Acts on the companion's turn after the player.
+    /// Returns companion fight text.
+    pub fn act(&mut self, character: &mut PlayerCharacter, enemy: &mut Enemy) -> (String, String) {
+        if is_chance_success(COMPANION_ABILITY_CHANCE) {
+            match self.get_ability() {
+                CompanionAbility::Bite => {
+                    let damage =
+                        (self.stats.damage as f64 * COMPANION_BITE_DAMAGE_MULTIPLIER) as u32;
+                    let damage_taken = enemy.take_damage(damage);
+                    return (
+                        tr!(
+                            "fight.companion_used_ability",
+                            companion = self.kind,
+                            ability = CompanionAbility::Bite
+                        ),
+                        tr!("fight.enemy_took_damage", damage = damage_taken),
+                    );
+                }
+                CompanionAbility::Heal => {
+                    if character.temp_stats.current_health < character.get_total_health() {
+                        let amount = (character.get_total_health() as f64
+                            * COMPANION_HEAL_PERCENTAGE)
+                            as u32;
+                        let restored_health = character.restore_health(amount);
+                        return (
+                            tr!(
+                                "fight.companion_used_ability",
+                                companion = self.kind,
+                                ability = CompanionAbility::Heal
+                            ),
+                            tr!("fight.player_restored_health", health = restored_health),
+                        );
+                    }
+                }
+                CompanionAbility::Taunt => {
+                    if !self.taunting {
+                        self.taunting = true;
+                        return (
+                            tr!(
+                                "fight.companion_used_ability",
+                                companion = self.kind,
+                                ability = CompanionAbility::Taunt
+                            ),
+                            tr!("fight.companion_taunted"),
+                        );
+                    }
+                }
+            }
+        }
+        let damage_taken = enemy.take_damage(self.stats.damage);
+        (
+            tr!("fight.companion_attacked", companion = self.kind),
+            tr!("fight.enemy_took_damage", damage = damage_taken),
+        )
+    }
+
+    /// Takes the enemy's attack in place of the player.
+    /// Returns enemy fight text.
+    pub fn take_enemy_attack(&mut self, enemy: &Enemy) -> (String, String) {
+        self.taunting = false;
+        let (damage, critical_hit) = if is_critical_hit(enemy.get_total_crit_hit_rate()) {
+            (
+                enemy.get_crit_hit_damage(),
+                tr!("fight.critical_hit_suffix"),
+            )
+        } else {
+            (enemy.get_total_damage(), String::new())
+        };
+        let damage_taken = self.take_damage(damage);
+        let knocked_out = if self.is_knocked_out() {
+            tr!("fight.companion_knocked_out_suffix", companion = self.kind)
+        } else {
+            String::new()
+        };
+        (
+            tr!("fight.enemy_attacked_companion", companion = self.kind),
+            tr!(
+                "fight.companion_took_damage",
+                companion = self.kind,
+                damage = damage_taken,
+                critical_hit = critical_hit,
+                knocked_out = knocked_out
+            ),
+        )
+    }
+}
+
+pub fn generate_random_companion(level: u32) -> Companion {
+    let mut rng = game_rng();
+    let kind = match rng.gen_range(0..3) {
+        0 => CompanionKind::Wolf,
+        1 => CompanionKind::Fairy,
+        _ => CompanionKind::Golem,
+    };
+    Companion::new(kind, level)
+}
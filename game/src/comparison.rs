@@ -0,0 +1,79 @@
+use crate::{
+    items::{ArmorItem, RingItem, WeaponItem},
+    session::PlayerCharacter,
+};
+
+/// Character stats that change when equipment is swapped.
+#[derive(Clone, Copy)]
+pub struct EquipmentStats {
+    pub damage: u32,
+    pub crit_hit_rate: f64,
+    pub max_health: u32,
+    pub defense: u32,
+    pub max_mana: u32,
+}
+
+impl EquipmentStats {
+    pub fn from_character(character: &PlayerCharacter) -> Self {
+        Self {
+            damage: character.get_total_damage(),
+            crit_hit_rate: character.get_total_crit_hit_rate(),
+            max_health: character.get_total_health(),
+            defense: character.get_total_defense(),
+            max_mana: character.get_total_mana(),
+        }
+    }
+}
+
+/// Character stats with the currently equipped item and with the compared item.
+pub struct ItemComparison {
+    pub current: EquipmentStats,
+    pub new: EquipmentStats,
+}
+
+/// Compares the weapon against the equipped weapon.
+/// The weapon is equipped on a copy of the character so the stats
+/// are calculated the same way as when it is actually equipped.
+pub fn compare_weapon(character: &PlayerCharacter, item: &WeaponItem) -> ItemComparison {
+    let mut new_character = character.clone();
+    new_character
+        .data
+        .inventory
+        .weapons
+        .insert(item.id.clone(), item.clone());
+    new_character.equip_weapon(&item.id);
+    ItemComparison {
+        current: EquipmentStats::from_character(character),
+        new: EquipmentStats::from_character(&new_character),
+    }
+}
+
+/// Compares the armor against the equipped armor.
+pub fn compare_armor(character: &PlayerCharacter, item: &ArmorItem) -> ItemComparison {
+    let mut new_character = character.clone();
+    new_character
+        .data
+        .inventory
+        .armors
+        .insert(item.id.clone(), item.clone());
+    new_character.equip_armor(&item.id);
+    ItemComparison {
+        current: EquipmentStats::from_character(character),
+        new: EquipmentStats::from_character(&new_character),
+    }
+}
+
+/// Compares the ring against the equipped ring.
+pub fn compare_ring(character: &PlayerCharacter, item: &RingItem) -> ItemComparison {
+    let mut new_character = character.clone();
+    new_character
+        .data
+        .inventory
+        .rings
+        .insert(item.id.clone(), item.clone());
+    new_character.equip_ring(&item.id);
+    ItemComparison {
+        current: EquipmentStats::from_character(character),
+        new: EquipmentStats::from_character(&new_character),
+    }
+}
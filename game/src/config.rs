@@ -1,13 +1,81 @@
-use crate::util::is_dev_mode;
+use std::{env, io, path::PathBuf};
+
+use crate::{
+    i18n::{parse_locale, Locale},
+    util::is_dev_mode,
+};
 
 pub struct GameConfig {
     pub dev_mode: bool,
+    /// File where the inputs of the session are recorded.
+    pub record_file: Option<PathBuf>,
+    /// Replay file whose inputs are played back instead of starting a new session.
+    pub replay_file: Option<PathBuf>,
+    /// Plays with line-delimited JSON over stdin and stdout instead of the terminal UI.
+    pub bot_mode: bool,
+    /// Language set with a flag. Overrides the language of the environment.
+    pub locale: Option<Locale>,
+    /// Appends the events of every fight to the combat log file.
+    pub combat_log_file: bool,
 }
 
 impl GameConfig {
-    pub fn new() -> Self {
-        Self {
+    /// Creates the config from the environment and command line arguments.
+    pub fn new() -> io::Result<Self> {
+        let mut cfg = Self {
             dev_mode: is_dev_mode(),
+            record_file: None,
+            replay_file: None,
+            bot_mode: false,
+            locale: None,
+            combat_log_file: false,
+        };
+        let mut args = env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--record" => cfg.record_file = Some(get_flag_value(&arg, args.next())?),
+                "--replay" => cfg.replay_file = Some(get_flag_value(&arg, args.next())?),
+                "--bot" => cfg.bot_mode = true,
+                "--combat-log" => cfg.combat_log_file = true,
+                "--lang" => match args.next() {
+                    Some(code) => cfg.locale = Some(parse_locale(&code)?),
+                    None => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            "--lang requires a language code",
+                        ))
+                    }
+                },
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("unknown argument: {}", arg),
+                    ))
+                }
+            }
+        }
+        if cfg.record_file.is_some() && cfg.replay_file.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "--record and --replay can't be used at the same time",
+            ));
+        }
+        if cfg.bot_mode && (cfg.record_file.is_some() || cfg.replay_file.is_some()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "--bot can't be used with --record or --replay",
+            ));
         }
+        Ok(cfg)
+    }
+}
+
+fn get_flag_value(flag: &str, value: Option<String>) -> io::Result<PathBuf> {
+    match value {
+        Some(value) => Ok(PathBuf::from(value)),
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} requires a file path", flag),
+        )),
     }
 }
@@ -1,11 +1,14 @@
-use rand::{thread_rng, Rng};
+use rand::Rng;
+
+use crate::rng::game_rng;
 
 pub const BASE_GOLD_MIN: u32 = 75;
 pub const BASE_GOLD_MAX: u32 = 100;
 pub const GOLD_MULTIPLIER_TREASURE_CHEST: u32 = 2;
 
-pub fn random_gold_amount(min_gold: u32, max_gold: u32, multiplier: u32) -> u32 {
-    let mut rng = thread_rng();
+/// `gold_find` increases the amount of gold. E.g. gold find 0.2 gives 20% more gold.
+pub fn random_gold_amount(min_gold: u32, max_gold: u32, multiplier: u32, gold_find: f64) -> u32 {
+    let mut rng = game_rng();
     let base_gold = rng.gen_range(min_gold..=max_gold);
-    return base_gold * multiplier;
+    (base_gold as f64 * multiplier as f64 * (1.0 + gold_find)) as u32
 }
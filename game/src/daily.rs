@@ -0,0 +1,132 @@
+use chrono::Utc;
+
+use crate::{
+    character::{create_player_character, CharacterClass},
+    game_data::DailyResult,
+    items::{ConsumableItem, ItemRarity},
+    replay::get_session_date,
+    rng::hash_seed,
+    session::PlayerCharacter,
+};
+
+pub const DAILY_CHALLENGE_CHARACTER_NAME: &str = "Daily Challenger";
+pub const DAILY_CHALLENGE_CLASS: CharacterClass = CharacterClass::Warrior;
+pub const DAILY_CHALLENGE_LEVEL: u32 = 5;
+pub const DAILY_CHALLENGE_START_FLOOR: u32 = 3;
+/// Number of floors in a daily challenge run.
+pub const DAILY_CHALLENGE_FLOORS: u32 = 3;
+pub const DAILY_CHALLENGE_GOLD: u32 = 300;
+pub const DAILY_CHALLENGE_HEALTH_POTIONS: u32 = 3;
+pub const DAILY_CHALLENGE_MANA_POTIONS: u32 = 2;
+pub const DAILY_POINTS_PER_FLOOR: u32 = 1000;
+pub const DAILY_POINTS_PER_LEVEL: u32 = 100;
+
+/// Returns the current UTC date in format YYYY-MM-DD.
+/// All players get the same challenge on the same date.
+/// Recorded and replayed sessions use the date the recording was started.
+pub fn get_daily_date() -> String {
+    get_session_date().unwrap_or_else(|| Utc::now().format("%Y-%m-%d").to_string())
+}
+
+/// Returns the seed used to create the character of the challenge.
+pub fn get_daily_seed(date: &str) -> u64 {
+    hash_seed(date)
+}
+
+/// Each floor has its own seed so its layout, enemies and loot
+/// don't depend on what the player did on earlier floors.
+pub fn get_daily_floor_seed(date: &str, floor: u32) -> u64 {
+    hash_seed(&format!("{}/{}", date, floor))
+}
+
+/// Creates the temporary character used in daily challenges.
+/// The character is never saved.
+pub fn create_daily_character() -> PlayerCharacter {
+    let mut character =
+        create_player_character(DAILY_CHALLENGE_CHARACTER_NAME, DAILY_CHALLENGE_CLASS);
+    while character.data.stats.general_stats.character_level < DAILY_CHALLENGE_LEVEL {
+        character.data.stats.general_stats.current_exp =
+            character.data.stats.general_stats.required_exp;
+        character.level_up();
+    }
+    character.data.stats.general_stats.current_dungeon_floor = DAILY_CHALLENGE_START_FLOOR;
+
+    character.give_consumable(
+        &ConsumableItem::new_health_potion(ItemRarity::Common),
+        DAILY_CHALLENGE_HEALTH_POTIONS,
+    );
+    character.give_consumable(
+        &ConsumableItem::new_mana_potion(ItemRarity::Common),
+        DAILY_CHALLENGE_MANA_POTIONS,
+    );
+    character.give_gold(DAILY_CHALLENGE_GOLD);
+    character.restore_health(character.get_total_health());
+    character.restore_mana(character.get_total_mana());
+    character
+}
+
+pub fn get_daily_score(floors_cleared: u32, levels_gained: u32) -> u32 {
+    DAILY_POINTS_PER_FLOOR * floors_cleared + DAILY_POINTS_PER_LEVEL * levels_gained
+}
+
+/// Creates the result of a daily challenge run from the state of the daily character.
+pub fn create_daily_result(character: &PlayerCharacter) -> DailyResult {
+    let general_stats = &character.data.stats.general_stats;
+    let floors_cleared = general_stats
+        .current_dungeon_floor
+        .saturating_sub(DAILY_CHALLENGE_START_FLOOR)
+        .min(DAILY_CHALLENGE_FLOORS);
+    let levels_gained = general_stats
+        .character_level
+        .saturating_sub(DAILY_CHALLENGE_LEVEL);
+    DailyResult {
+        floors_cleared,
+        character_level: general_stats.character_level,
+        score: get_daily_score(floors_cleared, levels_gained),
+        attempts: 1,
+        recorded_at: Utc::now().timestamp(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        items::{generate_random_weapon, WEAPON_BASE_VALUES},
+        rng::{reset_game_rng, seed_game_rng},
+    };
+
+    const DATE: &str = "2024-06-01";
+
+    #[test]
+    fn floor_seeds_differ_by_floor_and_date() {
+        assert_eq!(get_daily_seed(DATE), hash_seed(DATE));
+        assert_eq!(get_daily_floor_seed(DATE, 3), hash_seed("2024-06-01/3"));
+        assert_ne!(get_daily_floor_seed(DATE, 3), get_daily_floor_seed(DATE, 4));
+        assert_ne!(
+            get_daily_floor_seed(DATE, 3),
+            get_daily_floor_seed("2024-06-02", 3)
+        );
+    }
+
+    #[test]
+    fn same_date_generates_same_loot() {
+        let generate = || {
+            seed_game_rng(get_daily_floor_seed(DATE, DAILY_CHALLENGE_START_FLOOR));
+            let weapon = generate_random_weapon(
+                ItemRarity::Epic,
+                WEAPON_BASE_VALUES,
+                DAILY_CHALLENGE_START_FLOOR,
+                &DAILY_CHALLENGE_CLASS,
+            );
+            reset_game_rng();
+            (
+                weapon.stats.damage,
+                weapon.stats.crit_hit_rate,
+                weapon.enchantments.len(),
+                weapon.name,
+            )
+        };
+        assert_eq!(generate(), generate());
+    }
+}
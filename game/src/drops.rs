@@ -1,4 +1,8 @@
 use crate::{
+    alchemy::{
+        give_random_ingredients, Ingredient, INGREDIENT_AMOUNT_ANCIENT_ENEMY,
+        INGREDIENT_AMOUNT_BOSS_ENEMY, INGREDIENT_DROP_RATE,
+    },
     character::{random_exp_amount, BASE_EXP_MAX, BASE_EXP_MIN},
     currency::{random_gold_amount, BASE_GOLD_MAX, BASE_GOLD_MIN, GOLD_MULTIPLIER_TREASURE_CHEST},
     enemy::{
@@ -7,9 +11,10 @@ use crate::{
     },
     items::{
         generate_random_armor, generate_random_consumable, generate_random_ring,
-        generate_random_weapon, get_item_display_name, random_equipment_item, random_item_rarity,
-        CharacterItem, ItemCategory, ItemRarity, ARMOR_BASE_VALUES, ITEM_RARITY_DROP_RATES,
-        RING_BASE_VALUES, WEAPON_BASE_VALUES,
+        generate_random_weapon, generate_set_armor, generate_set_ring, generate_set_weapon,
+        get_boss_item_set, get_item_display_name, random_equipment_item, random_item_rarity,
+        CharacterItem, ItemCategory, ItemRarity, ItemSet, ARMOR_BASE_VALUES,
+        ITEM_RARITY_DROP_RATES, RING_BASE_VALUES, SET_ITEM_DROP_RATE, WEAPON_BASE_VALUES,
     },
     session::PlayerCharacter,
     util::is_chance_success,
@@ -27,6 +32,7 @@ pub struct NormalEnemyDrops {
     pub gold: u32,
     pub exp: u32,
     pub equipment_item: ItemDrop,
+    pub ingredients: Vec<(Ingredient, u32)>,
 }
 
 pub struct BossEnemyDrops {
@@ -36,6 +42,7 @@ pub struct BossEnemyDrops {
     pub consumable_item: ItemDrop,
     pub consumable_item_amount: u32,
     pub ancient_ruins_key: bool,
+    pub ingredients: Vec<(Ingredient, u32)>,
 }
 
 pub struct AncientEnemyDrops {
@@ -44,27 +51,37 @@ pub struct AncientEnemyDrops {
     pub equipment_item: ItemDrop,
     pub consumable_item: ItemDrop,
     pub consumable_item_amount: u32,
+    pub ingredients: Vec<(Ingredient, u32)>,
 }
 
 pub struct TreasureChestDrops {
     pub gold: u32,
     pub equipment_item: ItemDrop,
+    pub ingredients: Vec<(Ingredient, u32)>,
 }
 
 pub fn give_normal_enemy_drops(
     character: &mut PlayerCharacter,
     enemy_level: u32,
 ) -> NormalEnemyDrops {
-    let gold = random_gold_amount(BASE_GOLD_MIN, BASE_GOLD_MAX, GOLD_MULTIPLIER_NORMAL_ENEMY);
+    let gold = random_gold_amount(
+        BASE_GOLD_MIN,
+        BASE_GOLD_MAX,
+        GOLD_MULTIPLIER_NORMAL_ENEMY,
+        character.get_gold_find(),
+    );
     character.give_gold(gold);
-    let exp = random_exp_amount(BASE_EXP_MIN, BASE_EXP_MAX, EXP_MULTIPLIER_NORMAL_ENEMY);
-    character.gain_exp(exp);
+    let exp = character.gain_exp(random_exp_amount(
+        BASE_EXP_MIN,
+        BASE_EXP_MAX,
+        EXP_MULTIPLIER_NORMAL_ENEMY,
+    ));
 
     let equipment_item_category = random_equipment_item();
     let equipment_item = match equipment_item_category {
         ItemCategory::Weapon => {
             let weapon = generate_random_weapon(
-                random_item_rarity(&ITEM_RARITY_DROP_RATES),
+                random_item_rarity(&ITEM_RARITY_DROP_RATES, character.get_magic_find()),
                 WEAPON_BASE_VALUES,
                 enemy_level,
                 &character.data.metadata.class,
@@ -78,7 +95,7 @@ pub fn give_normal_enemy_drops(
         }
         ItemCategory::Armor => {
             let armor = generate_random_armor(
-                random_item_rarity(&ITEM_RARITY_DROP_RATES),
+                random_item_rarity(&ITEM_RARITY_DROP_RATES, character.get_magic_find()),
                 ARMOR_BASE_VALUES,
                 enemy_level,
             );
@@ -91,7 +108,7 @@ pub fn give_normal_enemy_drops(
         }
         ItemCategory::Ring => {
             let ring = generate_random_ring(
-                random_item_rarity(&ITEM_RARITY_DROP_RATES),
+                random_item_rarity(&ITEM_RARITY_DROP_RATES, character.get_magic_find()),
                 RING_BASE_VALUES,
                 enemy_level,
             );
@@ -109,18 +126,36 @@ pub fn give_normal_enemy_drops(
         },
     };
 
+    let mut ingredients = Vec::new();
+    if is_chance_success(INGREDIENT_DROP_RATE) {
+        ingredients = give_random_ingredients(character, 1);
+    }
+
     NormalEnemyDrops {
         gold,
         exp,
         equipment_item,
+        ingredients,
     }
 }
 
-pub fn give_boss_enemy_drops(character: &mut PlayerCharacter, enemy_level: u32) -> BossEnemyDrops {
-    let gold = random_gold_amount(BASE_GOLD_MIN, BASE_GOLD_MAX, GOLD_MULTIPLIER_BOSS_ENEMY);
+pub fn give_boss_enemy_drops(
+    character: &mut PlayerCharacter,
+    enemy_level: u32,
+    enemy_name: &str,
+) -> BossEnemyDrops {
+    let gold = random_gold_amount(
+        BASE_GOLD_MIN,
+        BASE_GOLD_MAX,
+        GOLD_MULTIPLIER_BOSS_ENEMY,
+        character.get_gold_find(),
+    );
     character.give_gold(gold);
-    let exp = random_exp_amount(BASE_EXP_MIN, BASE_EXP_MAX, EXP_MULTIPLIER_BOSS_ENEMY);
-    character.gain_exp(exp);
+    let exp = character.gain_exp(random_exp_amount(
+        BASE_EXP_MIN,
+        BASE_EXP_MAX,
+        EXP_MULTIPLIER_BOSS_ENEMY,
+    ));
 
     let mut equipment_drops = Vec::new();
     for _ in 0..2 {
@@ -128,7 +163,7 @@ pub fn give_boss_enemy_drops(character: &mut PlayerCharacter, enemy_level: u32)
         match equipment_item_category {
             ItemCategory::Weapon => {
                 let weapon = generate_random_weapon(
-                    random_item_rarity(&ITEM_RARITY_DROP_RATES),
+                    random_item_rarity(&ITEM_RARITY_DROP_RATES, character.get_magic_find()),
                     WEAPON_BASE_VALUES,
                     enemy_level,
                     &character.data.metadata.class,
@@ -142,7 +177,7 @@ pub fn give_boss_enemy_drops(character: &mut PlayerCharacter, enemy_level: u32)
             }
             ItemCategory::Armor => {
                 let armor = generate_random_armor(
-                    random_item_rarity(&ITEM_RARITY_DROP_RATES),
+                    random_item_rarity(&ITEM_RARITY_DROP_RATES, character.get_magic_find()),
                     ARMOR_BASE_VALUES,
                     enemy_level,
                 );
@@ -155,7 +190,7 @@ pub fn give_boss_enemy_drops(character: &mut PlayerCharacter, enemy_level: u32)
             }
             ItemCategory::Ring => {
                 let ring = generate_random_ring(
-                    random_item_rarity(&ITEM_RARITY_DROP_RATES),
+                    random_item_rarity(&ITEM_RARITY_DROP_RATES, character.get_magic_find()),
                     RING_BASE_VALUES,
                     enemy_level,
                 );
@@ -169,12 +204,18 @@ pub fn give_boss_enemy_drops(character: &mut PlayerCharacter, enemy_level: u32)
             _ => {}
         }
     }
+    if let Some(set) = get_boss_item_set(enemy_name) {
+        if is_chance_success(SET_ITEM_DROP_RATE) {
+            equipment_drops.push(give_set_item_drop(character, set, enemy_level));
+        }
+    }
     let consumable = generate_random_consumable();
     character.give_consumable(&consumable, 1);
     let ancient_ruins_key = is_chance_success(ANCIENT_RUINS_KEY_DROP_RATE);
     if ancient_ruins_key {
         character.give_ancient_ruins_key(1);
     }
+    let ingredients = give_random_ingredients(character, INGREDIENT_AMOUNT_BOSS_ENEMY);
 
     BossEnemyDrops {
         gold,
@@ -187,6 +228,40 @@ pub fn give_boss_enemy_drops(character: &mut PlayerCharacter, enemy_level: u32)
         },
         consumable_item_amount: 1,
         ancient_ruins_key,
+        ingredients,
+    }
+}
+
+/// Gives a random piece of an item set.
+fn give_set_item_drop(character: &mut PlayerCharacter, set: ItemSet, enemy_level: u32) -> ItemDrop {
+    match random_equipment_item() {
+        ItemCategory::Armor => {
+            let armor = generate_set_armor(set, enemy_level);
+            character.give_armor(&armor);
+            ItemDrop {
+                name: get_item_display_name(CharacterItem::Armor(&armor)),
+                rarity: armor.rarity,
+                lvl: armor.level,
+            }
+        }
+        ItemCategory::Ring => {
+            let ring = generate_set_ring(set, enemy_level);
+            character.give_ring(&ring);
+            ItemDrop {
+                name: get_item_display_name(CharacterItem::Ring(&ring)),
+                rarity: ring.rarity,
+                lvl: ring.level,
+            }
+        }
+        _ => {
+            let weapon = generate_set_weapon(set, enemy_level, &character.data.metadata.class);
+            character.give_weapon(&weapon);
+            ItemDrop {
+                name: get_item_display_name(CharacterItem::Weapon(&weapon)),
+                rarity: weapon.rarity,
+                lvl: weapon.level,
+            }
+        }
     }
 }
 
@@ -194,10 +269,18 @@ pub fn give_ancient_enemy_drops(
     character: &mut PlayerCharacter,
     enemy_level: u32,
 ) -> AncientEnemyDrops {
-    let gold = random_gold_amount(BASE_GOLD_MIN, BASE_GOLD_MAX, GOLD_MULTIPLIER_ANCIENT_ENEMY);
+    let gold = random_gold_amount(
+        BASE_GOLD_MIN,
+        BASE_GOLD_MAX,
+        GOLD_MULTIPLIER_ANCIENT_ENEMY,
+        character.get_gold_find(),
+    );
     character.give_gold(gold);
-    let exp = random_exp_amount(BASE_EXP_MIN, BASE_EXP_MAX, EXP_MULTIPLIER_ANCIENT_ENEMY);
-    character.gain_exp(exp);
+    let exp = character.gain_exp(random_exp_amount(
+        BASE_EXP_MIN,
+        BASE_EXP_MAX,
+        EXP_MULTIPLIER_ANCIENT_ENEMY,
+    ));
 
     let equipment_item_category = random_equipment_item();
     let equipment_item = match equipment_item_category {
@@ -241,6 +324,7 @@ pub fn give_ancient_enemy_drops(
     };
     let consumable = generate_random_consumable();
     character.give_consumable(&consumable, 3);
+    let ingredients = give_random_ingredients(character, INGREDIENT_AMOUNT_ANCIENT_ENEMY);
 
     AncientEnemyDrops {
         gold,
@@ -252,6 +336,7 @@ pub fn give_ancient_enemy_drops(
             lvl: 1,
         },
         consumable_item_amount: 3,
+        ingredients,
     }
 }
 
@@ -259,14 +344,19 @@ pub fn give_treasure_chest_drops(
     character: &mut PlayerCharacter,
     level: u32,
 ) -> TreasureChestDrops {
-    let gold = random_gold_amount(BASE_GOLD_MIN, BASE_GOLD_MAX, GOLD_MULTIPLIER_TREASURE_CHEST);
+    let gold = random_gold_amount(
+        BASE_GOLD_MIN,
+        BASE_GOLD_MAX,
+        GOLD_MULTIPLIER_TREASURE_CHEST,
+        character.get_gold_find(),
+    );
     character.give_gold(gold);
 
     let equipment_item_category = random_equipment_item();
     let equipment_item = match equipment_item_category {
         ItemCategory::Weapon => {
             let weapon = generate_random_weapon(
-                random_item_rarity(&ITEM_RARITY_DROP_RATES),
+                random_item_rarity(&ITEM_RARITY_DROP_RATES, character.get_magic_find()),
                 WEAPON_BASE_VALUES,
                 level,
                 &character.data.metadata.class,
@@ -280,7 +370,7 @@ pub fn give_treasure_chest_drops(
         }
         ItemCategory::Armor => {
             let armor = generate_random_armor(
-                random_item_rarity(&ITEM_RARITY_DROP_RATES),
+                random_item_rarity(&ITEM_RARITY_DROP_RATES, character.get_magic_find()),
                 ARMOR_BASE_VALUES,
                 level,
             );
@@ -293,7 +383,7 @@ pub fn give_treasure_chest_drops(
         }
         ItemCategory::Ring => {
             let ring = generate_random_ring(
-                random_item_rarity(&ITEM_RARITY_DROP_RATES),
+                random_item_rarity(&ITEM_RARITY_DROP_RATES, character.get_magic_find()),
                 RING_BASE_VALUES,
                 level,
             );
@@ -311,8 +401,14 @@ pub fn give_treasure_chest_drops(
         },
     };
 
+    let mut ingredients = Vec::new();
+    if is_chance_success(INGREDIENT_DROP_RATE) {
+        ingredients = give_random_ingredients(character, 1);
+    }
+
     TreasureChestDrops {
         gold,
         equipment_item,
+        ingredients,
     }
 }
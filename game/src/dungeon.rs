@@ -1,18 +1,27 @@
 use crate::{
     character::CharacterClass,
+    companion::{generate_random_companion, Companion},
     enemy::{
         generate_random_ancient_enemy, generate_random_boss_enemy, generate_random_normal_enemy,
         Enemy,
     },
-    shop::{generate_shop_items, ShopItems},
+    quest::{generate_quest_board, Quest},
+    rng::game_rng,
+    shop::{generate_merchant_items, generate_shop_items, ShopItems, WanderingMerchant},
 };
-use rand::{thread_rng, Rng};
+use rand::Rng;
 use std::collections::HashMap;
 
 pub const NORMAL_ENEMIES_PER_FLOOR: u32 = 3;
 pub const MIN_ROOMS_FOR_BOSS_ENTRANCE: u32 = 6;
 /// Increasing this makes dungeon floors have more rooms.
 pub const FLOOR_LENGTH_SCALE: u32 = 9;
+/// Chance for a companion to wait in a room on a dungeon floor.
+pub const COMPANION_ROOM_CHANCE: f64 = 0.25;
+/// Chance for a wandering merchant to appear on a dungeon floor.
+pub const WANDERING_MERCHANT_CHANCE: f64 = 0.3;
+/// Chance for a wandering merchant to trade Ancient Ruins Keys.
+pub const MERCHANT_KEY_TRADE_CHANCE: f64 = 0.4;
 
 pub struct DungeonFloor {
     pub floor: u32,
@@ -20,6 +29,10 @@ pub struct DungeonFloor {
     pub boss: Option<Enemy>,
     pub shop_items: ShopItems,
     pub ancient_ruins: bool,
+    /// Quests offered on the bounty board in the start room.
+    pub quest_board: Vec<Quest>,
+    /// Merchant that leaves the floor after one visit.
+    pub merchant: Option<WanderingMerchant>,
 }
 
 impl DungeonFloor {
@@ -36,6 +49,8 @@ impl DungeonFloor {
             boss: Some(boss),
             shop_items,
             ancient_ruins,
+            quest_board: generate_quest_board(floor),
+            merchant: None,
         }
     }
 
@@ -73,6 +88,8 @@ pub struct Room {
     pub adjacents: AdjacentRooms,
     pub enemy: Option<Enemy>,
     pub treasure: bool,
+    /// Companion the player can take along.
+    pub companion: Option<Companion>,
     pub room_num: u32,
 }
 
@@ -97,6 +114,7 @@ impl Room {
             },
             enemy: None,
             treasure: false,
+            companion: None,
             room_num: num,
         }
     }
@@ -135,9 +153,13 @@ pub fn generate_random_dungeon_floor(floor: u32, class: &CharacterClass) -> Dung
     generate_random_rooms(start_room, &mut rooms, FLOOR_LENGTH_SCALE);
     randomize_treasure_room(&mut rooms);
     randomize_enemy_rooms(&mut rooms, NORMAL_ENEMIES_PER_FLOOR, floor);
+    randomize_companion_room(&mut rooms, floor);
     let boss = generate_random_boss_enemy(floor);
     let shop_items = generate_shop_items(floor, class);
-    return DungeonFloor::new(floor, rooms, boss, shop_items, false);
+    let merchant = randomize_wandering_merchant(&rooms, floor, class);
+    let mut dungeon_floor = DungeonFloor::new(floor, rooms, boss, shop_items, false);
+    dungeon_floor.merchant = merchant;
+    dungeon_floor
 }
 
 pub fn generate_ancient_ruins(floor: u32, class: &CharacterClass) -> DungeonFloor {
@@ -206,7 +228,7 @@ fn generate_random_rooms(
     rooms: &mut HashMap<RoomCoordinates, Room>,
     length_scale: u32,
 ) {
-    let mut rng = thread_rng();
+    let mut rng = game_rng();
     let mut rooms_generated = 1;
     let mut boss_entrance_generated = false;
     let mut boss_room_generated = false;
@@ -351,20 +373,79 @@ fn generate_random_rooms(
     }
 }
 
+/// Returns the rooms ordered by their coordinates.
+/// The room map has no fixed order, so this keeps seeded floors the same.
+fn rooms_in_order(rooms: &HashMap<RoomCoordinates, Room>) -> Vec<&Room> {
+    let mut ordered: Vec<&Room> = rooms.values().collect();
+    ordered.sort_by_key(|room| (room.coords.x, room.coords.y));
+    ordered
+}
+
+/// Places a companion in a random room if one appears on the floor.
+fn randomize_companion_room(rooms: &mut HashMap<RoomCoordinates, Room>, floor: u32) {
+    let mut rng = game_rng();
+    if !rng.gen_bool(COMPANION_ROOM_CHANCE) {
+        return;
+    }
+    let mut temp_rooms = Vec::new();
+    for room in rooms_in_order(rooms) {
+        match room.kind {
+            RoomKind::Start | RoomKind::BossEntrance | RoomKind::Boss => continue,
+            _ => temp_rooms.push(room.coords.clone()),
+        }
+    }
+    if temp_rooms.is_empty() {
+        return;
+    }
+    let rand_num = rng.gen_range(0..temp_rooms.len());
+    if let Some(room) = rooms.get_mut(&temp_rooms[rand_num]) {
+        room.companion = Some(generate_random_companion(floor));
+    }
+}
+
+/// Places a wandering merchant in a random room if one appears on the floor.
+fn randomize_wandering_merchant(
+    rooms: &HashMap<RoomCoordinates, Room>,
+    floor: u32,
+    class: &CharacterClass,
+) -> Option<WanderingMerchant> {
+    let mut rng = game_rng();
+    if !rng.gen_bool(WANDERING_MERCHANT_CHANCE) {
+        return None;
+    }
+    let mut temp_rooms = Vec::new();
+    for room in rooms_in_order(rooms) {
+        match room.kind {
+            // the merchant doesn't visit these rooms
+            RoomKind::Start | RoomKind::BossEntrance | RoomKind::Boss => continue,
+            _ => temp_rooms.push(room.coords.clone()),
+        }
+    }
+    if temp_rooms.is_empty() {
+        return None;
+    }
+    let rand_num = rng.gen_range(0..temp_rooms.len());
+    Some(WanderingMerchant {
+        room: temp_rooms.swap_remove(rand_num),
+        shop_items: generate_merchant_items(floor, class),
+        key_trade: rng.gen_bool(MERCHANT_KEY_TRADE_CHANCE),
+    })
+}
+
 fn randomize_enemy_rooms(
     rooms: &mut HashMap<RoomCoordinates, Room>,
     enemies_per_floor: u32,
     dungeon_floor: u32,
 ) {
     let mut temp_rooms = Vec::new();
-    for room in rooms.values() {
+    for room in rooms_in_order(rooms) {
         match room.kind {
             // don't spawn normal enemies in these rooms
             RoomKind::Start | RoomKind::BossEntrance | RoomKind::Boss => continue,
             _ => temp_rooms.push(room.clone()),
         }
     }
-    let mut rng = thread_rng();
+    let mut rng = game_rng();
     for _ in 0..enemies_per_floor {
         loop {
             let rand_num = rng.gen_range(0..temp_rooms.len());
@@ -386,13 +467,13 @@ fn randomize_enemy_rooms(
 
 fn randomize_treasure_room(rooms: &mut HashMap<RoomCoordinates, Room>) {
     let mut temp_rooms = Vec::new();
-    for room in rooms.values() {
+    for room in rooms_in_order(rooms) {
         match room.kind {
             RoomKind::Start | RoomKind::BossEntrance => continue,
             _ => temp_rooms.push(room.clone()),
         }
     }
-    let mut rng = thread_rng();
+    let mut rng = game_rng();
     let rand_num = rng.gen_range(0..temp_rooms.len());
     let treasure_room = temp_rooms[rand_num].clone();
     if let Some(room) = rooms.get_mut(&treasure_room.coords) {
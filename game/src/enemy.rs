@@ -1,6 +1,11 @@
-use rand::{thread_rng, Rng};
+use core::fmt;
+use rand::Rng;
 
-use crate::{fight::is_critical_hit, session::PlayerCharacter};
+use crate::{
+    rng::game_rng,
+    tr,
+    {fight::is_critical_hit, session::PlayerCharacter},
+};
 
 pub const EXP_MULTIPLIER_NORMAL_ENEMY: u32 = 1;
 pub const EXP_MULTIPLIER_BOSS_ENEMY: u32 = 3;
@@ -67,6 +72,23 @@ pub enum EnemySkill {
     Unknown,
 }
 
+impl fmt::Display for EnemySkill {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Smash => tr!("enemy.skill.smash"),
+                Self::FireBreath => tr!("enemy.skill.fire_breath"),
+                Self::StatusAilment => tr!("enemy.skill.status_ailment"),
+                Self::DivineBlessing => tr!("enemy.skill.divine_blessing"),
+                Self::Blackhole => tr!("enemy.skill.blackhole"),
+                Self::Unknown => tr!("enemy.skill.unknown"),
+            }
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Enemy {
     pub name: &'static str,
@@ -159,10 +181,15 @@ impl Enemy {
     }
 
     pub fn get_display_name(&self) -> String {
+        let name = get_enemy_display_name(self.name);
         match self.kind {
-            EnemyKind::Ancient => format!("{} [Ancient Boss] (Level {})", self.name, self.level),
-            EnemyKind::Normal => format!("{} (Level {})", self.name, self.level),
-            EnemyKind::Boss => format!("{} [Boss] (Level {})", self.name, self.level),
+            EnemyKind::Ancient => tr!(
+                "enemy.display_name.ancient",
+                name = name,
+                level = self.level
+            ),
+            EnemyKind::Normal => tr!("enemy.display_name.normal", name = name, level = self.level),
+            EnemyKind::Boss => tr!("enemy.display_name.boss", name = name, level = self.level),
         }
     }
 
@@ -249,55 +276,72 @@ impl Enemy {
     }
 
     /// Returns enemy fight text.
-    pub fn attack_player(&self, character: &mut PlayerCharacter) -> (&str, String) {
-        if is_critical_hit(self.get_total_crit_hit_rate()) {
-            let damage_taken = character.take_damage(self.get_crit_hit_damage());
+    pub fn attack_player(&mut self, character: &mut PlayerCharacter) -> (String, String) {
+        if character.evades_attack() {
+            return (tr!("fight.enemy_attacked"), tr!("fight.player_evaded"));
+        }
+        let (damage, critical_hit) = if is_critical_hit(self.get_total_crit_hit_rate()) {
+            (self.get_crit_hit_damage(), tr!("fight.critical_hit_suffix"))
+        } else {
+            (self.get_total_damage(), String::new())
+        };
+        let (damage_taken, thorns_damage) = character.take_damage(damage, self);
+        if thorns_damage > 0 {
             return (
-                "Enemy attacked!",
-                format!("Player took {} damage (Critical Hit)", damage_taken),
+                tr!("fight.enemy_attacked"),
+                tr!(
+                    "fight.player_took_damage_thorns",
+                    damage = damage_taken,
+                    critical_hit = critical_hit,
+                    thorns_damage = thorns_damage
+                ),
             );
         }
-        let damage_taken = character.take_damage(self.get_total_damage());
-        return (
-            "Enemy attacked!",
-            format!("Player took {} damage", damage_taken),
-        );
+        (
+            tr!("fight.enemy_attacked"),
+            tr!(
+                "fight.player_took_damage_critical",
+                damage = damage_taken,
+                critical_hit = critical_hit
+            ),
+        )
     }
 
-    pub fn use_skill(&mut self, character: &mut PlayerCharacter) -> (&str, String) {
+    pub fn use_skill(&mut self, character: &mut PlayerCharacter) -> (String, String) {
         if let Some(skill) = &self.skill {
+            let skill_used = tr!("fight.enemy_used_skill", skill = skill);
             match skill {
                 EnemySkill::Smash => {
                     let damage = (character.get_total_health() as f64 * 0.24) as u32;
                     let damage_taken = character.take_pure_damage(damage);
                     return (
-                        "Enemy used skill Smash!",
-                        format!("Player took {} damage", damage_taken),
+                        skill_used,
+                        tr!("fight.player_took_damage", damage = damage_taken),
                     );
                 }
                 EnemySkill::FireBreath => {
                     let damage = (character.get_total_health() as f64 * 0.12) as u32;
                     let damage_taken = character.take_pure_damage(damage);
-                    let reduced_defense = 2 * self.level;
-                    character.temp_stat_boosts.decrease_defense(reduced_defense);
+                    let reduced_defense = character.reduce_defense_by_enemy(2 * self.level);
                     return (
-                        "Enemy used skill Fire Breath!",
-                        format!(
-                            "Player took {} damage. Player's defense was reduced by {}",
-                            damage_taken, reduced_defense
+                        skill_used,
+                        tr!(
+                            "fight.fire_breath",
+                            damage = damage_taken,
+                            defense = reduced_defense
                         ),
                     );
                 }
                 EnemySkill::StatusAilment => {
-                    let reduced_damage = self.level;
+                    let reduced_damage = character.reduce_damage_by_enemy(self.level);
                     let reduced_mana = 20;
-                    character.temp_stat_boosts.decrease_damage(reduced_damage);
                     character.consume_mana(reduced_mana);
                     return (
-                        "Enemy used skill Status Ailment!",
-                        format!(
-                            "Player's damage was reduced by {}. Player's mana was reduced by {}",
-                            reduced_damage, reduced_mana
+                        skill_used,
+                        tr!(
+                            "fight.status_ailment",
+                            damage = reduced_damage,
+                            mana = reduced_mana
                         ),
                     );
                 }
@@ -307,10 +351,11 @@ impl Enemy {
                     let increased_damage = self.level / 2;
                     self.increase_damage(increased_damage);
                     return (
-                        "Enemy used skill Divine Blessing!",
-                        format!(
-                            "Enemy restored {} health points. Enemy's damage was increased by {}",
-                            restored_health, increased_damage
+                        skill_used,
+                        tr!(
+                            "fight.divine_blessing",
+                            health = restored_health,
+                            damage = increased_damage
                         ),
                     );
                 }
@@ -319,25 +364,19 @@ impl Enemy {
                     let damage_taken = character.take_pure_damage(damage);
                     let reduced_mana = character.get_total_mana();
                     character.consume_mana(reduced_mana);
-                    return (
-                        "Enemy used skill Blackhole!",
-                        format!(
-                            "Player's mana was sucked into the void! Player took {} damage",
-                            damage_taken
-                        ),
-                    );
+                    return (skill_used, tr!("fight.blackhole", damage = damage_taken));
                 }
                 _ => {
                     return (
-                        "Enemy tried to use an unknown skill",
-                        "Nothing happened".to_string(),
+                        tr!("fight.enemy_unknown_skill"),
+                        tr!("fight.nothing_happened"),
                     )
                 }
             }
         }
         return (
-            "Enemy tried to use skill",
-            "But it doesn't have one".to_string(),
+            tr!("fight.enemy_tried_skill"),
+            tr!("fight.enemy_has_no_skill"),
         );
     }
 }
@@ -365,8 +404,24 @@ pub enum EnemyKind {
     Normal,
 }
 
+/// Translated name of an enemy. Enemy names are kept in English in the game logic.
+pub fn get_enemy_display_name(name: &str) -> String {
+    match name {
+        NORMAL_ENEMY_NAME_SKELETON => tr!("enemy.skeleton"),
+        NORMAL_ENEMY_NAME_GOBLIN => tr!("enemy.goblin"),
+        NORMAL_ENEMY_NAME_OGRE => tr!("enemy.ogre"),
+        NORMAL_ENEMY_NAME_GOLEM => tr!("enemy.golem"),
+        BOSS_ENEMY_NAME_OGRE_KING => tr!("enemy.ogre_king"),
+        BOSS_ENEMY_NAME_FIRE_DRAGON => tr!("enemy.fire_dragon"),
+        BOSS_ENEMY_NAME_UNDEAD_SORCERER => tr!("enemy.undead_sorcerer"),
+        ANCIENT_ENEMY_NAME_KNIGHT => tr!("enemy.ancient_knight"),
+        ANCIENT_ENEMY_NAME_MAGE => tr!("enemy.ancient_mage"),
+        _ => name.to_string(),
+    }
+}
+
 pub fn generate_random_normal_enemy(dungeon_floor: u32) -> Enemy {
-    let mut rng = thread_rng();
+    let mut rng = game_rng();
     let index = rng.gen_range(0..NORMAL_ENEMY_NAMES.len());
     let name = NORMAL_ENEMY_NAMES[index];
     let base_stats = match name {
@@ -380,14 +435,14 @@ pub fn generate_random_normal_enemy(dungeon_floor: u32) -> Enemy {
 }
 
 pub fn generate_random_boss_enemy(dungeon_floor: u32) -> Enemy {
-    let mut rng = thread_rng();
+    let mut rng = game_rng();
     let index = rng.gen_range(0..BOSS_ENEMY_NAMES.len());
     let name = BOSS_ENEMY_NAMES[index];
     Enemy::new_boss(dungeon_floor, name, &BOSS_ENEMY_BASE_STATS)
 }
 
 pub fn generate_random_ancient_enemy(level: u32) -> Enemy {
-    let mut rng = thread_rng();
+    let mut rng = game_rng();
     let index = rng.gen_range(0..ANCIENT_ENEMY_NAMES.len());
     let name = ANCIENT_ENEMY_NAMES[index];
     Enemy::new_ancient(level, name, &ANCIENT_ENEMY_BASE_STATS)
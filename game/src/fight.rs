@@ -1,5 +1,178 @@
-use crate::util::is_chance_success;
+use crate::{
+    enemy::{Enemy, EnemyKind, ENEMY_SKILL_CHANCE},
+    items::ItemRarity,
+    quest::QuestEvent,
+    session::{PlayerCharacter, TemporaryStatBoosts, TemporaryStatReductions},
+    util::is_chance_success,
+};
+
+/// Rules of a fight. Dungeon fights use the default rules.
+pub struct FightOptions {
+    /// Fully restores the player's health and mana at the start of the fight.
+    pub restore_at_start: bool,
+    /// Gives drops, EXP and quest progress when the enemy is defeated.
+    pub rewards: bool,
+    /// Counts losing the fight as a character death and shows the death screen.
+    pub character_death: bool,
+}
+
+impl Default for FightOptions {
+    fn default() -> Self {
+        Self {
+            restore_at_start: true,
+            rewards: true,
+            character_death: true,
+        }
+    }
+}
+
+/// Whose action was done last in a fight.
+pub enum FightTurn {
+    Player,
+    Companion,
+    Enemy,
+}
+
+/// What happened when a fight was advanced after an action.
+pub enum FightStep {
+    /// The enemy was defeated.
+    Victory,
+    /// The player died.
+    Defeat,
+    /// The companion or the enemy acted. Contains the action text and its effect.
+    Action(String, String),
+    /// The player was revived with an item. Contains the item name, rarity and effect.
+    Resurrection(String, ItemRarity, String),
+    /// The player's turn starts. Contains mana restored by Mana Regen.
+    PlayerTurn(u32),
+}
 
 pub fn is_critical_hit(rate: f64) -> bool {
     is_chance_success(rate)
 }
+
+/// Prepares the player for a fight.
+/// Returns the stat boosts the player had before the fight.
+/// They should be given back with `end_fight` when the fight ends.
+pub fn start_fight(character: &mut PlayerCharacter, options: &FightOptions) -> TemporaryStatBoosts {
+    character.stat_reductions = TemporaryStatReductions::default();
+
+    // fully heal player at the start of fights
+    if options.restore_at_start {
+        character.restore_health(character.get_total_health());
+        character.restore_mana(character.get_total_mana());
+        if let Some(companion) = &mut character.data.companion {
+            companion.prepare_for_fight();
+        }
+    }
+    character.temp_stat_boosts.clone()
+}
+
+/// Removes the stat boosts gained during the fight.
+pub fn end_fight(character: &mut PlayerCharacter, stat_boosts: TemporaryStatBoosts) {
+    character.temp_stat_boosts = stat_boosts;
+}
+
+/// Advances the fight after the previous action.
+/// The companion acts after the player and the enemy after them.
+pub fn advance_fight(
+    enemy: &mut Enemy,
+    character: &mut PlayerCharacter,
+    turn: &mut FightTurn,
+) -> FightStep {
+    // the player's death is resolved first if thorns killed the enemy with the same attack
+    if let FightTurn::Enemy = turn {
+        if let Some((item_name, item_rarity, effect)) = character.try_resurrect(enemy) {
+            return FightStep::Resurrection(item_name, item_rarity, effect);
+        }
+        if character.is_dead() {
+            return FightStep::Defeat;
+        }
+    }
+    if enemy.is_dead() {
+        return FightStep::Victory;
+    }
+    let companion_action = match turn {
+        FightTurn::Player => character.companion_act(enemy),
+        _ => None,
+    };
+    if let Some((event, effect)) = companion_action {
+        *turn = FightTurn::Companion;
+        return FightStep::Action(event, effect);
+    }
+    if let FightTurn::Player | FightTurn::Companion = turn {
+        *turn = FightTurn::Enemy;
+        let (event, effect) = enemy_act(enemy, character);
+        return FightStep::Action(event, effect);
+    }
+    *turn = FightTurn::Player;
+    FightStep::PlayerTurn(character.regenerate_mana())
+}
+
+/// Returns the action text and its effect.
+/// A taunting companion takes the enemy's attack instead of the player.
+pub fn enemy_act(enemy: &mut Enemy, character: &mut PlayerCharacter) -> (String, String) {
+    if let Some(action) = character.companion_take_enemy_attack(enemy) {
+        return action;
+    }
+    match enemy.kind {
+        EnemyKind::Boss | EnemyKind::Ancient if is_chance_success(ENEMY_SKILL_CHANCE) => {
+            enemy.use_skill(character)
+        }
+        _ => enemy.attack_player(character),
+    }
+}
+
+/// Progresses quests after the enemy was defeated.
+pub fn enemy_defeated(enemy: &Enemy, character: &mut PlayerCharacter) {
+    character.update_quests(QuestEvent::EnemyDefeated {
+        name: enemy.name,
+        kind: &enemy.kind,
+        level: enemy.level,
+    });
+}
+
+/// Counts the death and updates the character's records.
+pub fn player_died(character: &mut PlayerCharacter) {
+    character.increase_deaths();
+    let general_stats = &mut character.data.stats.general_stats;
+    if general_stats.character_level > general_stats.highest_character_level_achieved {
+        general_stats.highest_character_level_achieved = general_stats.character_level;
+    }
+    if general_stats.current_dungeon_floor > general_stats.highest_dungeon_floor_achieved {
+        general_stats.highest_dungeon_floor_achieved = general_stats.current_dungeon_floor;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        character::{create_player_character, CharacterClass},
+        enemy::{LESSER_ENEMY_BASE_STATS, NORMAL_ENEMY_NAME_SKELETON},
+    };
+
+    #[test]
+    fn player_death_is_resolved_before_enemy_death() {
+        let mut character = create_player_character("Hero", CharacterClass::Warrior);
+        let mut enemy = Enemy::new_normal(1, NORMAL_ENEMY_NAME_SKELETON, &LESSER_ENEMY_BASE_STATS);
+        // thorns killed the enemy with the attack that killed the player
+        character.temp_stats.current_health = 0;
+        enemy.stats.current_health = 0;
+
+        let mut turn = FightTurn::Enemy;
+        let step = advance_fight(&mut enemy, &mut character, &mut turn);
+        assert!(matches!(step, FightStep::Defeat));
+    }
+
+    #[test]
+    fn enemy_death_after_player_action_is_victory() {
+        let mut character = create_player_character("Hero", CharacterClass::Warrior);
+        let mut enemy = Enemy::new_normal(1, NORMAL_ENEMY_NAME_SKELETON, &LESSER_ENEMY_BASE_STATS);
+        enemy.stats.current_health = 0;
+
+        let mut turn = FightTurn::Player;
+        let step = advance_fight(&mut enemy, &mut character, &mut turn);
+        assert!(matches!(step, FightStep::Victory));
+    }
+}
@@ -0,0 +1,427 @@
+//! Drawing and input used by the menus.
+//! Menus draw through the frontend of the current thread instead of writing to stdout,
+//! so they can be rendered with other backends, e.g. in memory for snapshot tests.
+
+use crossterm::{
+    cursor::{self, Hide, Show},
+    event::{self, Event},
+    execute,
+    style::{Color, Print, SetBackgroundColor, SetForegroundColor},
+    terminal::{
+        self, disable_raw_mode, enable_raw_mode, Clear, ClearType, DisableLineWrap, EnableLineWrap,
+        EnterAlternateScreen, LeaveAlternateScreen,
+    },
+};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    io::{self, Write},
+    rc::Rc,
+    time::Duration,
+};
+
+use crate::theme::{get_theme_color, ThemeColor};
+
+pub const MEMORY_FRONTEND_WIDTH: u16 = 120;
+pub const MEMORY_FRONTEND_HEIGHT: u16 = 40;
+
+thread_local! {
+    static FRONTEND: RefCell<Box<dyn Frontend>> = RefCell::new(Box::new(CrosstermFrontend));
+}
+
+/// Backend that menus draw with and read input from.
+/// Colors and events use the crossterm types.
+pub trait Frontend {
+    /// Prepares the screen for the game.
+    fn start(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+    /// Restores the screen after the game.
+    fn stop(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+    fn clear(&mut self) -> io::Result<()>;
+    /// Clears the row the cursor is on.
+    fn clear_line(&mut self) -> io::Result<()>;
+    /// Clears from the cursor to the end of the screen.
+    fn clear_below(&mut self) -> io::Result<()>;
+    fn move_to(&mut self, column: u16, row: u16) -> io::Result<()>;
+    /// Writes text at the cursor. A newline moves the cursor down one row.
+    fn print(&mut self, text: &str) -> io::Result<()>;
+    fn set_foreground_color(&mut self, color: Color) -> io::Result<()>;
+    fn set_background_color(&mut self, color: Color) -> io::Result<()>;
+    fn show_cursor(&mut self, show: bool) -> io::Result<()>;
+    fn flush(&mut self) -> io::Result<()>;
+    /// Returns the number of columns and rows.
+    fn size(&self) -> io::Result<(u16, u16)>;
+    /// Waits for the next input event.
+    fn read_event(&mut self) -> io::Result<Event>;
+    /// Returns true if an event is available within the timeout.
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<bool>;
+    /// Reads a line of text input with the typed text shown.
+    fn read_line(&mut self) -> io::Result<String>;
+
+    /// Draws a list starting from the row with "> " in front of the selected item.
+    fn draw_list(&mut self, row: u16, items: &[&str], selected_index: usize) -> io::Result<()> {
+        for (i, item) in items.iter().enumerate() {
+            self.move_to(0, i as u16 + row)?;
+            if i == selected_index {
+                self.print(&format!("> {}\n", item))?;
+            } else {
+                self.print(&format!("  {}\n", item))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Draws to the terminal with crossterm.
+pub struct CrosstermFrontend;
+
+impl Frontend for CrosstermFrontend {
+    fn start(&mut self) -> io::Result<()> {
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide, DisableLineWrap)?;
+        if let Some(color) = get_theme_color(ThemeColor::Background) {
+            execute!(io::stdout(), SetBackgroundColor(color))?;
+        }
+        if let Some(color) = get_theme_color(ThemeColor::Text) {
+            execute!(io::stdout(), SetForegroundColor(color))?;
+        }
+        Ok(())
+    }
+
+    fn stop(&mut self) -> io::Result<()> {
+        execute!(
+            io::stdout(),
+            SetBackgroundColor(Color::Reset),
+            SetForegroundColor(Color::Reset),
+            EnableLineWrap,
+            LeaveAlternateScreen,
+            Show
+        )?;
+        disable_raw_mode()
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        execute!(io::stdout(), Clear(ClearType::All))
+    }
+
+    fn clear_line(&mut self) -> io::Result<()> {
+        execute!(io::stdout(), Clear(ClearType::CurrentLine))
+    }
+
+    fn clear_below(&mut self) -> io::Result<()> {
+        execute!(io::stdout(), Clear(ClearType::FromCursorDown))
+    }
+
+    fn move_to(&mut self, column: u16, row: u16) -> io::Result<()> {
+        execute!(io::stdout(), cursor::MoveTo(column, row))
+    }
+
+    fn print(&mut self, text: &str) -> io::Result<()> {
+        execute!(io::stdout(), Print(text))
+    }
+
+    fn set_foreground_color(&mut self, color: Color) -> io::Result<()> {
+        execute!(io::stdout(), SetForegroundColor(color))
+    }
+
+    fn set_background_color(&mut self, color: Color) -> io::Result<()> {
+        execute!(io::stdout(), SetBackgroundColor(color))
+    }
+
+    fn show_cursor(&mut self, show: bool) -> io::Result<()> {
+        if show {
+            execute!(io::stdout(), Show)
+        } else {
+            execute!(io::stdout(), Hide)
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stdout().flush()
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        terminal::size()
+    }
+
+    fn read_event(&mut self) -> io::Result<Event> {
+        event::read()
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<bool> {
+        event::poll(timeout)
+    }
+
+    fn read_line(&mut self) -> io::Result<String> {
+        // typed text is only shown outside raw mode
+        disable_raw_mode()?;
+        let mut line = String::new();
+        let result = io::stdin().read_line(&mut line);
+        enable_raw_mode()?;
+        result?;
+        Ok(line)
+    }
+}
+
+/// Frames captured by a memory frontend.
+pub type MemoryFrames = Rc<RefCell<Vec<String>>>;
+
+/// Draws to a text buffer and reads input from a queue of events.
+/// The screen is captured as a frame every time input is read,
+/// so a menu flow can be checked frame by frame.
+/// Resize events in the queue resize the screen when they are read.
+pub struct MemoryFrontend {
+    width: u16,
+    height: u16,
+    screen: Vec<Vec<char>>,
+    cursor: (u16, u16),
+    events: VecDeque<Event>,
+    lines: VecDeque<String>,
+    frames: MemoryFrames,
+}
+
+impl MemoryFrontend {
+    pub fn new(events: Vec<Event>) -> Self {
+        Self::with_size(events, MEMORY_FRONTEND_WIDTH, MEMORY_FRONTEND_HEIGHT)
+    }
+
+    pub fn with_size(events: Vec<Event>, width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            screen: vec![vec![' '; width as usize]; height as usize],
+            cursor: (0, 0),
+            events: events.into(),
+            lines: VecDeque::new(),
+            frames: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    /// Adds lines returned by `read_line`.
+    pub fn with_lines(mut self, lines: Vec<String>) -> Self {
+        self.lines = lines.into();
+        self
+    }
+
+    /// Resizes the screen keeping the text that still fits.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.screen
+            .resize(height as usize, vec![' '; width as usize]);
+        for row in &mut self.screen {
+            row.resize(width as usize, ' ');
+        }
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Returns a handle to the captured frames.
+    /// It can be read after the frontend was given to `set_frontend`.
+    pub fn frames(&self) -> MemoryFrames {
+        Rc::clone(&self.frames)
+    }
+
+    /// Returns the current screen with trailing spaces removed from rows.
+    pub fn get_screen(&self) -> String {
+        let mut rows: Vec<String> = self
+            .screen
+            .iter()
+            .map(|row| row.iter().collect::<String>().trim_end().to_string())
+            .collect();
+        while rows.last().is_some_and(|row| row.is_empty()) {
+            rows.pop();
+        }
+        rows.join("\n")
+    }
+
+    fn capture_frame(&self) {
+        self.frames.borrow_mut().push(self.get_screen());
+    }
+
+    fn no_more_input() -> io::Error {
+        io::Error::new(io::ErrorKind::UnexpectedEof, "no more input events")
+    }
+}
+
+impl Frontend for MemoryFrontend {
+    fn clear(&mut self) -> io::Result<()> {
+        for row in self.screen.iter_mut() {
+            row.fill(' ');
+        }
+        Ok(())
+    }
+
+    fn clear_line(&mut self) -> io::Result<()> {
+        if let Some(row) = self.screen.get_mut(self.cursor.1 as usize) {
+            row.fill(' ');
+        }
+        Ok(())
+    }
+
+    fn clear_below(&mut self) -> io::Result<()> {
+        let (column, row) = (self.cursor.0 as usize, self.cursor.1 as usize);
+        for (i, screen_row) in self.screen.iter_mut().enumerate().skip(row) {
+            let start = if i == row { column } else { 0 };
+            for c in screen_row.iter_mut().skip(start) {
+                *c = ' ';
+            }
+        }
+        Ok(())
+    }
+
+    fn move_to(&mut self, column: u16, row: u16) -> io::Result<()> {
+        self.cursor = (column, row);
+        Ok(())
+    }
+
+    fn print(&mut self, text: &str) -> io::Result<()> {
+        for c in text.chars() {
+            if c == '\n' {
+                self.cursor = (0, self.cursor.1.saturating_add(1));
+                continue;
+            }
+            let (column, row) = self.cursor;
+            if column < self.width && row < self.height {
+                self.screen[row as usize][column as usize] = c;
+            }
+            self.cursor.0 = column.saturating_add(1);
+        }
+        Ok(())
+    }
+
+    fn set_foreground_color(&mut self, _color: Color) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn set_background_color(&mut self, _color: Color) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn show_cursor(&mut self, _show: bool) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        Ok((self.width, self.height))
+    }
+
+    fn read_event(&mut self) -> io::Result<Event> {
+        self.capture_frame();
+        let event = self.events.pop_front().ok_or_else(Self::no_more_input)?;
+        if let Event::Resize(width, height) = event {
+            self.resize(width, height);
+        }
+        Ok(event)
+    }
+
+    fn poll_event(&mut self, _timeout: Duration) -> io::Result<bool> {
+        Ok(!self.events.is_empty())
+    }
+
+    fn read_line(&mut self) -> io::Result<String> {
+        self.capture_frame();
+        let line = self.lines.pop_front().ok_or_else(Self::no_more_input)?;
+        self.print(&format!("{}\n", line))?;
+        Ok(line)
+    }
+}
+
+/// Replaces the frontend of the current thread and returns the previous one.
+pub fn set_frontend(frontend: Box<dyn Frontend>) -> Box<dyn Frontend> {
+    FRONTEND.with(|current| current.replace(frontend))
+}
+
+/// Runs a function with the frontend of the current thread.
+pub fn with_frontend<T>(f: impl FnOnce(&mut dyn Frontend) -> T) -> T {
+    FRONTEND.with(|frontend| f(frontend.borrow_mut().as_mut()))
+}
+
+pub fn start_frontend() -> io::Result<()> {
+    with_frontend(|frontend| frontend.start())
+}
+
+pub fn stop_frontend() -> io::Result<()> {
+    with_frontend(|frontend| frontend.stop())
+}
+
+pub fn clear() -> io::Result<()> {
+    with_frontend(|frontend| frontend.clear())
+}
+
+pub fn clear_line() -> io::Result<()> {
+    with_frontend(|frontend| frontend.clear_line())
+}
+
+pub fn clear_below() -> io::Result<()> {
+    with_frontend(|frontend| frontend.clear_below())
+}
+
+pub fn move_to(column: u16, row: u16) -> io::Result<()> {
+    with_frontend(|frontend| frontend.move_to(column, row))
+}
+
+pub fn print(text: &str) -> io::Result<()> {
+    with_frontend(|frontend| frontend.print(text))
+}
+
+pub fn set_foreground_color(color: Color) -> io::Result<()> {
+    with_frontend(|frontend| frontend.set_foreground_color(color))
+}
+
+pub fn set_background_color(color: Color) -> io::Result<()> {
+    with_frontend(|frontend| frontend.set_background_color(color))
+}
+
+pub fn show_cursor(show: bool) -> io::Result<()> {
+    with_frontend(|frontend| frontend.show_cursor(show))
+}
+
+pub fn flush() -> io::Result<()> {
+    with_frontend(|frontend| frontend.flush())
+}
+
+pub fn size() -> io::Result<(u16, u16)> {
+    with_frontend(|frontend| frontend.size())
+}
+
+pub fn read_frontend_event() -> io::Result<Event> {
+    with_frontend(|frontend| frontend.read_event())
+}
+
+pub fn poll_frontend_event(timeout: Duration) -> io::Result<bool> {
+    with_frontend(|frontend| frontend.poll_event(timeout))
+}
+
+pub fn read_line() -> io::Result<String> {
+    with_frontend(|frontend| frontend.read_line())
+}
+
+pub fn draw_list<T: AsRef<str>>(row: u16, items: &[T], selected_index: usize) -> io::Result<()> {
+    let items: Vec<&str> = items.iter().map(|item| item.as_ref()).collect();
+    with_frontend(|frontend| frontend.draw_list(row, &items, selected_index))
+}
+
+/// Writes formatted text at the cursor with the frontend of the current thread.
+#[macro_export]
+macro_rules! print_text {
+    ($($arg:tt)*) => {
+        $crate::frontend::print(&format!($($arg)*))
+    };
+}
+
+/// Writes a formatted line at the cursor with the frontend of the current thread.
+#[macro_export]
+macro_rules! print_line {
+    () => {
+        $crate::frontend::print("\n")
+    };
+    ($($arg:tt)*) => {
+        $crate::frontend::print(&format!("{}\n", format_args!($($arg)*)))
+    };
+}
@@ -1,4 +1,4 @@
-use crate::{game_data::write_save_file, session::Player};
+use crate::{game_data::write_save_file, replay::is_replay_session, session::Player};
 use std::io;
 
 pub enum StatusBar {
@@ -6,7 +6,11 @@ pub enum StatusBar {
     Mana,
 }
 
+/// Temporary characters and replay sessions are never saved.
 pub fn save_game(player: &mut Player) -> io::Result<()> {
+    if player.temporary_character || is_replay_session() {
+        return Ok(());
+    }
     if let Some(player_character) = &mut player.character {
         player_character.data.equipment.weapon = player_character.equipped_items.weapon.clone();
         player_character.data.equipment.armor = player_character.equipped_items.armor.clone();
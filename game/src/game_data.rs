@@ -6,13 +6,19 @@ use std::io::Write;
 use std::path::PathBuf;
 
 use crate::{
+    alchemy::Ingredient,
     character::{get_class_starting_stats, CharacterClass},
-    items::{ArmorItem, ConsumableItem, RingItem, WeaponItem},
+    companion::Companion,
+    items::{ArmorItem, ConsumableEffect, ConsumableItem, RingItem, WeaponItem},
+    quest::Quest,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    io,
 };
-use std::{collections::HashMap, io};
 
 const SAVEFILE_NAME: &str = "terminal_rpg_game_data";
-const SUBDIR_NAME: &str = "terminal-rpg-game";
+pub const SUBDIR_NAME: &str = "terminal-rpg-game";
 
 pub const STARTING_REQUIRED_EXP: u32 = 100;
 pub const STARTING_HEALTH: u32 = 100;
@@ -22,11 +28,24 @@ pub const STARTING_DAMAGE: u32 = 1;
 pub const STARTING_CRITICAL_DAMAGE_MULTIPLIER: f64 = 2.0;
 pub const STARTING_CRITICAL_HIT_RATE: f64 = 0.0;
 
+pub const STASH_CAPACITY: usize = 30;
+pub const ARENA_LEADERBOARD_SIZE: usize = 10;
+
 /// Main game data.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct GameData {
     /// HashMap key: character name.
     pub characters: HashMap<String, CharacterData>,
+    /// Items shared by all characters.
+    #[serde(default)]
+    pub stash: Stash,
+    /// Best arena scores of all characters. Highest score first.
+    #[serde(default)]
+    pub arena_leaderboard: Vec<ArenaScore>,
+    /// Best daily challenge results.
+    /// BTreeMap key: UTC date of the challenge in format YYYY-MM-DD.
+    #[serde(default)]
+    pub daily_history: BTreeMap<String, DailyResult>,
 }
 
 impl GameData {
@@ -39,8 +58,110 @@ impl GameData {
     pub fn new() -> GameData {
         GameData {
             characters: HashMap::new(),
+            stash: Stash::default(),
+            arena_leaderboard: Vec::new(),
+            daily_history: BTreeMap::new(),
         }
     }
+
+    /// Adds the score to the arena leaderboard if it is high enough.
+    /// Returns the leaderboard position starting from 1 if the score was added.
+    pub fn record_arena_score(&mut self, score: ArenaScore) -> Option<usize> {
+        // older scores stay ahead of equal new scores
+        let position = self
+            .arena_leaderboard
+            .iter()
+            .position(|entry| entry.score < score.score)
+            .unwrap_or(self.arena_leaderboard.len());
+        if position >= ARENA_LEADERBOARD_SIZE {
+            return None;
+        }
+        self.arena_leaderboard.insert(position, score);
+        self.arena_leaderboard.truncate(ARENA_LEADERBOARD_SIZE);
+        Some(position + 1)
+    }
+
+    /// Records a daily challenge attempt on the date.
+    /// Only the best result of the date is kept.
+    /// Returns true if the result is the new best of the date.
+    pub fn record_daily_result(&mut self, date: &str, mut result: DailyResult) -> bool {
+        match self.daily_history.get_mut(date) {
+            Some(best) => {
+                best.attempts += 1;
+                if result.score <= best.score {
+                    return false;
+                }
+                result.attempts = best.attempts;
+                *best = result;
+            }
+            None => {
+                self.daily_history.insert(date.to_string(), result);
+            }
+        }
+        true
+    }
+
+    /// Updates data loaded from older save files to the current format.
+    fn migrate(&mut self) {
+        for character in self.characters.values_mut() {
+            for item in character.inventory.consumables.values_mut() {
+                if item.effect_kind == ConsumableEffect::Unknown {
+                    item.effect_kind = ConsumableEffect::from_item_name(&item.info.name);
+                }
+            }
+        }
+    }
+}
+
+/// Account-wide item storage shared by all characters on the save.
+/// Items in the stash are not lost when a character dies.
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct Stash {
+    /// HashMap key: item global id.
+    pub weapons: HashMap<String, WeaponItem>,
+    /// HashMap key: item global id.
+    pub armors: HashMap<String, ArmorItem>,
+    /// HashMap key: item global id.
+    pub rings: HashMap<String, RingItem>,
+}
+
+impl Stash {
+    /// Returns the number of items in the stash.
+    pub fn len(&self) -> usize {
+        self.weapons.len() + self.armors.len() + self.rings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() >= STASH_CAPACITY
+    }
+}
+
+/// Result of an arena run.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ArenaScore {
+    pub character_name: String,
+    pub class: CharacterClass,
+    pub character_level: u32,
+    pub waves_survived: u32,
+    pub score: u32,
+    /// Unix timestamp.
+    pub recorded_at: i64,
+}
+
+/// Result of a daily challenge.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DailyResult {
+    pub floors_cleared: u32,
+    pub character_level: u32,
+    pub score: u32,
+    /// Number of times the challenge was attempted on the date.
+    pub attempts: u32,
+    /// Unix timestamp.
+    pub recorded_at: i64,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -57,6 +178,12 @@ pub struct CharacterData {
     pub currency: CharacterCurrency,
     pub inventory: CharacterInventory,
     pub equipment: CharacterEquipment,
+    /// Companion that fights alongside the character. Lost when the character dies.
+    #[serde(default)]
+    pub companion: Option<Companion>,
+    /// Quests accepted from bounty boards.
+    #[serde(default)]
+    pub quests: Vec<Quest>,
 }
 
 impl CharacterData {
@@ -88,12 +215,17 @@ impl CharacterData {
                 weapons: HashMap::new(),
                 rings: HashMap::new(),
                 ancient_ruins_keys: 0,
+                crafting_materials: 0,
+                ingredients: HashMap::new(),
+                item_sequence: 0,
             },
             equipment: CharacterEquipment {
                 weapon: None,
                 armor: None,
                 ring: None,
             },
+            companion: None,
+            quests: Vec::new(),
         }
     }
 }
@@ -146,9 +278,24 @@ pub struct CharacterInventory {
     /// HashMap key: item global id.
     pub rings: HashMap<String, RingItem>,
     pub ancient_ruins_keys: u32,
+    /// Materials used by the blacksmith. Received by salvaging items.
+    #[serde(default)]
+    pub crafting_materials: u32,
+    /// Alchemy ingredients and their amounts.
+    #[serde(default)]
+    pub ingredients: HashMap<Ingredient, u32>,
+    /// Counter used to order items by the time they were received.
+    #[serde(default)]
+    pub item_sequence: u64,
 }
 
 impl CharacterInventory {
+    /// Returns the next item sequence number.
+    pub fn next_item_sequence(&mut self) -> u64 {
+        self.item_sequence += 1;
+        self.item_sequence
+    }
+
     pub fn clear_consumables(&mut self) {
         self.consumables.clear();
     }
@@ -164,6 +311,10 @@ impl CharacterInventory {
     pub fn clear_rings(&mut self) {
         self.rings.clear();
     }
+
+    pub fn clear_ingredients(&mut self) {
+        self.ingredients.clear();
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -215,7 +366,8 @@ pub fn load_save_file() -> io::Result<GameData> {
             ))
         }
     };
-    let game_data = deserialize_game_data_from_json(&json_str)?;
+    let mut game_data = deserialize_game_data_from_json(&json_str)?;
+    game_data.migrate();
 
     Ok(game_data)
 }
@@ -0,0 +1,277 @@
+//! Translations of the in-game text.
+//! Texts are looked up from message catalogs by ID. Messages can have named
+//! parameters, e.g. `Player took {damage} damage`, that are filled in with `tr!`.
+
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::HashMap, env, fmt, io};
+
+const CATALOG_EN: &str = include_str!("../locales/en.json");
+const CATALOG_FI: &str = include_str!("../locales/fi.json");
+
+thread_local! {
+    static CATALOG: RefCell<Catalog> = RefCell::new(Catalog::new(Locale::English));
+}
+
+/// Language of the in-game text.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "fi")]
+    Finnish,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::English, Locale::Finnish];
+
+    /// Language code, e.g. `fi`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::Finnish => "fi",
+        }
+    }
+
+    /// Parses a language code or a locale name like `fi_FI.UTF-8`.
+    /// Returns None if the language has no translation.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let language = code
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.code() == language)
+    }
+
+    fn get_catalog_source(&self) -> &'static str {
+        match self {
+            Self::English => CATALOG_EN,
+            Self::Finnish => CATALOG_FI,
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// Messages of a locale by ID.
+pub struct Catalog {
+    pub locale: Locale,
+    messages: HashMap<String, String>,
+    /// English messages used when the locale is missing a message.
+    fallback: HashMap<String, String>,
+}
+
+impl Catalog {
+    pub fn new(locale: Locale) -> Self {
+        Self {
+            locale,
+            messages: parse_catalog(locale.get_catalog_source()),
+            fallback: parse_catalog(CATALOG_EN),
+        }
+    }
+
+    /// Returns the message or None if neither the locale nor English has it.
+    pub fn get_message(&self, id: &str) -> Option<&str> {
+        self.messages
+            .get(id)
+            .or_else(|| self.fallback.get(id))
+            .map(|message| message.as_str())
+    }
+
+    /// IDs of English messages that the locale doesn't have.
+    pub fn get_missing_message_ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self
+            .fallback
+            .keys()
+            .filter(|id| !self.messages.contains_key(*id))
+            .map(|id| id.as_str())
+            .collect();
+        ids.sort();
+        ids
+    }
+}
+
+/// Catalogs are embedded in the binary so they are always valid JSON.
+fn parse_catalog(source: &str) -> HashMap<String, String> {
+    serde_json::from_str(source).expect("message catalog should be valid JSON")
+}
+
+/// Sets the language of the in-game text.
+pub fn set_locale(locale: Locale) {
+    CATALOG.with(|catalog| *catalog.borrow_mut() = Catalog::new(locale));
+}
+
+pub fn get_locale() -> Locale {
+    CATALOG.with(|catalog| catalog.borrow().locale)
+}
+
+/// Number of messages the current locale is missing.
+pub fn get_missing_message_count() -> usize {
+    CATALOG.with(|catalog| catalog.borrow().get_missing_message_ids().len())
+}
+
+/// Returns the message with the parameters filled in.
+/// Unknown IDs are returned as they are so missing messages are visible.
+pub fn translate(id: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    CATALOG.with(|catalog| match catalog.borrow().get_message(id) {
+        Some(message) => format_message(message, args),
+        None => id.to_string(),
+    })
+}
+
+/// Translates the messages of a menu.
+pub fn translate_all<T: AsRef<str>>(ids: &[T]) -> Vec<String> {
+    ids.iter().map(|id| translate(id.as_ref(), &[])).collect()
+}
+
+/// Replaces `{name}` in the message with the parameter of the same name.
+/// Unknown parameters are kept as they are.
+pub fn format_message(message: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    let mut formatted = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(start) = rest.find('{') {
+        formatted.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let arg = after.find('}').and_then(|end| {
+            args.iter()
+                .find(|(name, _)| *name == &after[..end])
+                .map(|(_, value)| (end, value))
+        });
+        match arg {
+            Some((end, value)) => {
+                formatted.push_str(&value.to_string());
+                rest = &after[end + 1..];
+            }
+            None => {
+                formatted.push('{');
+                rest = after;
+            }
+        }
+    }
+    formatted.push_str(rest);
+    formatted
+}
+
+/// Returns the locale of the `LC_ALL`, `LC_MESSAGES` or `LANG` environment variable.
+/// The first one that is set is used. Returns None if its language has no translation.
+pub fn get_env_locale() -> Option<Locale> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .and_then(|value| Locale::from_code(&value))
+}
+
+/// Parses the language code of the `--lang` flag.
+pub fn parse_locale(code: &str) -> io::Result<Locale> {
+    Locale::from_code(code).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unsupported language: {}", code),
+        )
+    })
+}
+
+/// Translates a message by ID. Named parameters fill in `{name}` in the message,
+/// e.g. `tr!("fight.player_took_damage", damage = 12)`.
+#[macro_export]
+macro_rules! tr {
+    ($id:expr) => {
+        $crate::i18n::translate($id, &[])
+    };
+    ($id:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::translate(
+            $id,
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    /// Names of the `{name}` parameters in a message.
+    fn get_parameters(message: &str) -> BTreeSet<&str> {
+        message
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}'))
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    #[test]
+    fn format_message_fills_parameters() {
+        let damage = 12;
+        let args: [(&str, &dyn fmt::Display); 2] = [("damage", &damage), ("name", &"Ogre")];
+        assert_eq!(
+            format_message("{name} took {damage} damage", &args),
+            "Ogre took 12 damage"
+        );
+        assert_eq!(
+            format_message("{unknown} {damage} {", &args),
+            "{unknown} 12 {"
+        );
+    }
+
+    #[test]
+    fn tr_translates_with_locale() {
+        set_locale(Locale::English);
+        assert_eq!(
+            tr!("list.position", first = 1, last = 5, len = 10),
+            "[1-5 of 10]"
+        );
+        assert_eq!(tr!("menu.yes"), "Yes");
+        assert_eq!(tr!("no.such.message"), "no.such.message");
+
+        set_locale(Locale::Finnish);
+        assert_eq!(tr!("menu.yes"), "Kyllä");
+        set_locale(Locale::English);
+    }
+
+    #[test]
+    fn catalogs_have_same_messages() {
+        let en = parse_catalog(CATALOG_EN);
+        for locale in Locale::ALL {
+            let catalog = Catalog::new(locale);
+            assert!(
+                catalog.get_missing_message_ids().is_empty(),
+                "{} is missing {:?}",
+                locale,
+                catalog.get_missing_message_ids()
+            );
+            let extra: Vec<&String> = catalog
+                .messages
+                .keys()
+                .filter(|id| !en.contains_key(*id))
+                .collect();
+            assert!(extra.is_empty(), "{} has unknown {:?}", locale, extra);
+            for (id, message) in &catalog.messages {
+                assert_eq!(
+                    get_parameters(message),
+                    get_parameters(&en[id]),
+                    "parameters of {} differ in {}",
+                    id,
+                    locale
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn locale_from_code() {
+        assert_eq!(Locale::from_code("fi_FI.UTF-8"), Some(Locale::Finnish));
+        assert_eq!(Locale::from_code("en-US"), Some(Locale::English));
+        assert_eq!(Locale::from_code("sv_SE"), None);
+        assert!(parse_locale("de").is_err());
+    }
+}
@@ -0,0 +1,329 @@
+use std::{cmp::Ordering, fmt};
+
+use crate::{
+    blacksmith::get_next_item_rarity,
+    items::{get_item_display_name, ArmorItem, CharacterItem, ItemRarity, RingItem, WeaponItem},
+    session::PlayerCharacter,
+    tr,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemSortOrder {
+    Rarity,
+    Level,
+    /// Main stat of the item. Damage for weapons, health for armors and mana for rings.
+    Stat,
+    Newest,
+}
+
+impl fmt::Display for ItemSortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Rarity => tr!("sort.rarity"),
+                Self::Level => tr!("sort.level"),
+                Self::Stat => tr!("sort.stat"),
+                Self::Newest => tr!("sort.newest"),
+            }
+        )
+    }
+}
+
+impl ItemSortOrder {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Rarity => Self::Level,
+            Self::Level => Self::Stat,
+            Self::Stat => Self::Newest,
+            Self::Newest => Self::Rarity,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EquippedFilter {
+    All,
+    Equipped,
+    NotEquipped,
+}
+
+impl EquippedFilter {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::All => Self::Equipped,
+            Self::Equipped => Self::NotEquipped,
+            Self::NotEquipped => Self::All,
+        }
+    }
+}
+
+impl fmt::Display for EquippedFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::All => tr!("filter.all"),
+                Self::Equipped => tr!("filter.equipped"),
+                Self::NotEquipped => tr!("filter.not_equipped"),
+            }
+        )
+    }
+}
+
+/// Options used to sort and filter items in inventory lists.
+pub struct ItemListOptions {
+    pub sort_order: ItemSortOrder,
+    /// Only items of this rarity are listed if set.
+    pub rarity_filter: Option<ItemRarity>,
+    pub equipped_filter: EquippedFilter,
+    /// Only items whose name contains this text are listed. Case insensitive.
+    pub search: String,
+}
+
+impl Default for ItemListOptions {
+    fn default() -> Self {
+        Self {
+            sort_order: ItemSortOrder::Rarity,
+            rarity_filter: None,
+            equipped_filter: EquippedFilter::All,
+            search: String::new(),
+        }
+    }
+}
+
+impl ItemListOptions {
+    /// Cycles the rarity filter from no filter through all rarities.
+    pub fn next_rarity_filter(&mut self) {
+        self.rarity_filter = match &self.rarity_filter {
+            None => Some(ItemRarity::Common),
+            Some(rarity) => get_next_item_rarity(rarity),
+        };
+    }
+
+    fn matches(&self, entry: &ItemListEntry) -> bool {
+        if let Some(rarity) = &self.rarity_filter {
+            if get_item_rarity_rank(rarity) != entry.rarity_rank {
+                return false;
+            }
+        }
+        match self.equipped_filter {
+            EquippedFilter::Equipped if !entry.equipped => return false,
+            EquippedFilter::NotEquipped if entry.equipped => return false,
+            _ => {}
+        }
+        entry
+            .name
+            .to_lowercase()
+            .contains(&self.search.to_lowercase())
+    }
+
+    /// Higher values are listed first. Ties are broken by newest first
+    /// and finally by id so the order stays the same between visits.
+    fn compare(&self, a: &ItemListEntry, b: &ItemListEntry) -> Ordering {
+        let ordering = match self.sort_order {
+            ItemSortOrder::Rarity => b
+                .rarity_rank
+                .cmp(&a.rarity_rank)
+                .then(b.level.cmp(&a.level)),
+            ItemSortOrder::Level => b
+                .level
+                .cmp(&a.level)
+                .then(b.rarity_rank.cmp(&a.rarity_rank)),
+            ItemSortOrder::Stat => b.stat.cmp(&a.stat),
+            ItemSortOrder::Newest => Ordering::Equal,
+        };
+        ordering
+            .then(b.sequence.cmp(&a.sequence))
+            .then(a.id.cmp(b.id))
+    }
+}
+
+/// Values of an item used to sort and filter it.
+struct ItemListEntry<'a> {
+    id: &'a str,
+    name: String,
+    rarity_rank: u8,
+    level: u32,
+    stat: u32,
+    sequence: u64,
+    equipped: bool,
+}
+
+pub fn get_item_rarity_rank(rarity: &ItemRarity) -> u8 {
+    match rarity {
+        ItemRarity::Common => 1,
+        ItemRarity::Uncommon => 2,
+        ItemRarity::Rare => 3,
+        ItemRarity::Epic => 4,
+        ItemRarity::Legendary => 5,
+        ItemRarity::Mythical => 6,
+        _ => 0,
+    }
+}
+
+fn list_items<T: Clone>(
+    mut entries: Vec<(ItemListEntry<'_>, &T)>,
+    options: &ItemListOptions,
+) -> Vec<T> {
+    entries.retain(|(entry, _)| options.matches(entry));
+    entries.sort_by(|(a, _), (b, _)| options.compare(a, b));
+    entries.into_iter().map(|(_, item)| item.clone()).collect()
+}
+
+/// Returns the weapons in the inventory sorted and filtered with the options.
+pub fn get_weapon_list(character: &PlayerCharacter, options: &ItemListOptions) -> Vec<WeaponItem> {
+    let entries = character
+        .data
+        .inventory
+        .weapons
+        .values()
+        .map(|item| {
+            let entry = ItemListEntry {
+                id: &item.id,
+                name: get_item_display_name(CharacterItem::Weapon(item)),
+                rarity_rank: get_item_rarity_rank(&item.rarity),
+                level: item.level,
+                stat: item.stats.damage,
+                sequence: item.sequence,
+                equipped: item.is_equipped(character),
+            };
+            (entry, item)
+        })
+        .collect();
+    list_items(entries, options)
+}
+
+/// Returns the armors in the inventory sorted and filtered with the options.
+pub fn get_armor_list(character: &PlayerCharacter, options: &ItemListOptions) -> Vec<ArmorItem> {
+    let entries = character
+        .data
+        .inventory
+        .armors
+        .values()
+        .map(|item| {
+            let entry = ItemListEntry {
+                id: &item.id,
+                name: get_item_display_name(CharacterItem::Armor(item)),
+                rarity_rank: get_item_rarity_rank(&item.rarity),
+                level: item.level,
+                stat: item.stats.health,
+                sequence: item.sequence,
+                equipped: item.is_equipped(character),
+            };
+            (entry, item)
+        })
+        .collect();
+    list_items(entries, options)
+}
+
+/// Returns the rings in the inventory sorted and filtered with the options.
+pub fn get_ring_list(character: &PlayerCharacter, options: &ItemListOptions) -> Vec<RingItem> {
+    let entries = character
+        .data
+        .inventory
+        .rings
+        .values()
+        .map(|item| {
+            let entry = ItemListEntry {
+                id: &item.id,
+                name: get_item_display_name(CharacterItem::Ring(item)),
+                rarity_rank: get_item_rarity_rank(&item.rarity),
+                level: item.level,
+                stat: item.stats.mana,
+                sequence: item.sequence,
+                equipped: item.is_equipped(character),
+            };
+            (entry, item)
+        })
+        .collect();
+    list_items(entries, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        character::CharacterClass,
+        items::ItemName,
+        test_util::{test_character, test_ring},
+    };
+
+    fn list_names(character: &PlayerCharacter, options: &ItemListOptions) -> Vec<String> {
+        get_ring_list(character, options)
+            .iter()
+            .map(|ring| ring.get_name().to_string())
+            .collect()
+    }
+
+    /// Character with rings given in order so later rings are newer.
+    fn character_with_rings() -> PlayerCharacter {
+        let mut character = test_character(CharacterClass::Mage);
+        for (name, rarity, level, mana) in [
+            ("Copper", ItemRarity::Common, 3, 10),
+            ("Silver", ItemRarity::Rare, 1, 30),
+            ("Gold", ItemRarity::Rare, 2, 20),
+            ("Bronze", ItemRarity::Common, 3, 5),
+        ] {
+            let mut ring = test_ring(rarity, level, mana);
+            ring.name = Some(ItemName::Text(name.to_string()));
+            character.give_ring(&ring);
+        }
+        character
+    }
+
+    #[test]
+    fn sort_orders() {
+        let character = character_with_rings();
+        let mut options = ItemListOptions::default();
+        assert_eq!(
+            list_names(&character, &options),
+            ["Gold", "Silver", "Bronze", "Copper"]
+        );
+        options.sort_order = ItemSortOrder::Level;
+        assert_eq!(
+            list_names(&character, &options),
+            ["Bronze", "Copper", "Gold", "Silver"]
+        );
+        options.sort_order = ItemSortOrder::Stat;
+        assert_eq!(
+            list_names(&character, &options),
+            ["Silver", "Gold", "Copper", "Bronze"]
+        );
+        options.sort_order = ItemSortOrder::Newest;
+        assert_eq!(
+            list_names(&character, &options),
+            ["Bronze", "Gold", "Silver", "Copper"]
+        );
+    }
+
+    #[test]
+    fn filters() {
+        let mut character = character_with_rings();
+        let gold = get_ring_list(&character, &ItemListOptions::default())[0].clone();
+        character.equip_ring(&gold.id);
+
+        let mut options = ItemListOptions::default();
+        options.next_rarity_filter();
+        assert_eq!(list_names(&character, &options), ["Bronze", "Copper"]);
+
+        options = ItemListOptions {
+            equipped_filter: EquippedFilter::Equipped,
+            ..ItemListOptions::default()
+        };
+        assert_eq!(list_names(&character, &options), ["Gold"]);
+        options.equipped_filter = options.equipped_filter.next();
+        assert_eq!(
+            list_names(&character, &options),
+            ["Silver", "Bronze", "Copper"]
+        );
+
+        options = ItemListOptions {
+            search: "ER".to_string(),
+            ..ItemListOptions::default()
+        };
+        assert_eq!(list_names(&character, &options), ["Silver", "Copper"]);
+    }
+}
@@ -1,9 +1,19 @@
-use rand::{thread_rng, Rng};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-use std::borrow::Cow;
-use uuid::Uuid;
-
-use crate::{character::CharacterClass, session::PlayerCharacter};
+use std::{borrow::Cow, cmp::Reverse, fmt, mem};
+use uuid::Builder;
+
+use crate::{
+    character::CharacterClass,
+    enemy::{
+        Enemy, BOSS_ENEMY_NAME_FIRE_DRAGON, BOSS_ENEMY_NAME_OGRE_KING,
+        BOSS_ENEMY_NAME_UNDEAD_SORCERER,
+    },
+    quest::QuestEvent,
+    rng::game_rng,
+    session::PlayerCharacter,
+    tr,
+};
 
 pub const ITEM_RARITY_DROP_RATES: ItemRarityDropRates = ItemRarityDropRates {
     common: 0.43,
@@ -39,6 +49,22 @@ pub const ENCHANTMENT_BASE_VALUES: EnchantmentBaseValues = EnchantmentBaseValues
     max_defense: 2,
     min_mana: 10,
     max_mana: 15,
+    min_lifesteal: 0.03,
+    max_lifesteal: 0.06,
+    min_thorns: 3,
+    max_thorns: 5,
+    min_evasion: 0.03,
+    max_evasion: 0.05,
+    min_mana_regen: 3,
+    max_mana_regen: 5,
+    min_crit_damage_multiplier: 0.10,
+    max_crit_damage_multiplier: 0.20,
+    min_exp_bonus: 0.05,
+    max_exp_bonus: 0.10,
+    min_gold_find: 0.10,
+    max_gold_find: 0.20,
+    min_magic_find: 0.05,
+    max_magic_find: 0.10,
 };
 
 //-------------------//
@@ -59,6 +85,44 @@ pub const ITEM_MANA_POTION: ItemInfo = ItemInfo {
     category: ItemCategory::Consumable,
 };
 
+pub const ITEM_ELIXIR_OF_STRENGTH_NAME: &str = "Elixir of Strength";
+pub const ITEM_ELIXIR_OF_STRENGTH: ItemInfo = ItemInfo {
+    name: Cow::Borrowed(ITEM_ELIXIR_OF_STRENGTH_NAME),
+    description: Cow::Borrowed("A brewed elixir that increases damage for the rest of the fight."),
+    category: ItemCategory::Consumable,
+};
+
+pub const ITEM_ANTIDOTE_NAME: &str = "Antidote";
+pub const ITEM_ANTIDOTE: ItemInfo = ItemInfo {
+    name: Cow::Borrowed(ITEM_ANTIDOTE_NAME),
+    description: Cow::Borrowed("A brewed remedy that cures stats reduced by enemy skills."),
+    category: ItemCategory::Consumable,
+};
+
+pub const ITEM_FIRE_BOMB_NAME: &str = "Fire Bomb";
+pub const ITEM_FIRE_BOMB: ItemInfo = ItemInfo {
+    name: Cow::Borrowed(ITEM_FIRE_BOMB_NAME),
+    description: Cow::Borrowed("A volatile flask that bursts into flames when thrown at an enemy."),
+    category: ItemCategory::Consumable,
+};
+
+pub const ITEM_RESURRECTION_DRAUGHT_NAME: &str = "Resurrection Draught";
+pub const ITEM_RESURRECTION_DRAUGHT: ItemInfo = ItemInfo {
+    name: Cow::Borrowed(ITEM_RESURRECTION_DRAUGHT_NAME),
+    description: Cow::Borrowed(
+        "A rare draught that is consumed automatically to revive you when you die in a fight.",
+    ),
+    category: ItemCategory::Consumable,
+};
+
+/// Damage increase of Elixir of Strength in percentage of total damage.
+pub const ELIXIR_OF_STRENGTH_DAMAGE_PERCENTAGE: u32 = 25;
+/// Fire Bomb damage as a multiplier of the player's total damage.
+/// Fire Bomb damage ignores enemy defense.
+pub const FIRE_BOMB_DAMAGE_MULTIPLIER: u32 = 2;
+/// Health restored by Resurrection Draught in percentage of max health.
+pub const RESURRECTION_DRAUGHT_HEALTH_PERCENTAGE: u32 = 50;
+
 //---------------//
 // Weapon items //
 //-------------//
@@ -113,6 +177,97 @@ pub const ITEM_RING: ItemInfo = ItemInfo {
     category: ItemCategory::Ring,
 };
 
+//------------//
+// Item sets //
+//----------//
+
+pub const SET_ITEM_DROP_RATE: f64 = 0.25;
+pub const SET_ITEM_RARITY: ItemRarity = ItemRarity::Epic;
+pub const ITEM_SET_PIECES: u8 = 3;
+
+pub const ITEM_SET_DRAGONSCALE_BONUSES: [ItemSetBonus; 2] = [
+    ItemSetBonus {
+        pieces: 2,
+        enchantments: &[Enchantment::Damage(12), Enchantment::CritHitRate(0.05)],
+    },
+    ItemSetBonus {
+        pieces: 3,
+        enchantments: &[Enchantment::Health(60), Enchantment::Defense(6)],
+    },
+];
+
+pub const ITEM_SET_OGRE_WARLORD_BONUSES: [ItemSetBonus; 2] = [
+    ItemSetBonus {
+        pieces: 2,
+        enchantments: &[Enchantment::Health(50)],
+    },
+    ItemSetBonus {
+        pieces: 3,
+        enchantments: &[Enchantment::Damage(15), Enchantment::Defense(5)],
+    },
+];
+
+pub const ITEM_SET_LICHBONE_BONUSES: [ItemSetBonus; 2] = [
+    ItemSetBonus {
+        pieces: 2,
+        enchantments: &[Enchantment::Mana(40)],
+    },
+    ItemSetBonus {
+        pieces: 3,
+        enchantments: &[Enchantment::CritHitRate(0.10), Enchantment::Damage(10)],
+    },
+];
+
+//---------------//
+// Unique items //
+//-------------//
+
+/// Chance for a generated legendary item to be a unique item.
+pub const UNIQUE_ITEM_CHANCE: f64 = 0.20;
+
+pub const UNIQUE_WEAPONS: [UniqueItem; 3] = [
+    UniqueItem {
+        name: "unique.dawnbreaker.name",
+        lore: "unique.dawnbreaker.lore",
+    },
+    UniqueItem {
+        name: "unique.whisper_of_the_void.name",
+        lore: "unique.whisper_of_the_void.lore",
+    },
+    UniqueItem {
+        name: "unique.oathkeeper.name",
+        lore: "unique.oathkeeper.lore",
+    },
+];
+pub const UNIQUE_ARMORS: [UniqueItem; 3] = [
+    UniqueItem {
+        name: "unique.aegis_of_the_fallen_king.name",
+        lore: "unique.aegis_of_the_fallen_king.lore",
+    },
+    UniqueItem {
+        name: "unique.shroud_of_ashes.name",
+        lore: "unique.shroud_of_ashes.lore",
+    },
+    UniqueItem {
+        name: "unique.gravewardens_mail.name",
+        lore: "unique.gravewardens_mail.lore",
+    },
+];
+pub const UNIQUE_RINGS: [UniqueItem; 3] = [
+    UniqueItem {
+        name: "unique.band_of_endless_stars.name",
+        lore: "unique.band_of_endless_stars.lore",
+    },
+    UniqueItem {
+        name: "unique.the_gamblers_loop.name",
+        lore: "unique.the_gamblers_loop.lore",
+    },
+    UniqueItem {
+        name: "unique.sorcerers_last_breath.name",
+        lore: "unique.sorcerers_last_breath.lore",
+    },
+];
+
 //-----------------------------------//
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -122,10 +277,53 @@ pub struct ItemInfo {
     pub category: ItemCategory,
 }
 
+impl ItemInfo {
+    /// Translated name of the base item.
+    pub fn get_display_name(&self) -> String {
+        match get_item_message_id(&self.name) {
+            Some(id) => tr!(&format!("item.{}.name", id)),
+            None => self.name.to_string(),
+        }
+    }
+
+    /// Translated description of the base item.
+    pub fn get_display_description(&self) -> String {
+        match get_item_message_id(&self.name) {
+            Some(id) => tr!(&format!("item.{}.description", id)),
+            None => self.description.to_string(),
+        }
+    }
+}
+
+/// Item names are kept in English in the game data.
+fn get_item_message_id(name: &str) -> Option<&'static str> {
+    match name {
+        ITEM_HEALTH_POTION_NAME => Some("health_potion"),
+        ITEM_MANA_POTION_NAME => Some("mana_potion"),
+        ITEM_ELIXIR_OF_STRENGTH_NAME => Some("elixir_of_strength"),
+        ITEM_ANTIDOTE_NAME => Some("antidote"),
+        ITEM_FIRE_BOMB_NAME => Some("fire_bomb"),
+        ITEM_RESURRECTION_DRAUGHT_NAME => Some("resurrection_draught"),
+        "Sword" => Some("sword"),
+        "Axe" => Some("axe"),
+        "Staff" => Some("staff"),
+        "Dagger" => Some("dagger"),
+        "Halberd" => Some("halberd"),
+        "Armor" => Some("armor"),
+        "Ring" => Some("ring"),
+        _ => None,
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ConsumableItem {
     pub info: ItemInfo,
+    /// Text describing the effect. Displayed in menus.
     pub effect: String,
+    /// Effect applied when the item is used.
+    /// Saves made before this existed are migrated when the save file is loaded.
+    #[serde(default)]
+    pub effect_kind: ConsumableEffect,
     pub rarity: ItemRarity,
     pub amount_in_inventory: u32,
 }
@@ -135,6 +333,7 @@ impl ConsumableItem {
         Self {
             info: ITEM_HEALTH_POTION,
             effect: get_health_potion_effect(&rarity),
+            effect_kind: ConsumableEffect::RestoreHealth,
             rarity,
             amount_in_inventory: 0,
         }
@@ -144,42 +343,166 @@ impl ConsumableItem {
         Self {
             info: ITEM_MANA_POTION,
             effect: get_mana_potion_effect(&rarity),
+            effect_kind: ConsumableEffect::RestoreMana,
             rarity,
             amount_in_inventory: 0,
         }
     }
 
+    pub fn new_elixir_of_strength() -> Self {
+        Self {
+            info: ITEM_ELIXIR_OF_STRENGTH,
+            effect: get_consumable_effect(&ConsumableEffect::Strength, &ItemRarity::Rare),
+            effect_kind: ConsumableEffect::Strength,
+            rarity: ItemRarity::Rare,
+            amount_in_inventory: 0,
+        }
+    }
+
+    pub fn new_antidote() -> Self {
+        Self {
+            info: ITEM_ANTIDOTE,
+            effect: get_consumable_effect(&ConsumableEffect::Cure, &ItemRarity::Uncommon),
+            effect_kind: ConsumableEffect::Cure,
+            rarity: ItemRarity::Uncommon,
+            amount_in_inventory: 0,
+        }
+    }
+
+    pub fn new_fire_bomb() -> Self {
+        Self {
+            info: ITEM_FIRE_BOMB,
+            effect: get_consumable_effect(&ConsumableEffect::FireDamage, &ItemRarity::Rare),
+            effect_kind: ConsumableEffect::FireDamage,
+            rarity: ItemRarity::Rare,
+            amount_in_inventory: 0,
+        }
+    }
+
+    pub fn new_resurrection_draught() -> Self {
+        Self {
+            info: ITEM_RESURRECTION_DRAUGHT,
+            effect: get_consumable_effect(&ConsumableEffect::Resurrection, &ItemRarity::Legendary),
+            effect_kind: ConsumableEffect::Resurrection,
+            rarity: ItemRarity::Legendary,
+            amount_in_inventory: 0,
+        }
+    }
+
+    /// Key of the item in the inventory. Stays the same in every language.
+    pub fn get_inventory_key(&self) -> String {
+        format!("{:?} {}", self.rarity, self.info.name)
+    }
+
+    /// Translated effect of the item.
+    /// Items of unknown kind show the effect text saved with them.
+    pub fn get_effect(&self) -> String {
+        match self.effect_kind {
+            ConsumableEffect::Unknown => self.effect.clone(),
+            _ => get_consumable_effect(&self.effect_kind, &self.rarity),
+        }
+    }
+
     /// Returns text telling what the item did.
-    pub fn use_item(&self, character: &mut PlayerCharacter) -> (String, ItemRarity, String) {
-        let display_name = get_item_display_name(CharacterItem::Consumable(&self));
-        let mut item_name = "Player used an unknown item.".to_string();
-        let mut effect = "Nothing happened".to_string();
-        let mut rarity = ItemRarity::Unknown;
-        match self.info.name.as_ref() {
-            ITEM_HEALTH_POTION_NAME => {
+    /// Returns empty strings if the item cannot be used right now.
+    pub fn use_item(
+        &self,
+        character: &mut PlayerCharacter,
+        enemy: &mut Enemy,
+    ) -> (String, ItemRarity, String) {
+        let display_name = get_item_display_name(CharacterItem::Consumable(self));
+        let inventory_key = self.get_inventory_key();
+        let effect = match self.effect_kind {
+            ConsumableEffect::RestoreHealth => {
                 let heal_percentage = get_potion_effect_percentage(&self.rarity) as f64 / 100.0;
                 let restored_health = character
                     .restore_health((heal_percentage * character.get_total_health() as f64) as u32);
-                item_name = format!("{}", &display_name);
-                rarity = self.rarity.clone();
-                effect = format!("Player restored {} health points", restored_health);
+                tr!("fight.player_restored_health", health = restored_health)
             }
-            ITEM_MANA_POTION_NAME => {
+            ConsumableEffect::RestoreMana => {
                 let heal_percentage = get_potion_effect_percentage(&self.rarity) as f64 / 100.0;
                 let restored_mana = character
                     .restore_mana((heal_percentage * character.get_total_mana() as f64) as u32);
-                item_name = format!("{}", &display_name);
-                rarity = self.rarity.clone();
-                effect = format!("Player restored {} mana points", restored_mana);
+                tr!("fight.player_restored_mana", mana = restored_mana)
             }
-            _ => {}
-        }
+            ConsumableEffect::Strength => {
+                let increased_damage =
+                    character.get_total_damage() * ELIXIR_OF_STRENGTH_DAMAGE_PERCENTAGE / 100;
+                character.temp_stat_boosts.increase_damage(increased_damage);
+                tr!("fight.player_damage_increased", damage = increased_damage)
+            }
+            ConsumableEffect::Cure => {
+                let (restored_damage, restored_defense) = character.cure_stat_reductions();
+                tr!(
+                    "fight.player_cured",
+                    damage = restored_damage,
+                    defense = restored_defense
+                )
+            }
+            ConsumableEffect::FireDamage => {
+                let damage = enemy
+                    .take_pure_damage(character.get_total_damage() * FIRE_BOMB_DAMAGE_MULTIPLIER);
+                tr!("fight.enemy_took_fire_damage", damage = damage)
+            }
+            ConsumableEffect::Resurrection => {
+                if !character.is_dead() {
+                    return (String::new(), ItemRarity::Unknown, String::new());
+                }
+                let restored_health = character.restore_health(
+                    character.get_total_health() * RESURRECTION_DRAUGHT_HEALTH_PERCENTAGE / 100,
+                );
+                tr!("fight.player_revived", health = restored_health)
+            }
+            ConsumableEffect::Unknown => {
+                return (
+                    tr!("fight.player_used_unknown_item"),
+                    ItemRarity::Unknown,
+                    tr!("fight.nothing_happened"),
+                )
+            }
+        };
         if self.amount_in_inventory > 1 {
-            character.decrease_consumable_inventory_amount(&display_name, 1);
+            character.decrease_consumable_inventory_amount(&inventory_key, 1);
         } else {
-            character.delete_consumable(&display_name);
+            character.delete_consumable(&inventory_key);
+        }
+        if let ConsumableEffect::RestoreHealth | ConsumableEffect::RestoreMana = self.effect_kind {
+            character.update_quests(QuestEvent::PotionUsed);
+        }
+        (display_name, self.rarity.clone(), effect)
+    }
+}
+
+/// Effect of a consumable item.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub enum ConsumableEffect {
+    RestoreHealth,
+    RestoreMana,
+    /// Increases damage for the rest of the fight.
+    Strength,
+    /// Removes stat reductions caused by enemy skills.
+    Cure,
+    /// Deals damage to the enemy.
+    FireDamage,
+    /// Revives the player when they die in a fight. Used automatically.
+    Resurrection,
+    #[default]
+    Unknown,
+}
+
+impl ConsumableEffect {
+    /// Returns the effect of a consumable item by its name.
+    /// Used to migrate items from saves that don't have the effect stored.
+    pub fn from_item_name(name: &str) -> Self {
+        match name {
+            ITEM_HEALTH_POTION_NAME => Self::RestoreHealth,
+            ITEM_MANA_POTION_NAME => Self::RestoreMana,
+            ITEM_ELIXIR_OF_STRENGTH_NAME => Self::Strength,
+            ITEM_ANTIDOTE_NAME => Self::Cure,
+            ITEM_FIRE_BOMB_NAME => Self::FireDamage,
+            ITEM_RESURRECTION_DRAUGHT_NAME => Self::Resurrection,
+            _ => Self::Unknown,
         }
-        (item_name, rarity, effect)
     }
 }
 
@@ -197,6 +520,21 @@ pub struct ArmorItem {
     pub rarity: ItemRarity,
     pub stats: ArmorItemStats,
     pub enchantments: Vec<Enchantment>,
+    /// Item set the item belongs to, if any.
+    #[serde(default)]
+    pub set: Option<ItemSet>,
+    /// Generated name with affixes. Falls back to the base item name if not set.
+    #[serde(default)]
+    pub name: Option<ItemName>,
+    /// Message ID of the lore text of unique items.
+    #[serde(default)]
+    pub lore: Option<String>,
+    /// Order in which the item was received. Higher is newer.
+    #[serde(default)]
+    pub sequence: u64,
+    /// Locked items are protected from selling, salvaging and deletion.
+    #[serde(default)]
+    pub locked: bool,
 }
 
 impl ArmorItem {
@@ -209,11 +547,24 @@ impl ArmorItem {
     ) -> Self {
         Self {
             info,
-            id: Uuid::new_v4().to_string(),
+            id: new_item_id(),
             level,
             rarity,
             stats,
             enchantments,
+            set: None,
+            name: None,
+            lore: None,
+            sequence: 0,
+            locked: false,
+        }
+    }
+
+    /// Returns the generated name of the item or the base item name.
+    pub fn get_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.get_display_name(&self.info),
+            None => self.info.get_display_name(),
         }
     }
 
@@ -243,6 +594,21 @@ pub struct WeaponItem {
     pub rarity: ItemRarity,
     pub stats: WeaponItemStats,
     pub enchantments: Vec<Enchantment>,
+    /// Item set the item belongs to, if any.
+    #[serde(default)]
+    pub set: Option<ItemSet>,
+    /// Generated name with affixes. Falls back to the base item name if not set.
+    #[serde(default)]
+    pub name: Option<ItemName>,
+    /// Message ID of the lore text of unique items.
+    #[serde(default)]
+    pub lore: Option<String>,
+    /// Order in which the item was received. Higher is newer.
+    #[serde(default)]
+    pub sequence: u64,
+    /// Locked items are protected from selling, salvaging and deletion.
+    #[serde(default)]
+    pub locked: bool,
 }
 
 impl WeaponItem {
@@ -255,11 +621,24 @@ impl WeaponItem {
     ) -> Self {
         Self {
             info,
-            id: Uuid::new_v4().to_string(),
+            id: new_item_id(),
             level,
             rarity,
             stats,
             enchantments,
+            set: None,
+            name: None,
+            lore: None,
+            sequence: 0,
+            locked: false,
+        }
+    }
+
+    /// Returns the generated name of the item or the base item name.
+    pub fn get_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.get_display_name(&self.info),
+            None => self.info.get_display_name(),
         }
     }
 
@@ -288,6 +667,21 @@ pub struct RingItem {
     pub rarity: ItemRarity,
     pub stats: RingItemStats,
     pub enchantments: Vec<Enchantment>,
+    /// Item set the item belongs to, if any.
+    #[serde(default)]
+    pub set: Option<ItemSet>,
+    /// Generated name with affixes. Falls back to the base item name if not set.
+    #[serde(default)]
+    pub name: Option<ItemName>,
+    /// Message ID of the lore text of unique items.
+    #[serde(default)]
+    pub lore: Option<String>,
+    /// Order in which the item was received. Higher is newer.
+    #[serde(default)]
+    pub sequence: u64,
+    /// Locked items are protected from selling, salvaging and deletion.
+    #[serde(default)]
+    pub locked: bool,
 }
 
 impl RingItem {
@@ -300,11 +694,24 @@ impl RingItem {
     ) -> Self {
         Self {
             info,
-            id: Uuid::new_v4().to_string(),
+            id: new_item_id(),
             level,
             rarity,
             stats,
             enchantments,
+            set: None,
+            name: None,
+            lore: None,
+            sequence: 0,
+            locked: false,
+        }
+    }
+
+    /// Returns the generated name of the item or the base item name.
+    pub fn get_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.get_display_name(&self.info),
+            None => self.info.get_display_name(),
         }
     }
 
@@ -358,6 +765,22 @@ pub struct EnchantmentBaseValues {
     pub max_defense: u32,
     pub min_mana: u32,
     pub max_mana: u32,
+    pub min_lifesteal: f64,
+    pub max_lifesteal: f64,
+    pub min_thorns: u32,
+    pub max_thorns: u32,
+    pub min_evasion: f64,
+    pub max_evasion: f64,
+    pub min_mana_regen: u32,
+    pub max_mana_regen: u32,
+    pub min_crit_damage_multiplier: f64,
+    pub max_crit_damage_multiplier: f64,
+    pub min_exp_bonus: f64,
+    pub max_exp_bonus: f64,
+    pub min_gold_find: f64,
+    pub max_gold_find: f64,
+    pub min_magic_find: f64,
+    pub max_magic_find: f64,
 }
 
 pub enum CharacterItem<'a> {
@@ -376,6 +799,37 @@ pub enum CharacterItemOwned {
     Unknown,
 }
 
+impl CharacterItemOwned {
+    pub fn as_character_item(&self) -> CharacterItem<'_> {
+        match self {
+            Self::Consumable(item) => CharacterItem::Consumable(item),
+            Self::Weapon(item) => CharacterItem::Weapon(item),
+            Self::Armor(item) => CharacterItem::Armor(item),
+            Self::Ring(item) => CharacterItem::Ring(item),
+            Self::Unknown => CharacterItem::Unknown,
+        }
+    }
+
+    /// Returns a key that orders items by the time they were received.
+    /// Items without a sequence number are ordered by ID.
+    pub fn get_receive_order_key(&self) -> (u64, String) {
+        match self {
+            Self::Weapon(item) => (item.sequence, item.id.clone()),
+            Self::Armor(item) => (item.sequence, item.id.clone()),
+            Self::Ring(item) => (item.sequence, item.id.clone()),
+            _ => (0, String::new()),
+        }
+    }
+}
+
+/// Returns a new random item ID.
+/// Uses the game RNG so item IDs are the same in replays.
+pub fn new_item_id() -> String {
+    Builder::from_random_bytes(game_rng().gen())
+        .into_uuid()
+        .to_string()
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub enum Enchantment {
     Damage(u32),
@@ -383,9 +837,99 @@ pub enum Enchantment {
     Health(u32),
     Defense(u32),
     Mana(u32),
+    /// Portion of damage dealt with attacks restored as health.
+    Lifesteal(f64),
+    /// Damage dealt back to enemies that attack the player.
+    Thorns(u32),
+    /// Chance to evade enemy attacks.
+    Evasion(f64),
+    /// Mana restored at the start of each turn in fights.
+    ManaRegen(u32),
+    CritDamageMultiplier(f64),
+    /// Portion of extra EXP gained.
+    ExpBonus(f64),
+    /// Portion of extra gold found.
+    GoldFind(f64),
+    /// Increases the chance of finding items of higher rarity.
+    MagicFind(f64),
     Unknown,
 }
 
+/// Named item set. Set pieces drop from a specific boss and
+/// give bonuses when several pieces of the same set are equipped.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum ItemSet {
+    /// Dropped by Fire Dragon.
+    Dragonscale,
+    /// Dropped by Ogre King.
+    OgreWarlord,
+    /// Dropped by Undead Sorcerer.
+    Lichbone,
+}
+
+impl fmt::Display for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Dragonscale => tr!("item_set.dragonscale"),
+                Self::OgreWarlord => tr!("item_set.ogre_warlord"),
+                Self::Lichbone => tr!("item_set.lichbone"),
+            }
+        )
+    }
+}
+
+/// Bonus that is active when at least `pieces` items of the set are equipped.
+pub struct ItemSetBonus {
+    pub pieces: u8,
+    pub enchantments: &'static [Enchantment],
+}
+
+/// Generated name of an item.
+/// Saved as message IDs so the name is shown in the current language.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ItemName {
+    /// Name of a unique item.
+    Unique { unique: String },
+    /// Base item name with optional prefix and suffix.
+    Affixes {
+        prefix: Option<String>,
+        suffix: Option<String>,
+    },
+    /// Name saved as text by older versions.
+    Text(String),
+}
+
+impl ItemName {
+    /// Translated name of an item with the base item `info`.
+    pub fn get_display_name(&self, info: &ItemInfo) -> String {
+        match self {
+            Self::Unique { unique } => tr!(unique),
+            Self::Affixes { prefix, suffix } => {
+                let mut name = info.get_display_name();
+                if let Some(prefix) = prefix {
+                    name = tr!("item.name_with_prefix", prefix = tr!(prefix), name = name);
+                }
+                if let Some(suffix) = suffix {
+                    name = tr!("item.name_with_suffix", suffix = tr!(suffix), name = name);
+                }
+                name
+            }
+            Self::Text(name) => name.clone(),
+        }
+    }
+}
+
+/// Hand-authored legendary item with a fixed name and lore text.
+/// The name and lore are message IDs.
+pub struct UniqueItem {
+    pub name: &'static str,
+    pub lore: &'static str,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum ItemRarity {
     Common,
@@ -397,6 +941,24 @@ pub enum ItemRarity {
     Unknown,
 }
 
+impl fmt::Display for ItemRarity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Common => tr!("rarity.common"),
+                Self::Uncommon => tr!("rarity.uncommon"),
+                Self::Rare => tr!("rarity.rare"),
+                Self::Epic => tr!("rarity.epic"),
+                Self::Legendary => tr!("rarity.legendary"),
+                Self::Mythical => tr!("rarity.mythical"),
+                Self::Unknown => tr!("rarity.unknown"),
+            }
+        )
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum ItemCategory {
     Consumable,
@@ -406,6 +968,22 @@ pub enum ItemCategory {
     Unknown,
 }
 
+impl fmt::Display for ItemCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Consumable => tr!("item_category.consumable"),
+                Self::Weapon => tr!("item_category.weapon"),
+                Self::Armor => tr!("item_category.armor"),
+                Self::Ring => tr!("item_category.ring"),
+                Self::Unknown => tr!("item_category.unknown"),
+            }
+        )
+    }
+}
+
 /// Returns the effect percentage of potions.
 /// For example, returns 50 if the percentage is 50%.
 /// 50 can be divided by 100 to get the decimal for calculations: 50/100 = 0.5.
@@ -422,41 +1000,171 @@ pub fn get_potion_effect_percentage(rarity: &ItemRarity) -> i32 {
 }
 
 pub fn get_health_potion_effect(rarity: &ItemRarity) -> String {
-    format!(
-        "Restores {}% of your maximum health points.",
-        get_potion_effect_percentage(rarity)
+    tr!(
+        "item.effect.restore_health",
+        percentage = get_potion_effect_percentage(rarity)
     )
 }
 
 pub fn get_mana_potion_effect(rarity: &ItemRarity) -> String {
-    format!(
-        "Restores {}% of your maximum mana points.",
-        get_potion_effect_percentage(rarity)
+    tr!(
+        "item.effect.restore_mana",
+        percentage = get_potion_effect_percentage(rarity)
     )
 }
 
+/// Returns the text telling what a consumable item does.
+pub fn get_consumable_effect(effect: &ConsumableEffect, rarity: &ItemRarity) -> String {
+    match effect {
+        ConsumableEffect::RestoreHealth => get_health_potion_effect(rarity),
+        ConsumableEffect::RestoreMana => get_mana_potion_effect(rarity),
+        ConsumableEffect::Strength => tr!(
+            "item.effect.strength",
+            percentage = ELIXIR_OF_STRENGTH_DAMAGE_PERCENTAGE
+        ),
+        ConsumableEffect::Cure => tr!("item.effect.cure"),
+        ConsumableEffect::FireDamage => tr!(
+            "item.effect.fire_damage",
+            multiplier = FIRE_BOMB_DAMAGE_MULTIPLIER
+        ),
+        ConsumableEffect::Resurrection => tr!(
+            "item.effect.resurrection",
+            percentage = RESURRECTION_DRAUGHT_HEALTH_PERCENTAGE
+        ),
+        ConsumableEffect::Unknown => tr!("item.effect.unknown"),
+    }
+}
+
 /// Returns a string representation of an item.
 /// The string is used to display the item in menus.
 pub fn get_item_display_name<'a>(item: CharacterItem<'a>) -> String {
     match item {
-        CharacterItem::Consumable(consumable) => {
-            format!("{:?} {}", consumable.rarity, consumable.info.name)
+        CharacterItem::Consumable(consumable) => tr!(
+            "item.display_name",
+            rarity = consumable.rarity,
+            name = consumable.info.get_display_name()
+        ),
+        CharacterItem::Weapon(weapon) => match &weapon.set {
+            Some(set) => tr!(
+                "item.set_display_name",
+                rarity = weapon.rarity,
+                set = set,
+                name = weapon.get_name()
+            ),
+            None => tr!(
+                "item.display_name",
+                rarity = weapon.rarity,
+                name = weapon.get_name()
+            ),
+        },
+        CharacterItem::Armor(armor) => match &armor.set {
+            Some(set) => tr!(
+                "item.set_display_name",
+                rarity = armor.rarity,
+                set = set,
+                name = armor.get_name()
+            ),
+            None => tr!(
+                "item.display_name",
+                rarity = armor.rarity,
+                name = armor.get_name()
+            ),
+        },
+        CharacterItem::Ring(ring) => match &ring.set {
+            Some(set) => tr!(
+                "item.set_display_name",
+                rarity = ring.rarity,
+                set = set,
+                name = ring.get_name()
+            ),
+            None => tr!(
+                "item.display_name",
+                rarity = ring.rarity,
+                name = ring.get_name()
+            ),
+        },
+        _ => tr!("item.unknown"),
+    }
+}
+
+/// Returns the text used to display an enchantment in menus.
+pub fn get_enchantment_display(enchantment: &Enchantment) -> String {
+    match enchantment {
+        Enchantment::Damage(val) => tr!("enchantment.damage", value = val),
+        Enchantment::CritHitRate(val) => {
+            tr!("enchantment.crit_hit_rate", value = format!("{:.2}", val))
+        }
+        Enchantment::Health(val) => tr!("enchantment.health", value = val),
+        Enchantment::Defense(val) => tr!("enchantment.defense", value = val),
+        Enchantment::Mana(val) => tr!("enchantment.mana", value = val),
+        Enchantment::Lifesteal(val) => {
+            tr!(
+                "enchantment.lifesteal",
+                value = format!("{:.2}", val * 100.0)
+            )
         }
-        CharacterItem::Weapon(weapon) => {
-            format!("{:?} {}", weapon.rarity, weapon.info.name)
+        Enchantment::Thorns(val) => tr!("enchantment.thorns", value = val),
+        Enchantment::Evasion(val) => {
+            tr!("enchantment.evasion", value = format!("{:.2}", val * 100.0))
         }
-        CharacterItem::Armor(armor) => {
-            format!("{:?} {}", armor.rarity, armor.info.name)
+        Enchantment::ManaRegen(val) => tr!("enchantment.mana_regen", value = val),
+        Enchantment::CritDamageMultiplier(val) => tr!(
+            "enchantment.crit_damage_multiplier",
+            value = format!("{:.2}", val)
+        ),
+        Enchantment::ExpBonus(val) => {
+            tr!(
+                "enchantment.exp_bonus",
+                value = format!("{:.2}", val * 100.0)
+            )
         }
-        CharacterItem::Ring(ring) => {
-            format!("{:?} {}", ring.rarity, ring.info.name)
+        Enchantment::GoldFind(val) => {
+            tr!(
+                "enchantment.gold_find",
+                value = format!("{:.2}", val * 100.0)
+            )
         }
-        _ => format!("?Unknown?"),
+        Enchantment::MagicFind(val) => {
+            tr!(
+                "enchantment.magic_find",
+                value = format!("{:.2}", val * 100.0)
+            )
+        }
+        _ => tr!("enchantment.unknown"),
+    }
+}
+
+/// Returns the bonuses of an item set.
+pub fn get_item_set_bonuses(set: &ItemSet) -> &'static [ItemSetBonus] {
+    match set {
+        ItemSet::Dragonscale => &ITEM_SET_DRAGONSCALE_BONUSES,
+        ItemSet::OgreWarlord => &ITEM_SET_OGRE_WARLORD_BONUSES,
+        ItemSet::Lichbone => &ITEM_SET_LICHBONE_BONUSES,
+    }
+}
+
+/// Returns the enchantments of all set bonuses that are active
+/// with the given amount of equipped pieces.
+pub fn get_active_item_set_bonuses(set: &ItemSet, equipped_pieces: u8) -> Vec<Enchantment> {
+    get_item_set_bonuses(set)
+        .iter()
+        .filter(|bonus| equipped_pieces >= bonus.pieces)
+        .flat_map(|bonus| bonus.enchantments.iter().cloned())
+        .collect()
+}
+
+/// Returns the item set that drops from a boss enemy.
+pub fn get_boss_item_set(boss_name: &str) -> Option<ItemSet> {
+    match boss_name {
+        BOSS_ENEMY_NAME_FIRE_DRAGON => Some(ItemSet::Dragonscale),
+        BOSS_ENEMY_NAME_OGRE_KING => Some(ItemSet::OgreWarlord),
+        BOSS_ENEMY_NAME_UNDEAD_SORCERER => Some(ItemSet::Lichbone),
+        _ => None,
     }
 }
 
 pub fn get_item_level_display<'a>(level: u32) -> String {
-    format!("(Level {})", level)
+    tr!("item.level", level = level)
 }
 
 /// Returns the purchase value of an item in gold.
@@ -505,7 +1213,7 @@ pub fn create_starter_weapon(character_class: &CharacterClass) -> WeaponItem {
 }
 
 pub fn random_equipment_item() -> ItemCategory {
-    let mut rng = rand::thread_rng();
+    let mut rng = game_rng();
     let rand_num = rng.gen_range(0..=2);
     match rand_num {
         0 => ItemCategory::Weapon,
@@ -515,8 +1223,11 @@ pub fn random_equipment_item() -> ItemCategory {
     }
 }
 
-pub fn random_item_rarity(drop_rates: &ItemRarityDropRates) -> ItemRarity {
-    let mut rng = rand::thread_rng();
+/// `magic_find` increases the drop rates of rarities above common.
+/// E.g. magic find 0.2 multiplies them by 1.2.
+pub fn random_item_rarity(drop_rates: &ItemRarityDropRates, magic_find: f64) -> ItemRarity {
+    let drop_rates = &apply_magic_find(drop_rates, magic_find);
+    let mut rng = game_rng();
     let rand_num = rng.gen_range(0.0..1.0);
     let mut drop_rate = 0.0;
 
@@ -548,6 +1259,26 @@ pub fn random_item_rarity(drop_rates: &ItemRarityDropRates) -> ItemRarity {
     ItemRarity::Unknown
 }
 
+/// Returns drop rates where rarities above common are multiplied by `1 + magic_find`.
+/// Common drop rate gets what is left over. The multiplier is clamped
+/// so that the rates above common add up to at most 1.
+fn apply_magic_find(drop_rates: &ItemRarityDropRates, magic_find: f64) -> ItemRarityDropRates {
+    let above_common =
+        drop_rates.uncommon + drop_rates.rare + drop_rates.epic + drop_rates.legendary;
+    let multiplier = (1.0 + magic_find).min(1.0 / above_common);
+    let uncommon = drop_rates.uncommon * multiplier;
+    let rare = drop_rates.rare * multiplier;
+    let epic = drop_rates.epic * multiplier;
+    let legendary = drop_rates.legendary * multiplier;
+    ItemRarityDropRates {
+        common: (1.0 - uncommon - rare - epic - legendary).max(0.0),
+        uncommon,
+        rare,
+        epic,
+        legendary,
+    }
+}
+
 pub fn num_enchantments(rarity: &ItemRarity) -> u8 {
     match rarity {
         ItemRarity::Common => 0,
@@ -588,18 +1319,28 @@ pub fn random_weapon_enchantment(
     base_values: &EnchantmentBaseValues,
     dungeon_floor: u32,
 ) -> Enchantment {
-    let mut rng = thread_rng();
-    let rand_num = rng.gen_range(0..=1);
+    let mut rng = game_rng();
+    let rand_num = rng.gen_range(0..=3);
     match rand_num {
         0 => {
             let damage = rng.gen_range(base_values.min_damage..=base_values.max_damage)
                 + (2 * dungeon_floor);
-            return Enchantment::Damage(damage);
+            Enchantment::Damage(damage)
         }
         1 => {
             let crit_hit_rate =
                 rng.gen_range(base_values.min_crit_hit_rate..=base_values.max_crit_hit_rate);
-            return Enchantment::CritHitRate(crit_hit_rate);
+            Enchantment::CritHitRate(crit_hit_rate)
+        }
+        2 => {
+            let lifesteal = rng.gen_range(base_values.min_lifesteal..=base_values.max_lifesteal);
+            Enchantment::Lifesteal(lifesteal)
+        }
+        3 => {
+            let crit_damage_multiplier = rng.gen_range(
+                base_values.min_crit_damage_multiplier..=base_values.max_crit_damage_multiplier,
+            );
+            Enchantment::CritDamageMultiplier(crit_damage_multiplier)
         }
         _ => Enchantment::Unknown,
     }
@@ -609,18 +1350,27 @@ pub fn random_armor_enchantment(
     base_values: &EnchantmentBaseValues,
     dungeon_floor: u32,
 ) -> Enchantment {
-    let mut rng = thread_rng();
-    let rand_num = rng.gen_range(0..=1);
+    let mut rng = game_rng();
+    let rand_num = rng.gen_range(0..=3);
     match rand_num {
         0 => {
             let health = rng.gen_range(base_values.min_health..=base_values.max_health)
                 + (4 * dungeon_floor);
-            return Enchantment::Health(health);
+            Enchantment::Health(health)
         }
         1 => {
             let defense = rng.gen_range(base_values.min_defense..=base_values.max_defense)
                 + (1 * dungeon_floor);
-            return Enchantment::Defense(defense);
+            Enchantment::Defense(defense)
+        }
+        2 => {
+            let thorns =
+                rng.gen_range(base_values.min_thorns..=base_values.max_thorns) + dungeon_floor;
+            Enchantment::Thorns(thorns)
+        }
+        3 => {
+            let evasion = rng.gen_range(base_values.min_evasion..=base_values.max_evasion);
+            Enchantment::Evasion(evasion)
         }
         _ => Enchantment::Unknown,
     }
@@ -630,41 +1380,184 @@ pub fn random_ring_enchantment(
     base_values: &EnchantmentBaseValues,
     dungeon_floor: u32,
 ) -> Enchantment {
-    let mut rng = thread_rng();
-    let rand_num = rng.gen_range(0..=3);
+    let mut rng = game_rng();
+    let rand_num = rng.gen_range(0..=7);
     match rand_num {
         0 => {
             let mana = rng.gen_range(base_values.min_mana..=base_values.max_mana);
-            return Enchantment::Mana(mana);
+            Enchantment::Mana(mana)
         }
         1 => {
             let damage = rng.gen_range(base_values.min_damage..=base_values.max_damage)
                 + (2 * dungeon_floor);
-            return Enchantment::Damage(damage);
+            Enchantment::Damage(damage)
         }
         2 => {
             let health = rng.gen_range(base_values.min_health..=base_values.max_health)
                 + (3 * dungeon_floor);
-            return Enchantment::Health(health);
+            Enchantment::Health(health)
         }
         3 => {
             let crit_hit_rate =
                 rng.gen_range(base_values.min_crit_hit_rate..=base_values.max_crit_hit_rate);
-            return Enchantment::CritHitRate(crit_hit_rate);
+            Enchantment::CritHitRate(crit_hit_rate)
+        }
+        4 => {
+            let mana_regen = rng.gen_range(base_values.min_mana_regen..=base_values.max_mana_regen);
+            Enchantment::ManaRegen(mana_regen)
+        }
+        5 => {
+            let exp_bonus = rng.gen_range(base_values.min_exp_bonus..=base_values.max_exp_bonus);
+            Enchantment::ExpBonus(exp_bonus)
+        }
+        6 => {
+            let gold_find = rng.gen_range(base_values.min_gold_find..=base_values.max_gold_find);
+            Enchantment::GoldFind(gold_find)
+        }
+        7 => {
+            let magic_find = rng.gen_range(base_values.min_magic_find..=base_values.max_magic_find);
+            Enchantment::MagicFind(magic_find)
         }
         _ => Enchantment::Unknown,
     }
 }
 
+/// Returns the message ID of the name prefix given by an enchantment.
+pub fn get_enchantment_prefix(enchantment: &Enchantment) -> &'static str {
+    match enchantment {
+        Enchantment::Damage(_) => "item.prefix.vicious",
+        Enchantment::CritHitRate(_) => "item.prefix.keen",
+        Enchantment::Health(_) => "item.prefix.sturdy",
+        Enchantment::Defense(_) => "item.prefix.reinforced",
+        Enchantment::Mana(_) => "item.prefix.arcane",
+        Enchantment::Lifesteal(_) => "item.prefix.vampiric",
+        Enchantment::Thorns(_) => "item.prefix.spiked",
+        Enchantment::Evasion(_) => "item.prefix.nimble",
+        Enchantment::ManaRegen(_) => "item.prefix.mystic",
+        Enchantment::CritDamageMultiplier(_) => "item.prefix.brutal",
+        Enchantment::ExpBonus(_) => "item.prefix.wise",
+        Enchantment::GoldFind(_) => "item.prefix.gilded",
+        Enchantment::MagicFind(_) => "item.prefix.lucky",
+        _ => "",
+    }
+}
+
+/// Returns the message ID of the name suffix given by an enchantment.
+pub fn get_enchantment_suffix(enchantment: &Enchantment) -> &'static str {
+    match enchantment {
+        Enchantment::Damage(_) => "item.suffix.wolf",
+        Enchantment::CritHitRate(_) => "item.suffix.hawk",
+        Enchantment::Health(_) => "item.suffix.bear",
+        Enchantment::Defense(_) => "item.suffix.turtle",
+        Enchantment::Mana(_) => "item.suffix.owl",
+        Enchantment::Lifesteal(_) => "item.suffix.leech",
+        Enchantment::Thorns(_) => "item.suffix.porcupine",
+        Enchantment::Evasion(_) => "item.suffix.fox",
+        Enchantment::ManaRegen(_) => "item.suffix.renewal",
+        Enchantment::CritDamageMultiplier(_) => "item.suffix.ruin",
+        Enchantment::ExpBonus(_) => "item.suffix.wisdom",
+        Enchantment::GoldFind(_) => "item.suffix.greed",
+        Enchantment::MagicFind(_) => "item.suffix.fortune",
+        _ => "",
+    }
+}
+
+/// Returns the message ID of the name prefix given by the quality of a stat roll.
+/// Quality is between 0.0 (lowest roll) and 1.0 (highest roll).
+pub fn get_stat_quality_prefix(quality: f64) -> Option<&'static str> {
+    if quality >= 0.75 {
+        Some("item.prefix.fine")
+    } else if quality <= 0.25 {
+        Some("item.prefix.crude")
+    } else {
+        None
+    }
+}
+
+/// Returns the quality of a stat roll between 0.0 and 1.0.
+fn get_stat_roll_quality(value: f64, min: f64, max: f64) -> f64 {
+    if max <= min {
+        return 1.0;
+    }
+    (value - min) / (max - min)
+}
+
+/// Generates an item name based on the dominant enchantments of the item.
+/// The most common enchantment gives the prefix and the second most common gives the suffix.
+/// Items without enchantments get a prefix based on the quality of their main stat.
+pub fn generate_item_name(enchantments: &[Enchantment], stat_quality: f64) -> ItemName {
+    let mut counts: Vec<(&Enchantment, u32)> = Vec::new();
+    for enchantment in enchantments {
+        match counts
+            .iter_mut()
+            .find(|(counted, _)| mem::discriminant(*counted) == mem::discriminant(enchantment))
+        {
+            Some((_, count)) => *count += 1,
+            None => counts.push((enchantment, 1)),
+        }
+    }
+    // Stable sort keeps the first rolled enchantment first on ties.
+    counts.sort_by_key(|(_, count)| Reverse(*count));
+
+    let prefix = match counts.first() {
+        Some((enchantment, _)) => Some(get_enchantment_prefix(enchantment)),
+        None => get_stat_quality_prefix(stat_quality),
+    };
+    let suffix = counts
+        .get(1)
+        .map(|(enchantment, _)| get_enchantment_suffix(enchantment));
+
+    ItemName::Affixes {
+        prefix: prefix
+            .filter(|prefix| !prefix.is_empty())
+            .map(str::to_string),
+        suffix: suffix
+            .filter(|suffix| !suffix.is_empty())
+            .map(str::to_string),
+    }
+}
+
+/// Returns a random unique item if the item is legendary and the unique item chance hits.
+pub fn random_unique_item<'a>(
+    rarity: &ItemRarity,
+    unique_items: &'a [UniqueItem],
+) -> Option<&'a UniqueItem> {
+    let mut rng = game_rng();
+    match rarity {
+        ItemRarity::Legendary if rng.gen_bool(UNIQUE_ITEM_CHANCE) => {
+            unique_items.get(rng.gen_range(0..unique_items.len()))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the generated name and lore message ID of an item.
+fn generate_item_name_and_lore(
+    rarity: &ItemRarity,
+    enchantments: &[Enchantment],
+    stat_quality: f64,
+    unique_items: &[UniqueItem],
+) -> (ItemName, Option<String>) {
+    match random_unique_item(rarity, unique_items) {
+        Some(unique) => (
+            ItemName::Unique {
+                unique: unique.name.to_string(),
+            },
+            Some(unique.lore.to_string()),
+        ),
+        None => (generate_item_name(enchantments, stat_quality), None),
+    }
+}
+
 pub fn generate_random_weapon(
     rarity: ItemRarity,
     base_values: WeaponBaseValues,
     dungeon_floor: u32,
     character_class: &CharacterClass,
 ) -> WeaponItem {
-    let mut rng = thread_rng();
-    let damage =
-        rng.gen_range(base_values.min_damage..=base_values.max_damage) + (3 * dungeon_floor);
+    let mut rng = game_rng();
+    let damage_roll = rng.gen_range(base_values.min_damage..=base_values.max_damage);
+    let damage = damage_roll + (3 * dungeon_floor);
     let crit_hit_rate =
         rng.gen_range(base_values.min_crit_hit_rate..=base_values.max_crit_hit_rate);
     let enchantments = generate_item_enchantments(
@@ -680,8 +1573,18 @@ pub fn generate_random_weapon(
         CharacterClass::Warrior => ITEM_AXE,
         CharacterClass::Knight => ITEM_SWORD,
     };
+    let (name, lore) = generate_item_name_and_lore(
+        &rarity,
+        &enchantments,
+        get_stat_roll_quality(
+            damage_roll as f64,
+            base_values.min_damage as f64,
+            base_values.max_damage as f64,
+        ),
+        &UNIQUE_WEAPONS,
+    );
 
-    WeaponItem::new(
+    let mut weapon = WeaponItem::new(
         item_info,
         dungeon_floor,
         rarity,
@@ -690,7 +1593,10 @@ pub fn generate_random_weapon(
             crit_hit_rate,
         },
         enchantments,
-    )
+    );
+    weapon.name = Some(name);
+    weapon.lore = lore;
+    weapon
 }
 
 pub fn generate_random_armor(
@@ -698,9 +1604,9 @@ pub fn generate_random_armor(
     base_values: ArmorBaseValues,
     dungeon_floor: u32,
 ) -> ArmorItem {
-    let mut rng = thread_rng();
-    let health =
-        rng.gen_range(base_values.min_health..=base_values.max_health) + (8 * dungeon_floor);
+    let mut rng = game_rng();
+    let health_roll = rng.gen_range(base_values.min_health..=base_values.max_health);
+    let health = health_roll + (8 * dungeon_floor);
     let defense =
         rng.gen_range(base_values.min_defense..=base_values.max_defense) + (2 * dungeon_floor);
     let enchantments = generate_item_enchantments(
@@ -709,14 +1615,27 @@ pub fn generate_random_armor(
         &ENCHANTMENT_BASE_VALUES,
         dungeon_floor,
     );
+    let (name, lore) = generate_item_name_and_lore(
+        &rarity,
+        &enchantments,
+        get_stat_roll_quality(
+            health_roll as f64,
+            base_values.min_health as f64,
+            base_values.max_health as f64,
+        ),
+        &UNIQUE_ARMORS,
+    );
 
-    ArmorItem::new(
+    let mut armor = ArmorItem::new(
         ITEM_ARMOR,
         dungeon_floor,
         rarity,
         ArmorItemStats { health, defense },
         enchantments,
-    )
+    );
+    armor.name = Some(name);
+    armor.lore = lore;
+    armor
 }
 
 pub fn generate_random_ring(
@@ -724,7 +1643,7 @@ pub fn generate_random_ring(
     base_values: RingBaseValues,
     dungeon_floor: u32,
 ) -> RingItem {
-    let mut rng = thread_rng();
+    let mut rng = game_rng();
     let mana = rng.gen_range(base_values.min_mana..=base_values.max_mana);
     let enchantments = generate_item_enchantments(
         num_enchantments(&rarity),
@@ -732,20 +1651,64 @@ pub fn generate_random_ring(
         &ENCHANTMENT_BASE_VALUES,
         dungeon_floor,
     );
+    let (name, lore) = generate_item_name_and_lore(
+        &rarity,
+        &enchantments,
+        get_stat_roll_quality(
+            mana as f64,
+            base_values.min_mana as f64,
+            base_values.max_mana as f64,
+        ),
+        &UNIQUE_RINGS,
+    );
 
-    RingItem::new(
+    let mut ring = RingItem::new(
         ITEM_RING,
         dungeon_floor,
         rarity,
         RingItemStats { mana },
         enchantments,
-    )
+    );
+    ring.name = Some(name);
+    ring.lore = lore;
+    ring
+}
+
+pub fn generate_set_weapon(
+    set: ItemSet,
+    dungeon_floor: u32,
+    character_class: &CharacterClass,
+) -> WeaponItem {
+    let mut weapon = generate_random_weapon(
+        SET_ITEM_RARITY,
+        WEAPON_BASE_VALUES,
+        dungeon_floor,
+        character_class,
+    );
+    weapon.set = Some(set);
+    // The set name is displayed instead of affixes.
+    weapon.name = None;
+    weapon
+}
+
+pub fn generate_set_armor(set: ItemSet, dungeon_floor: u32) -> ArmorItem {
+    let mut armor = generate_random_armor(SET_ITEM_RARITY, ARMOR_BASE_VALUES, dungeon_floor);
+    armor.set = Some(set);
+    armor.name = None;
+    armor
+}
+
+pub fn generate_set_ring(set: ItemSet, dungeon_floor: u32) -> RingItem {
+    let mut ring = generate_random_ring(SET_ITEM_RARITY, RING_BASE_VALUES, dungeon_floor);
+    ring.set = Some(set);
+    ring.name = None;
+    ring
 }
 
 pub fn generate_random_consumable() -> ConsumableItem {
-    let mut rng = thread_rng();
+    let mut rng = game_rng();
     let num = rng.gen_range(0..2);
-    let rarity = random_item_rarity(&ITEM_RARITY_DROP_RATES);
+    let rarity = random_item_rarity(&ITEM_RARITY_DROP_RATES, 0.0);
 
     match num {
         0 => ConsumableItem::new_health_potion(rarity),
@@ -753,3 +1716,55 @@ pub fn generate_random_consumable() -> ConsumableItem {
         _ => ConsumableItem::new_health_potion(rarity),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::{set_locale, Locale};
+
+    #[test]
+    fn generated_name_follows_current_locale() {
+        set_locale(Locale::English);
+        let mut weapon = WeaponItem::new(
+            ITEM_SWORD,
+            1,
+            ItemRarity::Rare,
+            WeaponItemStats {
+                damage: 10,
+                crit_hit_rate: 0.05,
+            },
+            vec![Enchantment::Damage(5)],
+        );
+        weapon.name = Some(generate_item_name(&weapon.enchantments, 0.5));
+        assert_eq!(weapon.get_name(), "Vicious Sword");
+        assert!(get_item_display_name(CharacterItem::Weapon(&weapon)).contains("Vicious Sword"));
+
+        set_locale(Locale::Finnish);
+        assert_eq!(weapon.get_name(), "Julma Miekka");
+        weapon.name = Some(ItemName::Unique {
+            unique: UNIQUE_WEAPONS[0].name.to_string(),
+        });
+        assert_eq!(weapon.get_name(), "Aamunkoitto");
+        set_locale(Locale::English);
+    }
+
+    #[test]
+    fn large_magic_find_keeps_drop_rates_valid() {
+        let drop_rates = apply_magic_find(&ITEM_RARITY_DROP_RATES, 100.0);
+        let total = drop_rates.common
+            + drop_rates.uncommon
+            + drop_rates.rare
+            + drop_rates.epic
+            + drop_rates.legendary;
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!(drop_rates.common < 1e-9);
+        // rarer items stay rarer
+        assert!(drop_rates.uncommon > drop_rates.rare);
+        assert!(drop_rates.epic > drop_rates.legendary);
+
+        for _ in 0..1000 {
+            let rarity = random_item_rarity(&ITEM_RARITY_DROP_RATES, 100.0);
+            assert!(!matches!(rarity, ItemRarity::Common | ItemRarity::Unknown));
+        }
+    }
+}
@@ -0,0 +1,355 @@
+use crossterm::event::KeyCode;
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::BTreeMap, fs, io, path::PathBuf};
+
+use crate::{
+    game_data::{get_config_subdir, SUBDIR_NAME},
+    tr,
+};
+
+const KEYMAP_FILE_NAME: &str = "keymap.json";
+
+thread_local! {
+    static KEYMAP: RefCell<Keymap> = RefCell::new(Keymap::from_preset(KeymapPreset::Default));
+}
+
+/// Action in menus that can be bound to keys.
+/// The same key can be bound to actions that are used in different menus.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyAction {
+    Up,
+    Down,
+    Left,
+    Right,
+    Select,
+    Back,
+    Use,
+    Equip,
+    Unequip,
+    Sell,
+    Delete,
+    Compare,
+    Lock,
+    Sort,
+    RarityFilter,
+    EquippedFilter,
+    Search,
+    Upgrade,
+    Reroll,
+    RaiseRarity,
+    Salvage,
+    Buy,
+    Trade,
+    Brew,
+    Abandon,
+    ScrollUp,
+    ScrollDown,
+    /// Pauses and resumes replay playback.
+    Pause,
+    /// Replays the next input while paused.
+    Step,
+    SpeedUp,
+    SpeedDown,
+    /// Stops the replay and continues playing live.
+    Stop,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum KeymapPreset {
+    /// Arrow keys and letter shortcuts.
+    #[default]
+    Default,
+    /// Default bindings with h, j, k and l for moving.
+    Vim,
+    /// Default bindings with w, a, s and d for moving.
+    Wasd,
+}
+
+/// Key bindings file in the config directory.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct KeymapFile {
+    pub preset: KeymapPreset,
+    /// Keys that replace the preset's keys of an action.
+    pub bindings: BTreeMap<KeyAction, Vec<String>>,
+}
+
+impl KeymapFile {
+    /// Builds the keymap from the preset and the bindings.
+    /// Fails if a key name is invalid.
+    pub fn to_keymap(&self) -> io::Result<Keymap> {
+        let mut keymap = Keymap::from_preset(self.preset);
+        for (action, names) in &self.bindings {
+            let mut keys = Vec::new();
+            for name in names {
+                keys.push(parse_key(name).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("invalid key \"{}\" in {}", name, KEYMAP_FILE_NAME),
+                    )
+                })?);
+            }
+            keymap.bindings.insert(*action, keys);
+        }
+        Ok(keymap)
+    }
+}
+
+/// Keys bound to each action.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: BTreeMap<KeyAction, Vec<KeyCode>>,
+}
+
+impl Keymap {
+    pub fn from_preset(preset: KeymapPreset) -> Self {
+        let mut bindings = BTreeMap::new();
+        bindings.insert(KeyAction::Up, vec![KeyCode::Up]);
+        bindings.insert(KeyAction::Down, vec![KeyCode::Down]);
+        bindings.insert(KeyAction::Left, vec![KeyCode::Left]);
+        bindings.insert(KeyAction::Right, vec![KeyCode::Right]);
+        bindings.insert(KeyAction::Select, vec![KeyCode::Enter]);
+        bindings.insert(KeyAction::Back, vec![KeyCode::Esc]);
+        bindings.insert(KeyAction::ScrollUp, vec![KeyCode::PageUp]);
+        bindings.insert(KeyAction::ScrollDown, vec![KeyCode::PageDown]);
+        for (action, c) in [
+            (KeyAction::Use, 'u'),
+            (KeyAction::Equip, 'e'),
+            (KeyAction::Unequip, 'u'),
+            (KeyAction::Sell, 's'),
+            (KeyAction::Delete, 'd'),
+            (KeyAction::Compare, 'c'),
+            (KeyAction::Lock, 'l'),
+            (KeyAction::Sort, 'o'),
+            (KeyAction::RarityFilter, 'r'),
+            (KeyAction::EquippedFilter, 'f'),
+            (KeyAction::Upgrade, 'u'),
+            (KeyAction::Reroll, 'r'),
+            (KeyAction::RaiseRarity, 'r'),
+            (KeyAction::Salvage, 's'),
+            (KeyAction::Buy, 'b'),
+            (KeyAction::Trade, 't'),
+            (KeyAction::Brew, 'b'),
+            (KeyAction::Abandon, 'a'),
+            (KeyAction::Step, 'n'),
+            (KeyAction::Stop, 'q'),
+        ] {
+            bindings.insert(
+                action,
+                vec![KeyCode::Char(c.to_ascii_uppercase()), KeyCode::Char(c)],
+            );
+        }
+        bindings.insert(KeyAction::Search, vec![KeyCode::Char('/')]);
+        bindings.insert(KeyAction::Pause, vec![KeyCode::Char(' ')]);
+        bindings.insert(KeyAction::SpeedUp, vec![KeyCode::Char('+')]);
+        bindings.insert(KeyAction::SpeedDown, vec![KeyCode::Char('-')]);
+
+        // Letters used for moving are left only in upper case for the other actions.
+        let movement = match preset {
+            KeymapPreset::Default => vec![],
+            KeymapPreset::Vim => vec![
+                (KeyAction::Up, 'k'),
+                (KeyAction::Down, 'j'),
+                (KeyAction::Left, 'h'),
+                (KeyAction::Right, 'l'),
+            ],
+            KeymapPreset::Wasd => vec![
+                (KeyAction::Up, 'w'),
+                (KeyAction::Down, 's'),
+                (KeyAction::Left, 'a'),
+                (KeyAction::Right, 'd'),
+            ],
+        };
+        for (action, c) in movement {
+            for keys in bindings.values_mut() {
+                keys.retain(|key| *key != KeyCode::Char(c));
+            }
+            if let Some(keys) = bindings.get_mut(&action) {
+                keys.push(KeyCode::Char(c));
+            }
+        }
+
+        Self { bindings }
+    }
+
+    pub fn get_keys(&self, action: KeyAction) -> &[KeyCode] {
+        self.bindings
+            .get(&action)
+            .map_or(&[], |keys| keys.as_slice())
+    }
+
+    pub fn is_bound(&self, code: KeyCode, action: KeyAction) -> bool {
+        self.get_keys(action).contains(&code)
+    }
+
+    /// Name of the action's first key shown in key hints.
+    /// Upper case letters without the lower case letter are shown as `Shift+<letter>`.
+    pub fn get_key_display(&self, action: KeyAction) -> String {
+        let keys = self.get_keys(action);
+        match keys.first() {
+            Some(KeyCode::Char(c)) if c.is_ascii_uppercase() => {
+                if keys.contains(&KeyCode::Char(c.to_ascii_lowercase())) {
+                    c.to_string()
+                } else {
+                    format!("Shift+{}", c)
+                }
+            }
+            Some(key) => get_key_name(*key),
+            None => "Unbound".to_string(),
+        }
+    }
+}
+
+/// Sets the keymap used by menus.
+pub fn set_keymap(keymap: Keymap) {
+    KEYMAP.with(|current| *current.borrow_mut() = keymap);
+}
+
+/// Returns true if the key is bound to the action in the current keymap.
+pub fn is_key_action(code: KeyCode, action: KeyAction) -> bool {
+    KEYMAP.with(|keymap| keymap.borrow().is_bound(code, action))
+}
+
+/// Name of the key bound to the action in the current keymap.
+pub fn get_key_display(action: KeyAction) -> String {
+    KEYMAP.with(|keymap| keymap.borrow().get_key_display(action))
+}
+
+/// Hints of the actions with the bound keys, e.g. `(S) Sell, (Esc) Back`.
+/// The hints are message IDs.
+pub fn format_key_hints(hints: &[(KeyAction, &str)]) -> String {
+    hints
+        .iter()
+        .map(|(action, id)| format!("({}) {}", get_key_display(*action), tr!(id)))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Returns the list index selected with the number keys 1-9.
+pub fn get_number_key_index(code: KeyCode) -> Option<usize> {
+    match code {
+        KeyCode::Char(c @ '1'..='9') => Some(c as usize - '1' as usize),
+        _ => None,
+    }
+}
+
+/// Parses a key name used in the key bindings file.
+/// Number keys 1-9 can't be bound because they select list items.
+pub fn parse_key(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return match c {
+            '1'..='9' => None,
+            c => Some(KeyCode::Char(c)),
+        };
+    }
+    match name.to_lowercase().as_str() {
+        "up" => Some(KeyCode::Up),
+        "down" => Some(KeyCode::Down),
+        "left" => Some(KeyCode::Left),
+        "right" => Some(KeyCode::Right),
+        "enter" => Some(KeyCode::Enter),
+        "esc" => Some(KeyCode::Esc),
+        "backspace" => Some(KeyCode::Backspace),
+        "pageup" => Some(KeyCode::PageUp),
+        "pagedown" => Some(KeyCode::PageDown),
+        "space" => Some(KeyCode::Char(' ')),
+        _ => None,
+    }
+}
+
+pub fn get_key_name(code: KeyCode) -> String {
+    match code {
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Left => "<-".to_string(),
+        KeyCode::Right => "->".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::PageUp => "PageUp".to_string(),
+        KeyCode::PageDown => "PageDown".to_string(),
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        code => format!("{:?}", code),
+    }
+}
+
+pub fn get_keymap_file_path() -> io::Result<PathBuf> {
+    Ok(get_config_subdir(SUBDIR_NAME)?.join(KEYMAP_FILE_NAME))
+}
+
+/// Loads the key bindings file from the config directory.
+/// Returns the default bindings if the file doesn't exist.
+pub fn load_keymap_file() -> io::Result<KeymapFile> {
+    let path = get_keymap_file_path()?;
+    if !path.try_exists()? {
+        return Ok(KeymapFile::default());
+    }
+    let keymap_file = serde_json::from_str(&fs::read_to_string(path)?)?;
+    Ok(keymap_file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_file(json: &str) -> io::Result<Keymap> {
+        serde_json::from_str::<KeymapFile>(json)?.to_keymap()
+    }
+
+    #[test]
+    fn parse_key_names() {
+        assert_eq!(parse_key("x"), Some(KeyCode::Char('x')));
+        assert_eq!(parse_key("X"), Some(KeyCode::Char('X')));
+        assert_eq!(parse_key("0"), Some(KeyCode::Char('0')));
+        assert_eq!(parse_key("BackSpace"), Some(KeyCode::Backspace));
+        assert_eq!(parse_key("space"), Some(KeyCode::Char(' ')));
+        assert_eq!(parse_key("tab"), None);
+        assert_eq!(parse_key(""), None);
+    }
+
+    #[test]
+    fn number_keys_cannot_be_bound() {
+        for c in '1'..='9' {
+            assert_eq!(parse_key(&c.to_string()), None);
+        }
+        let err = parse_file(r#"{"bindings": {"sell": ["5"]}}"#).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn presets() {
+        let keymap = parse_file(r#"{"preset": "vim"}"#).unwrap();
+        assert!(keymap.is_bound(KeyCode::Char('k'), KeyAction::Up));
+        assert!(keymap.is_bound(KeyCode::Up, KeyAction::Up));
+        // movement keys are removed from other actions
+        assert!(!keymap.is_bound(KeyCode::Char('l'), KeyAction::Lock));
+        assert!(keymap.is_bound(KeyCode::Char('L'), KeyAction::Lock));
+
+        let keymap = parse_file(r#"{"preset": "wasd"}"#).unwrap();
+        assert!(keymap.is_bound(KeyCode::Char('s'), KeyAction::Down));
+        assert!(!keymap.is_bound(KeyCode::Char('s'), KeyAction::Sell));
+
+        let keymap = parse_file("{}").unwrap();
+        assert!(keymap.is_bound(KeyCode::Char('s'), KeyAction::Sell));
+        assert!(keymap.is_bound(KeyCode::Char('S'), KeyAction::Sell));
+        assert_eq!(keymap.get_key_display(KeyAction::Back), "Esc");
+    }
+
+    #[test]
+    fn bindings_replace_preset_keys() {
+        let keymap =
+            parse_file(r#"{"preset": "vim", "bindings": {"sell": ["x", "Enter"], "back": ["Q"]}}"#)
+                .unwrap();
+        assert_eq!(
+            keymap.get_keys(KeyAction::Sell),
+            [KeyCode::Char('x'), KeyCode::Enter]
+        );
+        assert!(keymap.is_bound(KeyCode::Char('k'), KeyAction::Up));
+        assert!(!keymap.is_bound(KeyCode::Esc, KeyAction::Back));
+        assert_eq!(keymap.get_key_display(KeyAction::Back), "Shift+Q");
+    }
+}
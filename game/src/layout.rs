@@ -0,0 +1,124 @@
+//! Screen size checks and text fitting.
+//! Lines longer than the terminal are cut at the right edge, so texts that
+//! need to be read in full are wrapped with the terminal width.
+
+use crossterm::event::Event;
+use std::io;
+
+use crate::{
+    frontend::{clear, flush, move_to, read_frontend_event, size},
+    print_line, tr,
+};
+
+/// Smallest terminal size all screens fit in.
+pub const MIN_TERMINAL_WIDTH: u16 = 60;
+pub const MIN_TERMINAL_HEIGHT: u16 = 20;
+
+pub fn is_terminal_too_small() -> io::Result<bool> {
+    let (width, height) = size()?;
+    Ok(width < MIN_TERMINAL_WIDTH || height < MIN_TERMINAL_HEIGHT)
+}
+
+/// Shows a notice until the terminal is resized to at least the minimum size.
+/// Returns true if the terminal was too small. The screen is cleared after so it can be drawn again.
+pub fn wait_for_terminal_size() -> io::Result<bool> {
+    if !is_terminal_too_small()? {
+        return Ok(false);
+    }
+    loop {
+        let (width, height) = size()?;
+        if width >= MIN_TERMINAL_WIDTH && height >= MIN_TERMINAL_HEIGHT {
+            break;
+        }
+        clear()?;
+        for (i, line) in [
+            tr!("layout.terminal_too_small"),
+            tr!("layout.current_size", width = width, height = height),
+            tr!(
+                "layout.minimum_size",
+                width = MIN_TERMINAL_WIDTH,
+                height = MIN_TERMINAL_HEIGHT
+            ),
+        ]
+        .iter()
+        .enumerate()
+        {
+            move_to(0, i as u16)?;
+            print_line!("{}", truncate_text(line, width as usize))?;
+        }
+        flush()?;
+        // wait for a resize, other input is ignored
+        while !matches!(read_frontend_event()?, Event::Resize(..)) {}
+    }
+    clear()?;
+    Ok(true)
+}
+
+/// Width available for text on a row.
+pub fn get_text_width() -> io::Result<usize> {
+    Ok(size()?.0 as usize)
+}
+
+/// Number of rows left on the screen starting from the row.
+pub fn get_rows_left(row: u16) -> io::Result<usize> {
+    Ok(size()?.1.saturating_sub(row) as usize)
+}
+
+/// Cuts the text to the width. Cut texts end with `...`.
+pub fn truncate_text(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    if width <= 3 {
+        return text.chars().take(width).collect();
+    }
+    let mut truncated: String = text.chars().take(width - 3).collect();
+    truncated.push_str("...");
+    truncated
+}
+
+/// Splits the text into lines that fit the width.
+/// Lines are split between words. Words longer than the width are split.
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let mut word = word.to_string();
+            while word.chars().count() > width {
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                let rest = word.chars().skip(width).collect();
+                lines.push(word.chars().take(width).collect());
+                word = rest;
+            }
+            let line_len = line.chars().count();
+            if line_len > 0 && line_len + 1 + word.chars().count() > width {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&word);
+        }
+        lines.push(line);
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+/// Prints the text wrapped to the terminal width starting from the row.
+/// `indent` is printed before every line. Returns the number of rows used.
+pub fn print_wrapped(row: u16, indent: &str, text: &str) -> io::Result<u16> {
+    let width = get_text_width()?.saturating_sub(indent.chars().count());
+    let lines = wrap_text(text, width);
+    for (i, line) in lines.iter().enumerate() {
+        move_to(0, row + i as u16)?;
+        print_line!("{}{}", indent, line)?;
+    }
+    Ok(lines.len() as u16)
+}
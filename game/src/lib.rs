@@ -1,16 +1,38 @@
+pub mod alchemy;
+pub mod arena;
+pub mod art;
+pub mod blacksmith;
+pub mod bot;
 pub mod character;
+pub mod combat_log;
+pub mod companion;
+pub mod comparison;
 pub mod config;
 pub mod currency;
+pub mod daily;
 pub mod drops;
 pub mod dungeon;
 pub mod enemy;
 pub mod error;
 pub mod fight;
+pub mod frontend;
 pub mod game;
 pub mod game_data;
+pub mod i18n;
+pub mod inventory;
 pub mod items;
+pub mod keymap;
+pub mod layout;
 pub mod menu;
+pub mod quest;
+pub mod replay;
+pub mod rng;
 pub mod session;
 pub mod shop;
+pub mod stash;
+pub mod theme;
 pub mod util;
 pub mod validation;
+
+#[cfg(test)]
+mod test_util;
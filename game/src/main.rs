@@ -1,51 +1,75 @@
-use crossterm::{
-    cursor::{Hide, Show},
-    execute,
-    style::{Color, SetBackgroundColor},
-    terminal::{
-        disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen,
-        LeaveAlternateScreen,
-    },
-};
 use std::io;
 use terminal_rpg::{
+    bot::run_bot,
+    combat_log::set_combat_log_file_enabled,
     config::GameConfig,
+    frontend::{clear, start_frontend, stop_frontend},
     game_data::{create_savefile_if_not_exists, load_save_file},
+    i18n::{get_env_locale, set_locale},
+    keymap::{load_keymap_file, set_keymap},
     menu::main_menu::main_menu,
+    replay::{start_recording, start_replay},
     session::Player,
-    util::reset_background_color,
+    theme::{load_theme, set_theme},
 };
 
 fn main() -> io::Result<()> {
     if let Err(e) = run() {
-        let mut stdout = io::stdout();
-        reset_background_color()?;
-        execute!(stdout, LeaveAlternateScreen, Show, Clear(ClearType::All))?;
+        stop_frontend()?;
+        clear()?;
         eprintln!("Error: {}", e);
     }
     Ok(())
 }
 
 fn run() -> io::Result<()> {
-    let cfg = GameConfig::new();
-    create_savefile_if_not_exists()?;
-    let game_data = match load_save_file() {
-        Ok(game_data) => game_data,
-        Err(e) => {
-            eprintln!("Failed to load save file, it may be corrupted");
-            return Err(e);
+    let mut cfg = GameConfig::new()?;
+    if cfg.bot_mode {
+        // bots don't use the save file or the terminal UI
+        return run_bot(io::stdin().lock(), &mut io::stdout());
+    }
+    let (game_data, keymap_file) = match &cfg.replay_file {
+        Some(path) => {
+            // replays start from the game data and key bindings saved in the replay file
+            let header = start_replay(path)?;
+            cfg.dev_mode = header.dev_mode;
+            (header.game_data, header.keymap)
+        }
+        None => {
+            create_savefile_if_not_exists()?;
+            let game_data = match load_save_file() {
+                Ok(game_data) => game_data,
+                Err(e) => {
+                    eprintln!("Failed to load save file, it may be corrupted");
+                    return Err(e);
+                }
+            };
+            let keymap_file = match load_keymap_file() {
+                Ok(keymap_file) => keymap_file,
+                Err(e) => {
+                    eprintln!("Failed to load key bindings file");
+                    return Err(e);
+                }
+            };
+            (game_data, keymap_file)
         }
     };
+    set_keymap(keymap_file.to_keymap()?);
+    set_locale(cfg.locale.or_else(get_env_locale).unwrap_or_default());
+    set_combat_log_file_enabled(cfg.combat_log_file);
+    if let Some(path) = &cfg.record_file {
+        start_recording(path, &game_data, &keymap_file, cfg.dev_mode)?;
+    }
     let mut player = Player::new(game_data);
 
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(
-        stdout,
-        EnterAlternateScreen,
-        Hide,
-        SetBackgroundColor(Color::Black)
-    )?;
+    match load_theme() {
+        Ok(theme) => set_theme(theme),
+        Err(e) => {
+            eprintln!("Failed to load theme file");
+            return Err(e);
+        }
+    }
+    start_frontend()?;
 
     loop {
         if let Ok(rerender) = main_menu(&mut player, &cfg) {
@@ -57,8 +81,7 @@ fn run() -> io::Result<()> {
         }
     }
 
-    execute!(stdout, LeaveAlternateScreen, Show)?;
-    disable_raw_mode()?;
+    stop_frontend()?;
 
     Ok(())
 }
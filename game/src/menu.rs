@@ -1,9 +1,18 @@
+pub mod alchemy;
+pub mod arena;
+pub mod blacksmith;
 pub mod character;
+pub mod companion;
+pub mod comparison;
+pub mod daily;
 pub mod dungeon;
 pub mod enemy;
 pub mod equipment;
 pub mod inventory;
 pub mod main_menu;
+pub mod quest;
 pub mod shop;
 pub mod skill;
+pub mod stash;
 pub mod stats;
+pub mod widget;
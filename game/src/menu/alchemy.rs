@@ -0,0 +1,125 @@
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
+use std::io;
+
+use crate::{
+    alchemy::{Ingredient, RECIPES},
+    frontend::{clear, move_to},
+    items::{get_item_display_name, CharacterItem, ItemRarity},
+    keymap::{is_key_action, KeyAction},
+    menu::widget::{draw_key_hints, SelectList},
+    print_line, print_text,
+    replay::read_event,
+    session::PlayerCharacter,
+    tr,
+    util::{print_rarity_text, reset_text_color},
+};
+
+pub fn menu_alchemy(character: &mut PlayerCharacter) -> io::Result<()> {
+    let mut list = SelectList::new();
+    let start_column: u16 = 2;
+    let mut brewed_item = "".to_string();
+    let mut brewed_item_rarity = ItemRarity::Unknown;
+
+    clear()?;
+    loop {
+        draw_key_hints(
+            0,
+            &[
+                (KeyAction::Back, "hint.back"),
+                (KeyAction::Brew, "hint.brew"),
+            ],
+        )?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("alchemy.title"))?;
+
+        for (i, recipe) in RECIPES.iter().enumerate() {
+            move_to(0, i as u16 + start_column)?;
+            let ingredients = recipe
+                .ingredients
+                .iter()
+                .map(|(ingredient, amount)| {
+                    format!(
+                        "{} {}/{}",
+                        ingredient,
+                        character.get_ingredient_amount(ingredient),
+                        amount
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(", ");
+            if i == list.selected_index {
+                print_text!("> {}", tr!(recipe.name))?;
+            } else {
+                print_text!("  {}", tr!(recipe.name))?;
+            }
+            if recipe.can_brew(character) {
+                print_text!("   [{}] [{}]", ingredients, tr!("alchemy.can_brew"))?;
+            } else {
+                print_text!("   [{}]", ingredients)?;
+            }
+        }
+
+        let mut column = start_column + RECIPES.len() as u16 + 1;
+        move_to(0, column)?;
+        print_line!("{}", tr!("alchemy.ingredients"))?;
+        column += 1;
+        let mut ingredients: Vec<(&Ingredient, &u32)> =
+            character.data.inventory.ingredients.iter().collect();
+        ingredients.sort_by_key(|(ingredient, _)| ingredient.to_string());
+        if ingredients.is_empty() {
+            move_to(0, column)?;
+            print_line!("  {}", tr!("alchemy.no_ingredients"))?;
+            column += 1;
+        }
+        for (ingredient, amount) in ingredients {
+            move_to(0, column)?;
+            print_line!("  {} x{}", ingredient, amount)?;
+            column += 1;
+        }
+
+        if !brewed_item.is_empty() {
+            move_to(0, column + 1)?;
+            print_text!("{}", tr!("alchemy.brewed_prefix"))?;
+            print_rarity_text(&brewed_item_rarity, &brewed_item)?;
+            reset_text_color()?;
+            print_text!("{}", tr!("alchemy.brewed_suffix"))?;
+        }
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, RECIPES.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    code if is_key_action(code, KeyAction::Brew) => {
+                        if let Some(item) = RECIPES[list.selected_index].brew(character) {
+                            brewed_item = get_item_display_name(CharacterItem::Consumable(&item));
+                            brewed_item_rarity = item.rarity;
+                            clear()?;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
+
+/// Displays dropped ingredients. Returns the next free column.
+pub fn display_ingredient_drops(
+    ingredients: &[(Ingredient, u32)],
+    start_column: u16,
+) -> io::Result<u16> {
+    let mut column = start_column;
+    for (ingredient, amount) in ingredients {
+        move_to(0, column)?;
+        print_line!("    {} x{}", ingredient, amount)?;
+        column += 1;
+    }
+
+    Ok(column)
+}
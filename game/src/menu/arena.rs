@@ -0,0 +1,256 @@
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
+use std::io;
+
+use crate::{
+    arena::{
+        create_arena_score, generate_arena_enemy, get_arena_score, is_boss_wave,
+        ARENA_BOSS_WAVE_INTERVAL, ARENA_FIGHT_OPTIONS,
+    },
+    frontend::{clear, move_to},
+    game::save_game,
+    game_data::{ArenaScore, ARENA_LEADERBOARD_SIZE},
+    i18n::translate_all,
+    keymap::{is_key_action, KeyAction},
+    menu::{
+        enemy::menu_enemy_encounter,
+        widget::{draw_key_hints, SelectList},
+    },
+    print_line,
+    replay::read_event,
+    session::{Player, PlayerCharacter},
+    tr,
+};
+
+pub fn menu_arena(player: &mut Player) -> io::Result<()> {
+    let menu_items = vec!["menu.arena.start", "menu.arena.leaderboard"];
+    let mut list = SelectList::new();
+    let start_column: u16 = 3;
+
+    clear()?;
+    loop {
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("arena.title"))?;
+        move_to(0, 2)?;
+        print_line!(
+            "{}",
+            tr!("arena.rules", interval = ARENA_BOSS_WAVE_INTERVAL)
+        )?;
+
+        list.draw(start_column, &translate_all(&menu_items))?;
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    code if list.is_select(code, menu_items.len()) => {
+                        match menu_items[list.selected_index] {
+                            "menu.arena.start" => menu_arena_run(player)?,
+                            "menu.arena.leaderboard" => {
+                                menu_arena_leaderboard(&player.data.arena_leaderboard)?
+                            }
+                            _ => break,
+                        }
+                        clear()?;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
+
+/// Fights waves until the player dies or leaves.
+/// The score is recorded to the leaderboard and the game is saved after the run.
+fn menu_arena_run(player: &mut Player) -> io::Result<()> {
+    let character = player.get_character_mut()?;
+    let dungeon_floor = character.data.stats.general_stats.current_dungeon_floor;
+    let mut waves_survived = 0;
+
+    character.restore_health(character.get_total_health());
+    character.restore_mana(character.get_total_mana());
+    if let Some(companion) = &mut character.data.companion {
+        companion.prepare_for_fight();
+    }
+
+    loop {
+        let wave = waves_survived + 1;
+        let mut enemy = generate_arena_enemy(wave, dungeon_floor);
+        let victory = menu_enemy_encounter(&mut enemy, character, &ARENA_FIGHT_OPTIONS)?;
+        if !victory {
+            break;
+        }
+        waves_survived = wave;
+        if !menu_arena_wave_cleared(wave, character)? {
+            break;
+        }
+    }
+    let defeated = character.is_dead();
+    character.restore_health(character.get_total_health());
+    character.restore_mana(character.get_total_mana());
+
+    let score = create_arena_score(character, waves_survived);
+    let position = player.data.record_arena_score(score);
+    save_game(player)?;
+    menu_arena_results(waves_survived, defeated, position)
+}
+
+/// Returns true if the player continues to the next wave.
+fn menu_arena_wave_cleared(wave: u32, character: &PlayerCharacter) -> io::Result<bool> {
+    let menu_items = vec!["menu.arena.next_wave", "menu.arena.leave"];
+    let mut list = SelectList::new();
+    let start_column: u16 = 5;
+
+    clear()?;
+    loop {
+        move_to(0, 0)?;
+        print_line!(
+            "{}",
+            tr!(
+                "arena.wave_cleared",
+                wave = wave,
+                score = get_arena_score(wave)
+            )
+        )?;
+        move_to(0, 1)?;
+        print_line!(
+            "{}, {}",
+            tr!(
+                "stats.health_current",
+                current = character.temp_stats.current_health,
+                max = character.get_total_health()
+            ),
+            tr!(
+                "stats.mana_current",
+                current = character.temp_stats.current_mana,
+                max = character.get_total_mana()
+            )
+        )?;
+        move_to(0, 2)?;
+        if is_boss_wave(wave + 1) {
+            print_line!("{}", tr!("arena.next_wave_boss"))?;
+        } else {
+            print_line!("{}", tr!("arena.next_wave_normal"))?;
+        }
+        move_to(0, 3)?;
+        print_line!("{}", tr!("arena.consumables_hint"))?;
+
+        list.draw(start_column, &translate_all(&menu_items))?;
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if list.is_select(code, menu_items.len()) => {
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(menu_items[list.selected_index] == "menu.arena.next_wave")
+}
+
+fn menu_arena_results(
+    waves_survived: u32,
+    defeated: bool,
+    leaderboard_position: Option<usize>,
+) -> io::Result<()> {
+    clear()?;
+
+    loop {
+        move_to(0, 0)?;
+        if defeated {
+            print_line!("{}", tr!("arena.defeated", wave = waves_survived + 1))?;
+        } else {
+            print_line!("{}", tr!("arena.left"))?;
+        }
+        move_to(0, 1)?;
+        print_line!("{}", tr!("arena.waves_survived", waves = waves_survived))?;
+        move_to(0, 2)?;
+        print_line!(
+            "{}",
+            tr!("daily.score", score = get_arena_score(waves_survived))
+        )?;
+        move_to(0, 3)?;
+        match leaderboard_position {
+            Some(position) => {
+                print_line!("{}", tr!("arena.leaderboard_entry", position = position))?
+            }
+            None => print_line!("{}", tr!("arena.not_on_leaderboard"))?,
+        }
+        move_to(0, 5)?;
+        print_line!("{}", tr!("level_up.continue"))?;
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if is_key_action(code, KeyAction::Select) => {
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
+
+fn menu_arena_leaderboard(leaderboard: &[ArenaScore]) -> io::Result<()> {
+    let start_column: u16 = 2;
+    clear()?;
+
+    loop {
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!(
+            "{}",
+            tr!("arena.leaderboard_title", size = ARENA_LEADERBOARD_SIZE)
+        )?;
+
+        if leaderboard.is_empty() {
+            move_to(0, start_column)?;
+            print_line!("  {}", tr!("arena.no_scores"))?;
+        }
+        for (i, entry) in leaderboard.iter().enumerate() {
+            move_to(0, i as u16 + start_column)?;
+            print_line!(
+                "  {}",
+                tr!(
+                    "arena.leaderboard_row",
+                    position = i + 1,
+                    name = entry.character_name,
+                    level = entry.character_level,
+                    class = entry.class,
+                    waves = entry.waves_survived,
+                    score = entry.score
+                )
+            )?;
+        }
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
@@ -0,0 +1,445 @@
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
+use std::io;
+
+use crate::{
+    blacksmith::{
+        get_item_salvage_value, get_raise_rarity_cost, get_reroll_cost, get_upgrade_cost,
+        raise_armor_rarity, raise_ring_rarity, raise_weapon_rarity, reroll_armor_enchantment,
+        reroll_ring_enchantment, reroll_weapon_enchantment, salvage_armor, salvage_ring,
+        salvage_weapon, upgrade_armor, upgrade_ring, upgrade_weapon, BlacksmithCost,
+    },
+    frontend::{clear, move_to},
+    i18n::translate_all,
+    items::{
+        get_enchantment_display, get_item_display_name, get_item_level_display, CharacterItem,
+        CharacterItemOwned, Enchantment, ItemRarity,
+    },
+    keymap::{is_key_action, KeyAction},
+    menu::{
+        inventory::{menu_armor_info, menu_ring_info, menu_weapon_info},
+        widget::{draw_key_hints, SelectList},
+    },
+    print_line, print_text,
+    replay::read_event,
+    session::PlayerCharacter,
+    tr,
+    util::{print_rarity_text, reset_text_color, shift_index_back},
+};
+
+#[derive(PartialEq)]
+enum BlacksmithService {
+    Upgrade,
+    Reroll,
+    RaiseRarity,
+    Salvage,
+}
+
+pub fn menu_blacksmith(character: &mut PlayerCharacter) -> io::Result<()> {
+    let menu_items = vec![
+        "menu.blacksmith.upgrade_items",
+        "menu.blacksmith.reroll_enchantments",
+        "menu.blacksmith.raise_rarity",
+        "menu.blacksmith.salvage_items",
+    ];
+    let mut list = SelectList::new();
+    let start_column: u16 = 2;
+
+    clear()?;
+    loop {
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!(
+            "{}",
+            tr!(
+                "blacksmith.title",
+                gold = character.data.currency.gold,
+                materials = character.data.inventory.crafting_materials
+            )
+        )?;
+
+        list.draw(start_column, &translate_all(&menu_items))?;
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    code if list.is_select(code, menu_items.len()) => match menu_items
+                        [list.selected_index]
+                    {
+                        "menu.blacksmith.upgrade_items" => {
+                            menu_blacksmith_item_list(character, BlacksmithService::Upgrade)?
+                        }
+                        "menu.blacksmith.reroll_enchantments" => {
+                            menu_blacksmith_item_list(character, BlacksmithService::Reroll)?
+                        }
+                        "menu.blacksmith.raise_rarity" => {
+                            menu_blacksmith_item_list(character, BlacksmithService::RaiseRarity)?
+                        }
+                        "menu.blacksmith.salvage_items" => {
+                            menu_blacksmith_item_list(character, BlacksmithService::Salvage)?
+                        }
+                        _ => break,
+                    },
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
+
+fn menu_blacksmith_item_list(
+    character: &mut PlayerCharacter,
+    service: BlacksmithService,
+) -> io::Result<()> {
+    let mut list = SelectList::new();
+    let start_column: u16 = 2;
+
+    clear()?;
+    loop {
+        let menu_items = get_equipment_items(character);
+
+        match service {
+            BlacksmithService::Upgrade => draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.item_info"),
+                    (KeyAction::Upgrade, "hint.upgrade_item"),
+                ],
+            )?,
+            BlacksmithService::Reroll => draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.item_info"),
+                    (KeyAction::Reroll, "hint.reroll_enchantment"),
+                ],
+            )?,
+            BlacksmithService::RaiseRarity => draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.item_info"),
+                    (KeyAction::RaiseRarity, "hint.raise_rarity"),
+                ],
+            )?,
+            BlacksmithService::Salvage => draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.item_info"),
+                    (KeyAction::Salvage, "hint.salvage_item"),
+                ],
+            )?,
+        }
+        move_to(0, 1)?;
+        print_line!(
+            "{}",
+            tr!(
+                "blacksmith.title",
+                gold = character.data.currency.gold,
+                materials = character.data.inventory.crafting_materials
+            )
+        )?;
+        move_to(0, 2)?;
+
+        if menu_items.is_empty() {
+            print_line!("  {}", tr!("blacksmith.no_items"))?;
+        }
+
+        for (i, item) in menu_items.iter().enumerate() {
+            move_to(0, i as u16 + start_column)?;
+            let (name, lvl, rarity, enchantments, equipped) = match item {
+                CharacterItemOwned::Weapon(item) => (
+                    get_item_display_name(CharacterItem::Weapon(item)),
+                    item.level,
+                    &item.rarity,
+                    &item.enchantments,
+                    item.is_equipped(character),
+                ),
+                CharacterItemOwned::Armor(item) => (
+                    get_item_display_name(CharacterItem::Armor(item)),
+                    item.level,
+                    &item.rarity,
+                    &item.enchantments,
+                    item.is_equipped(character),
+                ),
+                CharacterItemOwned::Ring(item) => (
+                    get_item_display_name(CharacterItem::Ring(item)),
+                    item.level,
+                    &item.rarity,
+                    &item.enchantments,
+                    item.is_equipped(character),
+                ),
+                _ => continue,
+            };
+
+            if i == list.selected_index {
+                print_text!("> ")?;
+            } else {
+                print_text!("  ")?;
+            }
+            print_rarity_text(rarity, &name)?;
+            reset_text_color()?;
+            print_text!(" {}", get_item_level_display(lvl))?;
+            if equipped {
+                print_text!("{}", tr!("inventory.equipped_tag"))?;
+            }
+            match service {
+                BlacksmithService::Upgrade => {
+                    print_text!("   {}", get_cost_display(&get_upgrade_cost(lvl)))?
+                }
+                BlacksmithService::Reroll => {
+                    if enchantments.is_empty() {
+                        print_text!("   [{}]", tr!("item_info.no_enchantments"))?;
+                    } else {
+                        print_text!("   {}", get_cost_display(&get_reroll_cost(rarity)))?;
+                    }
+                }
+                BlacksmithService::RaiseRarity => match get_raise_rarity_cost(rarity) {
+                    Some(cost) => print_text!("   {}", get_cost_display(&cost))?,
+                    None => print_text!("   [{}]", tr!("blacksmith.max_rarity"))?,
+                },
+                BlacksmithService::Salvage => print_text!(
+                    "   [{}]",
+                    tr!(
+                        "blacksmith.salvage_value",
+                        materials = get_item_salvage_value(rarity)
+                    )
+                )?,
+            }
+        }
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    code if list.is_select(code, menu_items.len()) && !menu_items.is_empty() => {
+                        match &menu_items[list.selected_index] {
+                            CharacterItemOwned::Weapon(item) => {
+                                menu_weapon_info(item, character, false)?
+                            }
+                            CharacterItemOwned::Armor(item) => {
+                                menu_armor_info(item, character, false)?
+                            }
+                            CharacterItemOwned::Ring(item) => {
+                                menu_ring_info(item, character, false)?
+                            }
+                            _ => {}
+                        }
+                    }
+                    code if service == BlacksmithService::Upgrade
+                        && is_key_action(code, KeyAction::Upgrade)
+                        && !menu_items.is_empty() =>
+                    {
+                        let upgraded = match &menu_items[list.selected_index] {
+                            CharacterItemOwned::Weapon(item) => upgrade_weapon(&item.id, character),
+                            CharacterItemOwned::Armor(item) => upgrade_armor(&item.id, character),
+                            CharacterItemOwned::Ring(item) => upgrade_ring(&item.id, character),
+                            _ => false,
+                        };
+                        if upgraded {
+                            clear()?;
+                        }
+                    }
+                    code if service == BlacksmithService::Reroll
+                        && is_key_action(code, KeyAction::Reroll)
+                        && !menu_items.is_empty() =>
+                    {
+                        menu_blacksmith_reroll_enchantment(
+                            &menu_items[list.selected_index],
+                            character,
+                        )?;
+                    }
+                    code if service == BlacksmithService::RaiseRarity
+                        && is_key_action(code, KeyAction::RaiseRarity)
+                        && !menu_items.is_empty() =>
+                    {
+                        let raised = match &menu_items[list.selected_index] {
+                            CharacterItemOwned::Weapon(item) => {
+                                raise_weapon_rarity(&item.id, character)
+                            }
+                            CharacterItemOwned::Armor(item) => {
+                                raise_armor_rarity(&item.id, character)
+                            }
+                            CharacterItemOwned::Ring(item) => {
+                                raise_ring_rarity(&item.id, character)
+                            }
+                            _ => false,
+                        };
+                        if raised {
+                            clear()?;
+                        }
+                    }
+                    code if service == BlacksmithService::Salvage
+                        && is_key_action(code, KeyAction::Salvage)
+                        && !menu_items.is_empty() =>
+                    {
+                        let materials = match &menu_items[list.selected_index] {
+                            CharacterItemOwned::Weapon(item) => salvage_weapon(item, character),
+                            CharacterItemOwned::Armor(item) => salvage_armor(item, character),
+                            CharacterItemOwned::Ring(item) => salvage_ring(item, character),
+                            _ => 0,
+                        };
+                        if materials != 0 {
+                            list.selected_index = shift_index_back(list.selected_index);
+                            clear()?;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
+
+fn menu_blacksmith_reroll_enchantment(
+    item: &CharacterItemOwned,
+    character: &mut PlayerCharacter,
+) -> io::Result<()> {
+    let mut list = SelectList::new();
+    let start_column: u16 = 3;
+
+    clear()?;
+    loop {
+        // Read the item again from the inventory so rerolled enchantments are shown.
+        let (name, rarity, enchantments): (String, ItemRarity, Vec<Enchantment>) = match item {
+            CharacterItemOwned::Weapon(item) => {
+                match character.data.inventory.weapons.get(&item.id) {
+                    Some(item) => (
+                        get_item_display_name(CharacterItem::Weapon(item)),
+                        item.rarity.clone(),
+                        item.enchantments.clone(),
+                    ),
+                    None => break,
+                }
+            }
+            CharacterItemOwned::Armor(item) => {
+                match character.data.inventory.armors.get(&item.id) {
+                    Some(item) => (
+                        get_item_display_name(CharacterItem::Armor(item)),
+                        item.rarity.clone(),
+                        item.enchantments.clone(),
+                    ),
+                    None => break,
+                }
+            }
+            CharacterItemOwned::Ring(item) => match character.data.inventory.rings.get(&item.id) {
+                Some(item) => (
+                    get_item_display_name(CharacterItem::Ring(item)),
+                    item.rarity.clone(),
+                    item.enchantments.clone(),
+                ),
+                None => break,
+            },
+            _ => break,
+        };
+
+        draw_key_hints(
+            0,
+            &[
+                (KeyAction::Back, "hint.back"),
+                (KeyAction::Reroll, "hint.reroll_enchantment"),
+            ],
+        )?;
+        move_to(0, 1)?;
+        print_text!("{}", tr!("blacksmith.reroll_enchantment"))?;
+        print_rarity_text(&rarity, &name)?;
+        reset_text_color()?;
+        move_to(0, 2)?;
+        print_line!(
+            "{}",
+            tr!(
+                "blacksmith.reroll_cost",
+                cost = get_cost_display(&get_reroll_cost(&rarity)),
+                gold = character.data.currency.gold,
+                materials = character.data.inventory.crafting_materials
+            )
+        )?;
+        move_to(0, 3)?;
+
+        if enchantments.is_empty() {
+            print_line!("  {}", tr!("item_info.no_enchantments"))?;
+        }
+
+        for (i, enchantment) in enchantments.iter().enumerate() {
+            move_to(0, i as u16 + start_column)?;
+            if i == list.selected_index {
+                print_line!("> {}", get_enchantment_display(enchantment))?;
+            } else {
+                print_line!("  {}", get_enchantment_display(enchantment))?;
+            }
+        }
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, enchantments.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    code if is_key_action(code, KeyAction::Reroll) => {
+                        let rerolled = match item {
+                            CharacterItemOwned::Weapon(item) => {
+                                reroll_weapon_enchantment(&item.id, list.selected_index, character)
+                            }
+                            CharacterItemOwned::Armor(item) => {
+                                reroll_armor_enchantment(&item.id, list.selected_index, character)
+                            }
+                            CharacterItemOwned::Ring(item) => {
+                                reroll_ring_enchantment(&item.id, list.selected_index, character)
+                            }
+                            _ => false,
+                        };
+                        if rerolled {
+                            clear()?;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
+
+/// Returns the character's weapons, armors and rings.
+fn get_equipment_items(character: &PlayerCharacter) -> Vec<CharacterItemOwned> {
+    let mut items = Vec::new();
+    for item in character.data.inventory.weapons.values() {
+        items.push(CharacterItemOwned::Weapon(item.clone()));
+    }
+    for item in character.data.inventory.armors.values() {
+        items.push(CharacterItemOwned::Armor(item.clone()));
+    }
+    for item in character.data.inventory.rings.values() {
+        items.push(CharacterItemOwned::Ring(item.clone()));
+    }
+    items.sort_by_cached_key(|item| item.get_receive_order_key());
+    items
+}
+
+fn get_cost_display(cost: &BlacksmithCost) -> String {
+    format!(
+        "[{}]",
+        tr!(
+            "blacksmith.cost",
+            gold = cost.gold,
+            materials = cost.materials
+        )
+    )
+}
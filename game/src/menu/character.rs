@@ -1,88 +1,86 @@
-use crossterm::{
-    cursor,
-    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
-    execute,
-    terminal::{Clear, ClearType},
-};
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
 use std::io;
 
 use crate::{
+    frontend::{clear, move_to},
+    i18n::translate_all,
+    keymap::{is_key_action, KeyAction},
     menu::{
-        equipment::menu_equipment, inventory::menu_inventory, skill::menu_skill,
+        alchemy::menu_alchemy,
+        companion::menu_companion,
+        equipment::menu_equipment,
+        inventory::menu_inventory,
+        skill::menu_skill,
         stats::menu_character_stats,
+        widget::{draw_key_hints, menu_confirm, SelectList},
     },
+    print_line,
+    replay::read_event,
     session::PlayerCharacter,
+    tr,
 };
 
 /// Returns true if should go back to main menu.
 pub fn menu_character(character: &mut PlayerCharacter) -> io::Result<bool> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     let menu_items = vec![
-        "Stats",
-        "Inventory",
-        "Equipment",
-        "Skill",
-        "Return to main menu",
+        "menu.character.stats",
+        "menu.character.inventory",
+        "menu.character.equipment",
+        "menu.character.companion",
+        "menu.character.skill",
+        "menu.character.alchemy",
+        "menu.character.return_to_main_menu",
     ];
-    let mut selected_index = 0;
+    let mut list = SelectList::new();
     let start_column: u16 = 2;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("(Esc) Close Menu");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Menu");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
+        draw_key_hints(0, &[(KeyAction::Back, "hint.close_menu")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("character_menu.title"))?;
+        move_to(0, 2)?;
 
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
-            } else {
-                println!("  {}", item);
-            }
-        }
+        list.draw(start_column, &translate_all(&menu_items))?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Esc => {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
-                    KeyCode::Enter => match menu_items[selected_index] {
-                        "Stats" => {
-                            menu_character_stats(&character)?;
-                        }
-                        "Inventory" => {
-                            menu_inventory(character, false)?;
-                        }
-                        "Equipment" => {
-                            menu_equipment(character)?;
-                        }
-                        "Skill" => {
-                            menu_skill(character)?;
-                        }
-                        "Return to main menu" => {
-                            let confirm = menu_confirm_return_to_main_menu()?;
-                            if confirm {
-                                return Ok(true);
+                    code if list.is_select(code, menu_items.len()) => {
+                        match menu_items[list.selected_index] {
+                            "menu.character.stats" => {
+                                menu_character_stats(character)?;
                             }
-                            execute!(stdout, Clear(ClearType::All))?;
+                            "menu.character.inventory" => {
+                                menu_inventory(character, None)?;
+                            }
+                            "menu.character.equipment" => {
+                                menu_equipment(character)?;
+                            }
+                            "menu.character.companion" => {
+                                menu_companion(character)?;
+                            }
+                            "menu.character.skill" => {
+                                menu_skill(character)?;
+                            }
+                            "menu.character.alchemy" => {
+                                menu_alchemy(character)?;
+                            }
+                            "menu.character.return_to_main_menu" => {
+                                let confirm = menu_confirm_return_to_main_menu()?;
+                                if confirm {
+                                    return Ok(true);
+                                }
+                                clear()?;
+                            }
+                            _ => break,
                         }
-                        _ => break,
-                    },
+                    }
                     _ => {}
                 }
             }
@@ -93,77 +91,29 @@ pub fn menu_character(character: &mut PlayerCharacter) -> io::Result<bool> {
 }
 
 fn menu_confirm_return_to_main_menu() -> io::Result<bool> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
-
-    let menu_items = vec!["No", "Yes"];
-    let mut selected_index = 0;
-    let start_column: u16 = 2;
-
-    loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("Return back to the game main menu?");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Progress in the current dungeon floor will be lost.");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
-
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
-            } else {
-                println!("  {}", item);
-            }
-        }
-
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
-            if kind == KeyEventKind::Press {
-                match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Enter => {
-                        break;
-                    }
-                    _ => {}
-                }
-            }
-        }
-    }
-
-    match menu_items[selected_index] {
-        "Yes" => return Ok(true),
-        _ => {}
-    }
-
-    Ok(false)
+    menu_confirm(&[
+        &tr!("character_menu.return_confirm"),
+        &tr!("character_menu.return_confirm_progress"),
+    ])
 }
 
 pub fn menu_level_up(new_level: u32) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("You Leveled Up!");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("New Level: {}", new_level);
-        execute!(stdout, cursor::MoveTo(0, 2))?;
-        println!("Some stats have been increased");
-        execute!(stdout, cursor::MoveTo(0, 4))?;
-        println!("> Continue");
-
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        move_to(0, 0)?;
+        print_line!("{}", tr!("level_up.title"))?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("level_up.new_level", level = new_level))?;
+        move_to(0, 2)?;
+        print_line!("{}", tr!("level_up.stats_increased"))?;
+        move_to(0, 4)?;
+        print_line!("{}", tr!("level_up.continue"))?;
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Enter => {
+                    code if is_key_action(code, KeyAction::Select) => {
                         break;
                     }
                     _ => {}
@@ -171,7 +121,7 @@ pub fn menu_level_up(new_level: u32) -> io::Result<()> {
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
@@ -0,0 +1,156 @@
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
+use std::io;
+
+use crate::{
+    companion::Companion,
+    frontend::{clear, move_to},
+    i18n::translate_all,
+    keymap::{is_key_action, KeyAction},
+    layout::print_wrapped,
+    menu::widget::{draw_key_hints, SelectList},
+    print_line,
+    replay::read_event,
+    session::PlayerCharacter,
+    tr,
+};
+
+pub fn menu_companion(character: &PlayerCharacter) -> io::Result<()> {
+    clear()?;
+
+    loop {
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("companion.title"))?;
+        match &character.data.companion {
+            Some(companion) => {
+                move_to(0, 2)?;
+                print_line!(
+                    "  {}",
+                    tr!(
+                        "stats.exp",
+                        current = companion.current_exp,
+                        required = companion.required_exp
+                    )
+                )?;
+                display_companion_stats(companion, 3)?;
+            }
+            None => {
+                move_to(0, 2)?;
+                print_line!("  {}", tr!("companion.none"))?;
+            }
+        }
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
+
+/// Shows a companion the player can take along.
+/// The companion is free if price is None.
+/// Returns true if the player accepts the companion.
+pub fn menu_companion_offer(
+    companion: &Companion,
+    character: &PlayerCharacter,
+    price: Option<u32>,
+) -> io::Result<bool> {
+    let menu_items = match price {
+        Some(_) => vec!["menu.companion.buy", "menu.back"],
+        None => vec!["menu.companion.take", "menu.leave"],
+    };
+    let mut list = SelectList::new();
+    let mut accepted = false;
+
+    clear()?;
+    loop {
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        match price {
+            Some(price) => print_line!(
+                "{}",
+                tr!(
+                    "companion.for_sale",
+                    price = price,
+                    gold = character.data.currency.gold
+                )
+            )?,
+            None => print_line!("{}", tr!("companion.wants_to_join"))?,
+        }
+        let mut start_column = display_companion_stats(companion, 2)?;
+        if let Some(current) = &character.data.companion {
+            move_to(0, start_column)?;
+            print_line!(
+                "{}",
+                tr!(
+                    "companion.current_will_leave",
+                    companion = current.get_display_name()
+                )
+            )?;
+            start_column += 1;
+        }
+        start_column += 1;
+
+        list.draw(start_column, &translate_all(&menu_items))?;
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    code if list.is_select(code, menu_items.len()) => {
+                        accepted = matches!(
+                            menu_items[list.selected_index],
+                            "menu.companion.buy" | "menu.companion.take"
+                        );
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(accepted)
+}
+
+/// Returns the next column after the stats.
+pub fn display_companion_stats(companion: &Companion, start_column: u16) -> io::Result<u16> {
+    move_to(0, start_column)?;
+    print_line!("  {}", companion.get_display_name())?;
+    move_to(0, start_column + 1)?;
+    print_line!(
+        "  {}",
+        tr!("stats.health", value = companion.stats.max_health)
+    )?;
+    move_to(0, start_column + 2)?;
+    print_line!("  {}", tr!("stats.damage", value = companion.stats.damage))?;
+    move_to(0, start_column + 3)?;
+    print_line!(
+        "  {}",
+        tr!("stats.defense", value = companion.stats.defense)
+    )?;
+    let ability_rows = print_wrapped(
+        start_column + 4,
+        "  ",
+        &tr!(
+            "companion.ability",
+            ability = companion.get_ability(),
+            description = companion.get_ability_description()
+        ),
+    )?;
+
+    Ok(start_column + 4 + ability_rows)
+}
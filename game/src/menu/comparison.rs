@@ -0,0 +1,190 @@
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
+use std::io;
+
+use crate::{
+    comparison::{compare_armor, compare_ring, compare_weapon, ItemComparison},
+    frontend::{clear, move_to},
+    items::{get_item_display_name, get_item_level_display, CharacterItem},
+    keymap::{is_key_action, KeyAction},
+    menu::widget::draw_key_hints,
+    print_line, print_text,
+    replay::read_event,
+    session::PlayerCharacter,
+    theme::ThemeColor,
+    tr,
+    util::{print_rarity_text, reset_text_color, set_text_color},
+};
+
+/// Shows the stat changes of swapping the equipped item for the selected item.
+pub fn menu_compare_item(item: CharacterItem, character: &PlayerCharacter) -> io::Result<()> {
+    let (comparison, equipped_item) = match item {
+        CharacterItem::Weapon(weapon) => (
+            compare_weapon(character, weapon),
+            character
+                .equipped_items
+                .weapon
+                .as_ref()
+                .and_then(|id| character.data.inventory.weapons.get(id))
+                .map(CharacterItem::Weapon),
+        ),
+        CharacterItem::Armor(armor) => (
+            compare_armor(character, armor),
+            character
+                .equipped_items
+                .armor
+                .as_ref()
+                .and_then(|id| character.data.inventory.armors.get(id))
+                .map(CharacterItem::Armor),
+        ),
+        CharacterItem::Ring(ring) => (
+            compare_ring(character, ring),
+            character
+                .equipped_items
+                .ring
+                .as_ref()
+                .and_then(|id| character.data.inventory.rings.get(id))
+                .map(CharacterItem::Ring),
+        ),
+        _ => return Ok(()),
+    };
+
+    clear()?;
+    loop {
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("compare.title"))?;
+        move_to(0, 2)?;
+        print_text!("  {}", tr!("compare.selected"))?;
+        display_compared_item(Some(&item))?;
+        move_to(0, 3)?;
+        print_text!("  {}", tr!("compare.equipped"))?;
+        display_compared_item(equipped_item.as_ref())?;
+        display_stat_comparison(&comparison, 5)?;
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
+
+fn display_compared_item(item: Option<&CharacterItem>) -> io::Result<()> {
+    let (name, lvl, rarity) = match item {
+        Some(CharacterItem::Weapon(item)) => (
+            get_item_display_name(CharacterItem::Weapon(item)),
+            item.level,
+            &item.rarity,
+        ),
+        Some(CharacterItem::Armor(item)) => (
+            get_item_display_name(CharacterItem::Armor(item)),
+            item.level,
+            &item.rarity,
+        ),
+        Some(CharacterItem::Ring(item)) => (
+            get_item_display_name(CharacterItem::Ring(item)),
+            item.level,
+            &item.rarity,
+        ),
+        _ => {
+            print_line!("{}", tr!("equipment.not_equipped"))?;
+            return Ok(());
+        }
+    };
+    print_rarity_text(rarity, &name)?;
+    reset_text_color()?;
+    print_line!(" {}", get_item_level_display(lvl))?;
+    Ok(())
+}
+
+/// Displays the current and new stats with the change between them.
+fn display_stat_comparison(comparison: &ItemComparison, start_column: u16) -> io::Result<()> {
+    let current = &comparison.current;
+    let new = &comparison.new;
+    move_to(0, start_column)?;
+    print_line!(
+        "  {:<20}{:<10}{:<10}{}",
+        tr!("compare.stat"),
+        tr!("compare.current"),
+        tr!("compare.new"),
+        tr!("compare.change")
+    )?;
+    display_stat_row(
+        &tr!("compare.damage"),
+        current.damage as f64,
+        new.damage as f64,
+        0,
+        start_column + 1,
+    )?;
+    display_stat_row(
+        &tr!("compare.crit_hit_rate"),
+        current.crit_hit_rate,
+        new.crit_hit_rate,
+        2,
+        start_column + 2,
+    )?;
+    display_stat_row(
+        &tr!("compare.max_health"),
+        current.max_health as f64,
+        new.max_health as f64,
+        0,
+        start_column + 3,
+    )?;
+    display_stat_row(
+        &tr!("compare.defense"),
+        current.defense as f64,
+        new.defense as f64,
+        0,
+        start_column + 4,
+    )?;
+    display_stat_row(
+        &tr!("compare.max_mana"),
+        current.max_mana as f64,
+        new.max_mana as f64,
+        0,
+        start_column + 5,
+    )?;
+
+    Ok(())
+}
+
+/// Positive changes are displayed in green and negative changes in red.
+fn display_stat_row(
+    stat: &str,
+    current: f64,
+    new: f64,
+    decimals: usize,
+    column: u16,
+) -> io::Result<()> {
+    let change = new - current;
+    move_to(0, column)?;
+    print_text!(
+        "  {:<20}{:<10.*}{:<10.*}",
+        stat,
+        decimals,
+        current,
+        decimals,
+        new
+    )?;
+    if change.abs() < 0.005 {
+        print_line!("{:.*}", decimals, 0.0)?;
+        return Ok(());
+    }
+    if change > 0.0 {
+        set_text_color(ThemeColor::Positive)?;
+    } else {
+        set_text_color(ThemeColor::Negative)?;
+    }
+    print_line!("{:+.*}", decimals, change)?;
+    reset_text_color()?;
+
+    Ok(())
+}
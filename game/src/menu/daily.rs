@@ -0,0 +1,237 @@
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
+use std::{collections::BTreeMap, io};
+
+use crate::{
+    daily::{
+        create_daily_character, create_daily_result, get_daily_date, get_daily_floor_seed,
+        get_daily_seed, DAILY_CHALLENGE_CLASS, DAILY_CHALLENGE_FLOORS, DAILY_CHALLENGE_LEVEL,
+        DAILY_CHALLENGE_START_FLOOR,
+    },
+    dungeon::{generate_random_dungeon_floor, RoomCoordinates},
+    frontend::{clear, move_to},
+    game::save_game,
+    game_data::DailyResult,
+    i18n::translate_all,
+    keymap::{is_key_action, KeyAction},
+    menu::{
+        dungeon::menu_dungeon_floor,
+        widget::{draw_key_hints, SelectList},
+    },
+    print_line,
+    replay::read_event,
+    rng::{reset_game_rng, seed_game_rng},
+    session::Player,
+    tr,
+};
+
+/// Max number of dates shown in the daily challenge history.
+const DAILY_HISTORY_ROWS: usize = 20;
+
+pub fn menu_daily_challenge(player: &mut Player) -> io::Result<()> {
+    let menu_items = vec!["menu.daily.start", "menu.daily.history"];
+    let mut list = SelectList::new();
+    let start_column: u16 = 5;
+
+    clear()?;
+    loop {
+        let date = get_daily_date();
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("daily.title", date = date))?;
+        move_to(0, 2)?;
+        print_line!(
+            "{}",
+            tr!(
+                "daily.rules",
+                floors = DAILY_CHALLENGE_FLOORS,
+                start_floor = DAILY_CHALLENGE_START_FLOOR,
+                level = DAILY_CHALLENGE_LEVEL,
+                class = DAILY_CHALLENGE_CLASS
+            )
+        )?;
+        move_to(0, 3)?;
+        match player.data.daily_history.get(&date) {
+            Some(best) => print_line!(
+                "{}",
+                tr!(
+                    "daily.todays_best",
+                    score = best.score,
+                    floors_cleared = best.floors_cleared,
+                    floors = DAILY_CHALLENGE_FLOORS,
+                    attempts = best.attempts
+                )
+            )?,
+            None => print_line!("{}", tr!("daily.not_attempted"))?,
+        }
+
+        list.draw(start_column, &translate_all(&menu_items))?;
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    code if list.is_select(code, menu_items.len()) => {
+                        match menu_items[list.selected_index] {
+                            "menu.daily.start" => menu_daily_run(player, &date)?,
+                            "menu.daily.history" => menu_daily_history(&player.data.daily_history)?,
+                            _ => break,
+                        }
+                        clear()?;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
+
+/// Plays the daily challenge with a temporary character.
+/// The previously selected character is restored after the run.
+fn menu_daily_run(player: &mut Player, date: &str) -> io::Result<()> {
+    seed_game_rng(get_daily_seed(date));
+    let previous_character = player.character.replace(create_daily_character());
+    player.temporary_character = true;
+
+    let run = play_daily_floors(player, date);
+
+    reset_game_rng();
+    player.temporary_character = false;
+    let daily_character = std::mem::replace(&mut player.character, previous_character);
+    run?;
+
+    let result = match &daily_character {
+        Some(character) => create_daily_result(character),
+        None => return Ok(()),
+    };
+    let new_best = player.data.record_daily_result(date, result.clone());
+    save_game(player)?;
+    menu_daily_results(&result, new_best)
+}
+
+/// Each floor is generated from the seed of the date and the floor.
+fn play_daily_floors(player: &mut Player, date: &str) -> io::Result<()> {
+    for floor in DAILY_CHALLENGE_START_FLOOR..DAILY_CHALLENGE_START_FLOOR + DAILY_CHALLENGE_FLOORS {
+        seed_game_rng(get_daily_floor_seed(date, floor));
+        let mut dungeon_floor =
+            generate_random_dungeon_floor(floor, &player.get_character()?.data.metadata.class);
+        let mut next_room_coords = RoomCoordinates::new(0, 0);
+
+        loop {
+            let opts = menu_dungeon_floor(&mut dungeon_floor, player, &next_room_coords)?;
+            if opts.return_to_main_menu || opts.game_over {
+                return Ok(());
+            }
+            if opts.dungeon_completed {
+                break;
+            }
+            if let Some(coords) = opts.next_room_coords {
+                next_room_coords = coords;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn menu_daily_results(result: &DailyResult, new_best: bool) -> io::Result<()> {
+    clear()?;
+
+    loop {
+        move_to(0, 0)?;
+        if result.floors_cleared >= DAILY_CHALLENGE_FLOORS {
+            print_line!("{}", tr!("daily.completed"))?;
+        } else {
+            print_line!("{}", tr!("daily.over"))?;
+        }
+        move_to(0, 1)?;
+        print_line!(
+            "{}",
+            tr!(
+                "daily.floors_cleared",
+                floors_cleared = result.floors_cleared,
+                floors = DAILY_CHALLENGE_FLOORS
+            )
+        )?;
+        move_to(0, 2)?;
+        print_line!(
+            "{}",
+            tr!("daily.character_level", level = result.character_level)
+        )?;
+        move_to(0, 3)?;
+        print_line!("{}", tr!("daily.score", score = result.score))?;
+        move_to(0, 4)?;
+        if new_best {
+            print_line!("{}", tr!("daily.new_best"))?;
+        } else {
+            print_line!("{}", tr!("daily.best_is_higher"))?;
+        }
+        move_to(0, 6)?;
+        print_line!("{}", tr!("level_up.continue"))?;
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if is_key_action(code, KeyAction::Select) => {
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
+
+fn menu_daily_history(history: &BTreeMap<String, DailyResult>) -> io::Result<()> {
+    let start_column: u16 = 2;
+    clear()?;
+
+    loop {
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("daily.history"))?;
+
+        if history.is_empty() {
+            move_to(0, start_column)?;
+            print_line!("  {}", tr!("daily.no_history"))?;
+        }
+        // newest first
+        for (i, (date, result)) in history.iter().rev().take(DAILY_HISTORY_ROWS).enumerate() {
+            move_to(0, i as u16 + start_column)?;
+            print_line!(
+                "  {}",
+                tr!(
+                    "daily.history_entry",
+                    date = date,
+                    score = result.score,
+                    floors_cleared = result.floors_cleared,
+                    floors = DAILY_CHALLENGE_FLOORS,
+                    level = result.character_level,
+                    attempts = result.attempts
+                )
+            )?;
+        }
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
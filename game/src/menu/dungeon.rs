@@ -4,22 +4,38 @@ use crate::{
         generate_ancient_ruins, generate_random_dungeon_floor, DungeonFloor, Room, RoomCoordinates,
         RoomKind,
     },
+    fight::FightOptions,
+    frontend::{clear, move_to},
     game::save_game,
+    i18n::translate_all,
     items::get_item_level_display,
-    menu::{character::menu_character, shop::menu_shop},
+    keymap::{get_key_display, is_key_action, KeyAction},
+    layout::get_rows_left,
+    menu::{
+        alchemy::display_ingredient_drops,
+        arena::menu_arena,
+        blacksmith::menu_blacksmith,
+        character::menu_character,
+        companion::menu_companion_offer,
+        quest::menu_bounty_board,
+        shop::{menu_shop, menu_wandering_merchant},
+        stash::menu_stash,
+        widget::SelectList,
+    },
+    print_line, print_text,
+    replay::read_event,
     session::{Player, PlayerCharacter},
-    util::{reset_text_color, set_rarity_text_color},
-};
-use crossterm::{
-    cursor,
-    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
-    execute,
-    terminal::{Clear, ClearType},
+    tr,
+    util::{print_rarity_text, reset_text_color},
 };
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
 use std::io;
 
 use super::enemy::menu_enemy_encounter;
 
+/// Rows used by the room drawings including the empty rows around them.
+const ROOM_DISPLAY_ROWS: u16 = 8;
+
 pub struct GameMenuReturnOptions {
     /// If should return to main menu.
     pub main_menu: bool,
@@ -36,87 +52,76 @@ pub struct DungeonFloorMenuOptions {
 
 /// Returns true if should go back to main menu.
 pub fn menu_start_dungeon_floor(player: &mut Player) -> io::Result<bool> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     let menu_items = vec![
-        "Start Dungeon Floor",
-        "Enter Ancient Ruins (Ancient Ruins Key required)",
-        "Return to main menu",
+        "menu.dungeon.start_floor",
+        "menu.dungeon.enter_ancient_ruins",
+        "menu.dungeon.enter_arena",
+        "menu.character.return_to_main_menu",
     ];
-    let mut selected_index = 0;
+    let mut list = SelectList::new();
     let start_column: u16 = 1;
 
     loop {
         let character = player.get_character()?;
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!(
-            "Character: {} (Level {} {:?}, Dungeon Floor {}, Ancient Ruins Keys: {})",
-            character.data.metadata.name,
-            character.data.stats.general_stats.character_level,
-            character.data.metadata.class,
-            character.data.stats.general_stats.current_dungeon_floor,
-            character.data.inventory.ancient_ruins_keys,
-        );
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
-            } else {
-                println!("  {}", item);
-            }
-        }
-
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        move_to(0, 0)?;
+        print_line!(
+            "{}",
+            tr!(
+                "dungeon.character",
+                name = character.data.metadata.name,
+                level = character.data.stats.general_stats.character_level,
+                class = character.data.metadata.class,
+                floor = character.data.stats.general_stats.current_dungeon_floor,
+                keys = character.data.inventory.ancient_ruins_keys,
+            )
+        )?;
+        move_to(0, 1)?;
+
+        list.draw(start_column, &translate_all(&menu_items))?;
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Enter => match menu_items[selected_index] {
-                        "Start Dungeon Floor" => {
-                            let mut dungeon_floor = generate_random_dungeon_floor(
-                                character.data.stats.general_stats.current_dungeon_floor,
-                                &character.data.metadata.class,
-                            );
-                            let mut next_room_coords = RoomCoordinates::new(0, 0);
-
-                            loop {
-                                let opts = menu_dungeon_floor(
-                                    &mut dungeon_floor,
-                                    player,
-                                    &next_room_coords,
-                                )?;
-                                if opts.return_to_main_menu {
-                                    return Ok(true);
-                                }
-                                if opts.game_over {
-                                    player.get_character_mut()?.reset_character_on_death();
-                                    save_game(player)?;
-                                    execute!(stdout, Clear(ClearType::All))?;
-                                    break;
-                                }
-                                if opts.dungeon_completed {
-                                    save_game(player)?;
-                                    execute!(stdout, Clear(ClearType::All))?;
-                                    break;
-                                }
-                                if let Some(coords) = opts.next_room_coords {
-                                    next_room_coords = coords;
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if list.is_select(code, menu_items.len()) => {
+                        match menu_items[list.selected_index] {
+                            "menu.dungeon.start_floor" => {
+                                let mut dungeon_floor = generate_random_dungeon_floor(
+                                    character.data.stats.general_stats.current_dungeon_floor,
+                                    &character.data.metadata.class,
+                                );
+                                let mut next_room_coords = RoomCoordinates::new(0, 0);
+
+                                loop {
+                                    let opts = menu_dungeon_floor(
+                                        &mut dungeon_floor,
+                                        player,
+                                        &next_room_coords,
+                                    )?;
+                                    if opts.return_to_main_menu {
+                                        return Ok(true);
+                                    }
+                                    if opts.game_over {
+                                        player.get_character_mut()?.reset_character_on_death();
+                                        save_game(player)?;
+                                        clear()?;
+                                        break;
+                                    }
+                                    if opts.dungeon_completed {
+                                        save_game(player)?;
+                                        clear()?;
+                                        break;
+                                    }
+                                    if let Some(coords) = opts.next_room_coords {
+                                        next_room_coords = coords;
+                                    }
                                 }
                             }
-                        }
-                        "Enter Ancient Ruins (Ancient Ruins Key required)" => {
-                            if player.get_character()?.can_enter_ancient_ruins() {
+                            "menu.dungeon.enter_ancient_ruins"
+                                if player.get_character()?.can_enter_ancient_ruins() =>
+                            {
                                 let character = player.get_character_mut()?;
                                 character.consume_ancient_ruins_key();
                                 let mut dungeon_floor = generate_ancient_ruins(
@@ -137,12 +142,12 @@ pub fn menu_start_dungeon_floor(player: &mut Player) -> io::Result<bool> {
                                     if opts.game_over {
                                         player.get_character_mut()?.reset_character_on_death();
                                         save_game(player)?;
-                                        execute!(stdout, Clear(ClearType::All))?;
+                                        clear()?;
                                         break;
                                     }
                                     if opts.dungeon_completed {
                                         save_game(player)?;
-                                        execute!(stdout, Clear(ClearType::All))?;
+                                        clear()?;
                                         break;
                                     }
                                     if let Some(coords) = opts.next_room_coords {
@@ -150,10 +155,13 @@ pub fn menu_start_dungeon_floor(player: &mut Player) -> io::Result<bool> {
                                     }
                                 }
                             }
+                            "menu.dungeon.enter_arena" => {
+                                menu_arena(player)?;
+                            }
+                            "menu.character.return_to_main_menu" => break,
+                            _ => {}
                         }
-                        "Return to main menu" => break,
-                        _ => {}
-                    },
+                    }
                     _ => {}
                 }
             }
@@ -169,10 +177,9 @@ pub fn menu_dungeon_floor(
     player: &mut Player,
     current_room_coords: &RoomCoordinates,
 ) -> io::Result<DungeonFloorMenuOptions> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
     let mut menu_items = Vec::new();
-    let mut selected_index = 0;
+    let mut list = SelectList::new();
 
     let current_room = match dungeon_floor.rooms.get_mut(current_room_coords) {
         Some(room) => room,
@@ -185,7 +192,8 @@ pub fn menu_dungeon_floor(
     };
 
     if let Some(enemy) = &mut current_room.enemy {
-        let victory = menu_enemy_encounter(enemy, player.get_character_mut()?)?;
+        let victory =
+            menu_enemy_encounter(enemy, player.get_character_mut()?, &FightOptions::default())?;
         if victory {
             current_room.enemy = None;
         } else {
@@ -201,25 +209,36 @@ pub fn menu_dungeon_floor(
     if let Some(_) = current_room.adjacents.up {
         match current_room.kind {
             RoomKind::BossEntrance => {}
-            _ => menu_items.push("Go Up"),
+            _ => menu_items.push("menu.dungeon.go_up"),
         }
     }
     if let Some(_) = current_room.adjacents.down {
-        menu_items.push("Go Down");
+        menu_items.push("menu.dungeon.go_down");
     }
     if let Some(_) = current_room.adjacents.right {
-        menu_items.push("Go Right");
+        menu_items.push("menu.dungeon.go_right");
     }
     if let Some(_) = current_room.adjacents.left {
-        menu_items.push("Go Left");
+        menu_items.push("menu.dungeon.go_left");
     }
     match current_room.kind {
-        RoomKind::Start => menu_items.push("Enter Shop"),
-        RoomKind::BossEntrance => menu_items.push("Enter Boss Room"),
+        RoomKind::Start => {
+            menu_items.push("menu.dungeon.enter_shop");
+            menu_items.push("menu.dungeon.enter_blacksmith");
+            if !player.temporary_character {
+                menu_items.push("menu.dungeon.enter_stash");
+            }
+            menu_items.push("menu.dungeon.check_bounty_board");
+        }
+        RoomKind::BossEntrance => menu_items.push("menu.dungeon.enter_boss_room"),
         RoomKind::Boss => {
-            menu_items.push("Enter Next Floor");
+            menu_items.push("menu.dungeon.enter_next_floor");
             if let Some(boss) = &mut dungeon_floor.boss {
-                let victory = menu_enemy_encounter(boss, player.get_character_mut()?)?;
+                let victory = menu_enemy_encounter(
+                    boss,
+                    player.get_character_mut()?,
+                    &FightOptions::default(),
+                )?;
                 if victory {
                     dungeon_floor.boss = None;
                     if !dungeon_floor.ancient_ruins {
@@ -241,31 +260,56 @@ pub fn menu_dungeon_floor(
         _ => {}
     }
     if current_room.treasure {
-        menu_items.push("Open Treasure Chest");
+        menu_items.push("menu.dungeon.open_treasure_chest");
+    }
+    if current_room.companion.is_some() {
+        menu_items.push("menu.dungeon.meet_companion");
+    }
+    if let Some(merchant) = &dungeon_floor.merchant {
+        if merchant.room == current_room.coords {
+            menu_items.push("menu.dungeon.visit_wandering_merchant");
+        }
     }
 
     loop {
         let mut start_column = 2;
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("(Esc) Open Menu, Map Markers (S = Shop, B = Boss Room, N = Next Floor)");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
+        move_to(0, 0)?;
+        print_line!(
+            "{}",
+            tr!("dungeon.key_hints", key = get_key_display(KeyAction::Back))
+        )?;
+        move_to(0, 1)?;
         if !dungeon_floor.ancient_ruins {
             match current_room.kind {
-                RoomKind::Boss => println!("Dungeon Floor {}, Boss Room", dungeon_floor.floor),
-                _ => println!(
-                    "Dungeon Floor {}, Room {}",
-                    dungeon_floor.floor, current_room.room_num
-                ),
+                RoomKind::Boss => print_line!(
+                    "{}",
+                    tr!("dungeon.floor_boss_room", floor = dungeon_floor.floor)
+                )?,
+                _ => print_line!(
+                    "{}",
+                    tr!(
+                        "dungeon.floor_room",
+                        floor = dungeon_floor.floor,
+                        room = current_room.room_num
+                    )
+                )?,
             }
         } else {
             match current_room.kind {
-                RoomKind::Boss => println!("Ancient Ruins, Boss Room"),
-                _ => println!("Ancient Ruins, Room {}", current_room.room_num),
+                RoomKind::Boss => print_line!("{}", tr!("dungeon.ancient_ruins_boss_room"))?,
+                _ => print_line!(
+                    "{}",
+                    tr!("dungeon.ancient_ruins_room", room = current_room.room_num)
+                )?,
             }
         }
-        execute!(stdout, cursor::MoveTo(0, 2))?;
+        move_to(0, 2)?;
 
+        // the room is left out if it doesn't fit with the menu
+        let room_fits =
+            get_rows_left(start_column)? >= ROOM_DISPLAY_ROWS as usize + menu_items.len();
         start_column = match current_room.kind {
+            _ if !room_fits => start_column + 1,
             RoomKind::Start => display_start_room(start_column)?,
             RoomKind::Boss => display_boss_room(start_column)?,
             RoomKind::BossEntrance => display_boss_entrance_room(start_column)?,
@@ -283,30 +327,16 @@ pub fn menu_dungeon_floor(
             }
         };
 
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
-            } else {
-                println!("  {}", item);
-            }
-        }
+        list.draw(start_column, &translate_all(&menu_items))?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Enter => match menu_items[selected_index] {
-                        "Go Up" => {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if list.is_select(code, menu_items.len()) => match menu_items
+                        [list.selected_index]
+                    {
+                        "menu.dungeon.go_up" => {
                             return Ok(DungeonFloorMenuOptions {
                                 return_to_main_menu: false,
                                 dungeon_completed: false,
@@ -314,7 +344,7 @@ pub fn menu_dungeon_floor(
                                 next_room_coords: current_room.adjacents.up.clone(),
                             })
                         }
-                        "Go Down" => {
+                        "menu.dungeon.go_down" => {
                             return Ok(DungeonFloorMenuOptions {
                                 return_to_main_menu: false,
                                 dungeon_completed: false,
@@ -322,7 +352,7 @@ pub fn menu_dungeon_floor(
                                 next_room_coords: current_room.adjacents.down.clone(),
                             })
                         }
-                        "Go Right" => {
+                        "menu.dungeon.go_right" => {
                             return Ok(DungeonFloorMenuOptions {
                                 return_to_main_menu: false,
                                 dungeon_completed: false,
@@ -330,7 +360,7 @@ pub fn menu_dungeon_floor(
                                 next_room_coords: current_room.adjacents.right.clone(),
                             })
                         }
-                        "Go Left" => {
+                        "menu.dungeon.go_left" => {
                             return Ok(DungeonFloorMenuOptions {
                                 return_to_main_menu: false,
                                 dungeon_completed: false,
@@ -338,10 +368,22 @@ pub fn menu_dungeon_floor(
                                 next_room_coords: current_room.adjacents.left.clone(),
                             })
                         }
-                        "Enter Shop" => {
+                        "menu.dungeon.enter_shop" => {
                             menu_shop(&mut dungeon_floor.shop_items, player.get_character_mut()?)?;
                         }
-                        "Enter Boss Room" => {
+                        "menu.dungeon.enter_blacksmith" => {
+                            menu_blacksmith(player.get_character_mut()?)?;
+                        }
+                        "menu.dungeon.enter_stash" => {
+                            menu_stash(player)?;
+                        }
+                        "menu.dungeon.check_bounty_board" => {
+                            menu_bounty_board(
+                                &mut dungeon_floor.quest_board,
+                                player.get_character_mut()?,
+                            )?;
+                        }
+                        "menu.dungeon.enter_boss_room" => {
                             return Ok(DungeonFloorMenuOptions {
                                 return_to_main_menu: false,
                                 dungeon_completed: false,
@@ -349,7 +391,7 @@ pub fn menu_dungeon_floor(
                                 next_room_coords: current_room.adjacents.up.clone(),
                             })
                         }
-                        "Enter Next Floor" => {
+                        "menu.dungeon.enter_next_floor" => {
                             return Ok(DungeonFloorMenuOptions {
                                 return_to_main_menu: false,
                                 dungeon_completed: true,
@@ -357,18 +399,38 @@ pub fn menu_dungeon_floor(
                                 next_room_coords: None,
                             })
                         }
-                        "Open Treasure Chest" => {
+                        "menu.dungeon.open_treasure_chest" => {
                             menu_open_treasure_chest(
                                 dungeon_floor.floor,
                                 player.get_character_mut()?,
                                 current_room,
                             )?;
-                            menu_items.remove(selected_index);
-                            selected_index = 0;
+                            menu_items.remove(list.selected_index);
+                            list.selected_index = 0;
+                        }
+                        "menu.dungeon.meet_companion" => {
+                            if let Some(companion) = &current_room.companion {
+                                let character = player.get_character_mut()?;
+                                if menu_companion_offer(companion, character, None)? {
+                                    if let Some(companion) = current_room.companion.take() {
+                                        character.give_companion(companion);
+                                    }
+                                    menu_items.remove(list.selected_index);
+                                    list.selected_index = 0;
+                                }
+                            }
+                        }
+                        "menu.dungeon.visit_wandering_merchant" => {
+                            if let Some(merchant) = &mut dungeon_floor.merchant {
+                                menu_wandering_merchant(merchant, player.get_character_mut()?)?;
+                            }
+                            dungeon_floor.merchant = None;
+                            menu_items.remove(list.selected_index);
+                            list.selected_index = 0;
                         }
                         _ => break,
                     },
-                    KeyCode::Esc => {
+                    code if is_key_action(code, KeyAction::Back) => {
                         if let Ok(return_to_main_menu) = menu_character(player.get_character_mut()?)
                         {
                             if return_to_main_menu {
@@ -379,7 +441,7 @@ pub fn menu_dungeon_floor(
                                     next_room_coords: None,
                                 });
                             } else {
-                                execute!(stdout, Clear(ClearType::All))?;
+                                clear()?;
                             }
                         }
                     }
@@ -402,31 +464,31 @@ pub fn menu_open_treasure_chest(
     character: &mut PlayerCharacter,
     current_room: &mut Room,
 ) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
     let drops = give_treasure_chest_drops(character, dungeon_floor);
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("You opened a treasure chest");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Drops:");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
-        println!("  Gold: {}", drops.gold);
-        execute!(stdout, cursor::MoveTo(0, 3))?;
-        println!("  Items:");
-        execute!(stdout, cursor::MoveTo(0, 4))?;
-        set_rarity_text_color(&drops.equipment_item.rarity)?;
-        print!("    {}", drops.equipment_item.name);
+        move_to(0, 0)?;
+        print_line!("{}", tr!("dungeon.treasure_chest_opened"))?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("fight.drops"))?;
+        move_to(0, 2)?;
+        print_line!("{}", tr!("fight.drops_gold", gold = drops.gold))?;
+        move_to(0, 3)?;
+        print_line!("{}", tr!("fight.drops_items"))?;
+        move_to(0, 4)?;
+        print_text!("    ")?;
+        print_rarity_text(&drops.equipment_item.rarity, &drops.equipment_item.name)?;
         reset_text_color()?;
-        print!(" {}", get_item_level_display(drops.equipment_item.lvl));
-        execute!(stdout, cursor::MoveTo(0, 6))?;
-        println!("> Continue");
+        print_text!(" {}", get_item_level_display(drops.equipment_item.lvl))?;
+        let column = display_ingredient_drops(&drops.ingredients, 5)?;
+        move_to(0, column + 1)?;
+        print_line!("{}", tr!("level_up.continue"))?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Enter => {
+                    code if is_key_action(code, KeyAction::Select) => {
                         break;
                     }
                     _ => {}
@@ -435,210 +497,201 @@ pub fn menu_open_treasure_chest(
         }
     }
     current_room.treasure = false;
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
 
 fn display_start_room(start_column: u16) -> io::Result<u16> {
-    let mut stdout = io::stdout();
-    execute!(stdout, cursor::MoveTo(0, start_column))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 1))?;
-    println!("+----     ----+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 2))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 3))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 4))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 5))?;
-    println!("|    | S |    |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 6))?;
-    println!("+-------------+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 7))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 8))?;
-
-    Ok(start_column + 8)
+    move_to(0, start_column)?;
+    print_line!("")?;
+    move_to(0, start_column + 1)?;
+    print_line!("+----     ----+")?;
+    move_to(0, start_column + 2)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 3)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 4)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 5)?;
+    print_line!("|    | S |    |")?;
+    move_to(0, start_column + 6)?;
+    print_line!("+-------------+")?;
+    move_to(0, start_column + 7)?;
+    print_line!("")?;
+    move_to(0, start_column + 8)?;
+
+    Ok(start_column + ROOM_DISPLAY_ROWS)
 }
 
 fn display_boss_entrance_room(start_column: u16) -> io::Result<u16> {
-    let mut stdout = io::stdout();
-    execute!(stdout, cursor::MoveTo(0, start_column))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 1))?;
-    println!("+-------------+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 2))?;
-    println!("|    | B |    |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 3))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 4))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 5))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 6))?;
-    println!("+----     ----+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 7))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 8))?;
-
-    Ok(start_column + 8)
+    move_to(0, start_column)?;
+    print_line!("")?;
+    move_to(0, start_column + 1)?;
+    print_line!("+-------------+")?;
+    move_to(0, start_column + 2)?;
+    print_line!("|    | B |    |")?;
+    move_to(0, start_column + 3)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 4)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 5)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 6)?;
+    print_line!("+----     ----+")?;
+    move_to(0, start_column + 7)?;
+    print_line!("")?;
+    move_to(0, start_column + 8)?;
+
+    Ok(start_column + ROOM_DISPLAY_ROWS)
 }
 
 fn display_boss_room(start_column: u16) -> io::Result<u16> {
-    let mut stdout = io::stdout();
-    execute!(stdout, cursor::MoveTo(0, start_column + 1))?;
-    println!("+-------------+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 2))?;
-    println!("|    | N |    |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 3))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 4))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 5))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 6))?;
-    println!("+----     ----+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 8))?;
-
-    Ok(start_column + 8)
+    move_to(0, start_column + 1)?;
+    print_line!("+-------------+")?;
+    move_to(0, start_column + 2)?;
+    print_line!("|    | N |    |")?;
+    move_to(0, start_column + 3)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 4)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 5)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 6)?;
+    print_line!("+----     ----+")?;
+    move_to(0, start_column + 8)?;
+
+    Ok(start_column + ROOM_DISPLAY_ROWS)
 }
 
 fn display_two_way_up_down_room(start_column: u16) -> io::Result<u16> {
-    let mut stdout = io::stdout();
-    execute!(stdout, cursor::MoveTo(0, start_column))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 1))?;
-    println!("+----     ----+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 2))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 3))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 4))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 5))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 6))?;
-    println!("+----     ----+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 7))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 8))?;
-
-    Ok(start_column + 8)
+    move_to(0, start_column)?;
+    print_line!("")?;
+    move_to(0, start_column + 1)?;
+    print_line!("+----     ----+")?;
+    move_to(0, start_column + 2)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 3)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 4)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 5)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 6)?;
+    print_line!("+----     ----+")?;
+    move_to(0, start_column + 7)?;
+    print_line!("")?;
+    move_to(0, start_column + 8)?;
+
+    Ok(start_column + ROOM_DISPLAY_ROWS)
 }
 
 fn display_two_way_left_right_room(start_column: u16) -> io::Result<u16> {
-    let mut stdout = io::stdout();
-    execute!(stdout, cursor::MoveTo(0, start_column))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 1))?;
-    println!("+-------------+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 2))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 3))?;
-    println!("               ");
-    execute!(stdout, cursor::MoveTo(0, start_column + 4))?;
-    println!("               ");
-    execute!(stdout, cursor::MoveTo(0, start_column + 5))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 6))?;
-    println!("+-------------+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 7))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 8))?;
-
-    Ok(start_column + 8)
+    move_to(0, start_column)?;
+    print_line!("")?;
+    move_to(0, start_column + 1)?;
+    print_line!("+-------------+")?;
+    move_to(0, start_column + 2)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 3)?;
+    print_line!("               ")?;
+    move_to(0, start_column + 4)?;
+    print_line!("               ")?;
+    move_to(0, start_column + 5)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 6)?;
+    print_line!("+-------------+")?;
+    move_to(0, start_column + 7)?;
+    print_line!("")?;
+    move_to(0, start_column + 8)?;
+
+    Ok(start_column + ROOM_DISPLAY_ROWS)
 }
 
 fn display_two_way_up_left_room(start_column: u16) -> io::Result<u16> {
-    let mut stdout = io::stdout();
-    execute!(stdout, cursor::MoveTo(0, start_column))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 1))?;
-    println!("+----     ----+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 2))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 3))?;
-    println!("              |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 4))?;
-    println!("              |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 5))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 6))?;
-    println!("+-------------+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 7))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 8))?;
-
-    Ok(start_column + 8)
+    move_to(0, start_column)?;
+    print_line!("")?;
+    move_to(0, start_column + 1)?;
+    print_line!("+----     ----+")?;
+    move_to(0, start_column + 2)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 3)?;
+    print_line!("              |")?;
+    move_to(0, start_column + 4)?;
+    print_line!("              |")?;
+    move_to(0, start_column + 5)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 6)?;
+    print_line!("+-------------+")?;
+    move_to(0, start_column + 7)?;
+    print_line!("")?;
+    move_to(0, start_column + 8)?;
+
+    Ok(start_column + ROOM_DISPLAY_ROWS)
 }
 
 fn display_two_way_up_right_room(start_column: u16) -> io::Result<u16> {
-    let mut stdout = io::stdout();
-    execute!(stdout, cursor::MoveTo(0, start_column))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 1))?;
-    println!("+----     ----+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 2))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 3))?;
-    println!("|              ");
-    execute!(stdout, cursor::MoveTo(0, start_column + 4))?;
-    println!("|              ");
-    execute!(stdout, cursor::MoveTo(0, start_column + 5))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 6))?;
-    println!("+-------------+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 7))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 8))?;
-
-    Ok(start_column + 8)
+    move_to(0, start_column)?;
+    print_line!("")?;
+    move_to(0, start_column + 1)?;
+    print_line!("+----     ----+")?;
+    move_to(0, start_column + 2)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 3)?;
+    print_line!("|              ")?;
+    move_to(0, start_column + 4)?;
+    print_line!("|              ")?;
+    move_to(0, start_column + 5)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 6)?;
+    print_line!("+-------------+")?;
+    move_to(0, start_column + 7)?;
+    print_line!("")?;
+    move_to(0, start_column + 8)?;
+
+    Ok(start_column + ROOM_DISPLAY_ROWS)
 }
 
 fn display_two_way_down_left_room(start_column: u16) -> io::Result<u16> {
-    let mut stdout = io::stdout();
-    execute!(stdout, cursor::MoveTo(0, start_column))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 1))?;
-    println!("+-------------+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 2))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 3))?;
-    println!("              |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 4))?;
-    println!("              |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 5))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 6))?;
-    println!("+----     ----+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 7))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 8))?;
-
-    Ok(start_column + 8)
+    move_to(0, start_column)?;
+    print_line!("")?;
+    move_to(0, start_column + 1)?;
+    print_line!("+-------------+")?;
+    move_to(0, start_column + 2)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 3)?;
+    print_line!("              |")?;
+    move_to(0, start_column + 4)?;
+    print_line!("              |")?;
+    move_to(0, start_column + 5)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 6)?;
+    print_line!("+----     ----+")?;
+    move_to(0, start_column + 7)?;
+    print_line!("")?;
+    move_to(0, start_column + 8)?;
+
+    Ok(start_column + ROOM_DISPLAY_ROWS)
 }
 
 fn display_two_way_down_right_room(start_column: u16) -> io::Result<u16> {
-    let mut stdout = io::stdout();
-    execute!(stdout, cursor::MoveTo(0, start_column))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 1))?;
-    println!("+-------------+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 2))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 3))?;
-    println!("|              ");
-    execute!(stdout, cursor::MoveTo(0, start_column + 4))?;
-    println!("|              ");
-    execute!(stdout, cursor::MoveTo(0, start_column + 5))?;
-    println!("|             |");
-    execute!(stdout, cursor::MoveTo(0, start_column + 6))?;
-    println!("+----     ----+");
-    execute!(stdout, cursor::MoveTo(0, start_column + 7))?;
-    println!("");
-    execute!(stdout, cursor::MoveTo(0, start_column + 8))?;
-
-    Ok(start_column + 8)
+    move_to(0, start_column)?;
+    print_line!("")?;
+    move_to(0, start_column + 1)?;
+    print_line!("+-------------+")?;
+    move_to(0, start_column + 2)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 3)?;
+    print_line!("|              ")?;
+    move_to(0, start_column + 4)?;
+    print_line!("|              ")?;
+    move_to(0, start_column + 5)?;
+    print_line!("|             |")?;
+    move_to(0, start_column + 6)?;
+    print_line!("+----     ----+")?;
+    move_to(0, start_column + 7)?;
+    print_line!("")?;
+    move_to(0, start_column + 8)?;
+
+    Ok(start_column + ROOM_DISPLAY_ROWS)
 }
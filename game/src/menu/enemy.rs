@@ -1,42 +1,64 @@
 use crate::{
+    art::{display_art, get_enemy_art, ART_MARGIN},
     character::SKILL_MANA_COST,
+    combat_log::CombatLog,
+    companion::Companion,
     drops::{give_ancient_enemy_drops, give_boss_enemy_drops, give_normal_enemy_drops},
-    enemy::{Enemy, EnemyKind, ENEMY_SKILL_CHANCE},
+    enemy::{Enemy, EnemyKind},
+    fight::{
+        advance_fight, end_fight, enemy_defeated, player_died, start_fight, FightOptions,
+        FightStep, FightTurn,
+    },
+    frontend::{clear, clear_below, move_to},
     game::StatusBar,
+    i18n::translate_all,
     items::{get_item_level_display, ItemRarity},
-    menu::{character::menu_level_up, inventory::menu_inventory_consumable_list},
+    keymap::{is_key_action, KeyAction},
+    layout::{get_rows_left, get_text_width, print_wrapped},
+    menu::{
+        alchemy::display_ingredient_drops,
+        character::menu_level_up,
+        inventory::menu_inventory_consumable_list,
+        stats::display_special_stats,
+        widget::{draw_key_hints, SelectList},
+    },
+    print_line, print_text,
+    replay::read_event,
     session::PlayerCharacter,
-    util::{is_chance_success, reset_text_color, set_rarity_text_color},
-};
-use crossterm::{
-    cursor,
-    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
-    execute,
-    style::{Color, SetForegroundColor},
-    terminal::{Clear, ClearType},
+    theme::{uses_colors, ThemeColor},
+    tr,
+    util::{print_rarity_text, reset_text_color, set_text_color},
 };
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
 use std::io;
 
-const DEFAULT_FIGHT_TEXT: &str = "Select what to do...";
+const DEFAULT_FIGHT_TEXT: &str = "fight.select_action";
+const STATUS_BAR_LENGTH: usize = 25;
+const MIN_STATUS_BAR_LENGTH: usize = 10;
+/// Title, one event and the key hints.
+const MIN_COMBAT_LOG_ROWS: usize = 3;
 
 /// Returns true if the player wins the fight.
 pub fn menu_enemy_encounter(
     enemy: &mut Enemy,
     character: &mut PlayerCharacter,
+    options: &FightOptions,
 ) -> io::Result<bool> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("Encountered enemy {}", enemy.get_display_name());
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("> Fight");
+        move_to(0, 0)?;
+        print_line!(
+            "{}",
+            tr!("fight.encountered_enemy", enemy = enemy.get_display_name())
+        )?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("fight.select_fight"))?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Enter => {
+                    code if is_key_action(code, KeyAction::Select) => {
                         break;
                     }
                     _ => {}
@@ -44,34 +66,39 @@ pub fn menu_enemy_encounter(
             }
         }
     }
-    let victory = menu_enemy_fight(enemy, character)?;
-    execute!(stdout, Clear(ClearType::All))?;
+    let victory = menu_enemy_fight(enemy, character, options)?;
+    clear()?;
 
     Ok(victory)
 }
 
 /// Returns true if the player wins the fight.
-fn menu_enemy_fight(enemy: &mut Enemy, character: &mut PlayerCharacter) -> io::Result<bool> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+fn menu_enemy_fight(
+    enemy: &mut Enemy,
+    character: &mut PlayerCharacter,
+    options: &FightOptions,
+) -> io::Result<bool> {
+    clear()?;
 
-    let mut selected_index = 0;
-    let mut fight_text = DEFAULT_FIGHT_TEXT.to_string();
+    let mut list = SelectList::new();
+    let mut fight_text = tr!(DEFAULT_FIGHT_TEXT);
     let mut effect_text = "".to_string();
     let mut used_item = "".to_string();
     let mut used_item_rarity = ItemRarity::Unknown;
     let mut action = false;
-    let mut player_turn = true;
-    let player_temp_stat_boosts = character.temp_stat_boosts.clone();
-
-    // fully heal player at the start of fights
-    character.restore_health(character.get_total_health());
-    character.restore_mana(character.get_total_mana());
+    let mut turn = FightTurn::Player;
+    let player_temp_stat_boosts = start_fight(character, options);
+    let mut combat_log = CombatLog::new(enemy, character);
 
     loop {
-        let mut menu_items = vec!["Attack", "Use Skill", "Consumables", "Stats"];
+        let mut menu_items = vec![
+            "menu.fight.attack",
+            "menu.fight.use_skill",
+            "menu.fight.consumables",
+            "menu.fight.stats",
+        ];
         if action {
-            menu_items = vec!["Continue"];
+            menu_items = vec!["menu.continue"];
         }
         let enemy_curr_health = enemy.stats.current_health;
         let enemy_max_health = enemy.stats.max_health;
@@ -80,230 +107,317 @@ fn menu_enemy_fight(enemy: &mut Enemy, character: &mut PlayerCharacter) -> io::R
         let player_curr_mana = character.temp_stats.current_mana;
         let player_max_mana = character.get_total_mana();
 
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("Enemy: {}", enemy.get_display_name());
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        display_health_bar(
+        let enemy_text = tr!("fight.enemy", enemy = enemy.get_display_name());
+        let player_text = tr!(
+            "fight.player",
+            name = character.data.metadata.name,
+            level = character.data.stats.general_stats.character_level,
+            class = character.data.metadata.class,
+            exp = character.data.stats.general_stats.current_exp,
+            required_exp = character.data.stats.general_stats.required_exp
+        );
+        // the widest texts are the names and the status bars
+        let mut stats_width = enemy_text.chars().count().max(player_text.chars().count());
+
+        move_to(0, 0)?;
+        print_line!("{}", enemy_text)?;
+        move_to(0, 1)?;
+        stats_width = stats_width.max(display_health_bar(
             ((enemy_curr_health as f64 / enemy_max_health as f64) * 100.0).ceil() as u16,
             enemy_curr_health,
             enemy_max_health,
+        )?);
+        move_to(0, 2)?;
+        print_line!(
+            "{}",
+            tr!("stats.defense", value = enemy.get_total_defense())
         )?;
-        execute!(stdout, cursor::MoveTo(0, 2))?;
-        println!("Defense: {}", enemy.get_total_defense());
-
-        execute!(stdout, cursor::MoveTo(0, 4))?;
-        println!(
-            "Player: {} (Level {} {:?}, EXP: {}/{})",
-            character.data.metadata.name,
-            character.data.stats.general_stats.character_level,
-            character.data.metadata.class,
-            character.data.stats.general_stats.current_exp,
-            character.data.stats.general_stats.required_exp
-        );
-        execute!(stdout, cursor::MoveTo(0, 5))?;
-        display_health_bar(
+
+        move_to(0, 4)?;
+        print_line!("{}", player_text)?;
+        move_to(0, 5)?;
+        stats_width = stats_width.max(display_health_bar(
             ((player_curr_health as f64 / player_max_health as f64) * 100.0).ceil() as u16,
             player_curr_health,
             player_max_health,
-        )?;
-        execute!(stdout, cursor::MoveTo(0, 6))?;
-        display_mana_bar(
+        )?);
+        move_to(0, 6)?;
+        stats_width = stats_width.max(display_mana_bar(
             ((player_curr_mana as f64 / player_max_mana as f64) * 100.0).ceil() as u16,
             player_curr_mana,
             player_max_mana,
+        )?);
+        move_to(0, 7)?;
+        print_line!(
+            "{}",
+            tr!("stats.defense", value = character.get_total_defense())
         )?;
-        execute!(stdout, cursor::MoveTo(0, 7))?;
-        println!("Defense: {}", character.get_total_defense());
+        if let Some(art) = get_enemy_art(enemy.name) {
+            display_art(art, 0, stats_width as u16 + ART_MARGIN)?;
+        }
 
-        execute!(stdout, cursor::MoveTo(0, 9))?;
+        let text_column = match &character.data.companion {
+            Some(companion) => display_companion_panel(companion, 9)? + 1,
+            None => 9,
+        };
+        let mut start_column = text_column;
         if !used_item.is_empty() {
-            print!("Player used ");
-            set_rarity_text_color(&used_item_rarity)?;
-            print!("{}", used_item);
+            move_to(0, text_column)?;
+            print_text!("{}", tr!("fight.player_used_item_prefix"))?;
+            print_rarity_text(&used_item_rarity, &used_item)?;
             reset_text_color()?;
-            print!("!");
+            print_text!("{}", tr!("fight.player_used_item_suffix"))?;
             used_item = "".to_string();
             used_item_rarity = ItemRarity::Unknown;
+            start_column += 1;
         } else {
-            println!("{}", fight_text);
+            start_column += print_wrapped(text_column, "", &fight_text)?;
         }
-
-        let start_column: u16 = match action {
-            true => {
-                execute!(stdout, cursor::MoveTo(0, 10))?;
-                println!("{}", effect_text);
-                11
-            }
-            _ => 10,
-        };
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
-            } else {
-                println!("  {}", item);
-            }
+        if action {
+            start_column += print_wrapped(start_column, "", &effect_text)?;
         }
+        list.draw(start_column, &translate_all(&menu_items))?;
+        let combat_log_rows =
+            display_combat_log(&mut combat_log, start_column + menu_items.len() as u16 + 1)?.max(1);
+        // the texts can take fewer rows than on the last draw
+        clear_below()?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::ScrollUp) => {
+                        combat_log.scroll_up(combat_log_rows);
+                        clear()?;
                     }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
+                    code if is_key_action(code, KeyAction::ScrollDown) => {
+                        combat_log.scroll_down(combat_log_rows);
+                        clear()?;
                     }
-                    KeyCode::Enter => match menu_items[selected_index] {
-                        "Attack" => {
-                            action = true;
-                            let (event, effect) = character.attack_enemy(enemy);
-                            fight_text = event.to_string();
-                            effect_text = effect;
-                            selected_index = 0;
-                            execute!(stdout, Clear(ClearType::All))?;
-                        }
-                        "Use Skill" => {
-                            if character.has_enough_mana_for_skill() {
+                    code if list.is_select(code, menu_items.len()) => {
+                        match menu_items[list.selected_index] {
+                            "menu.fight.attack" => {
                                 action = true;
-                                (fight_text, effect_text) = character.use_skill(enemy);
-                                selected_index = 0;
-                                execute!(stdout, Clear(ClearType::All))?;
-                            } else {
-                                fight_text = format!(
-                                    "Not enough mana to use skill ({} required)",
-                                    SKILL_MANA_COST
-                                );
-                            }
-                        }
-                        "Consumables" => {
-                            let (item_name, item_rarity, effect) =
-                                menu_inventory_consumable_list(character, true, false)?;
-                            if !item_name.is_empty() && !effect.is_empty() {
-                                action = true;
-                                used_item = item_name;
-                                used_item_rarity = item_rarity;
+                                let (event, effect) = character.attack_enemy(enemy);
+                                combat_log.add_action(&event, &effect);
+                                fight_text = event;
                                 effect_text = effect;
-                                selected_index = 0;
-                                execute!(stdout, Clear(ClearType::All))?;
-                            } else {
-                                fight_text = DEFAULT_FIGHT_TEXT.to_string();
-                                effect_text = "".to_string();
-                                used_item = "".to_string();
-                                used_item_rarity = ItemRarity::Unknown;
+                                list.selected_index = 0;
+                                clear()?;
                             }
-                        }
-                        "Stats" => {
-                            menu_enemy_fight_character_stats(character)?;
-                            fight_text = DEFAULT_FIGHT_TEXT.to_string();
-                            effect_text = "".to_string();
-                        }
-                        "Continue" => {
-                            if enemy.is_dead() {
-                                let character_level =
-                                    character.data.stats.general_stats.character_level;
-                                match enemy.kind {
-                                    EnemyKind::Normal => {
-                                        menu_normal_enemy_fight_victory(enemy.level, character)?;
-                                    }
-                                    EnemyKind::Boss => {
-                                        menu_boss_enemy_fight_victory(enemy.level, character)?;
-                                    }
-                                    EnemyKind::Ancient => {
-                                        menu_ancient_enemy_fight_victory(enemy.level, character)?;
-                                    }
+                            "menu.fight.use_skill" => {
+                                if character.has_enough_mana_for_skill() {
+                                    action = true;
+                                    (fight_text, effect_text) = character.use_skill(enemy);
+                                    combat_log.add_action(&fight_text, &effect_text);
+                                    list.selected_index = 0;
+                                    clear()?;
+                                } else {
+                                    fight_text =
+                                        tr!("fight.not_enough_mana", required = SKILL_MANA_COST);
                                 }
-                                if character.data.stats.general_stats.character_level
-                                    > character_level
-                                {
-                                    menu_level_up(
-                                        character.data.stats.general_stats.character_level,
-                                    )?;
+                            }
+                            "menu.fight.consumables" => {
+                                let (item_name, item_rarity, effect) =
+                                    menu_inventory_consumable_list(character, Some(enemy), false)?;
+                                if !item_name.is_empty() && !effect.is_empty() {
+                                    action = true;
+                                    combat_log.add_action(&get_used_item_text(&item_name), &effect);
+                                    used_item = item_name;
+                                    used_item_rarity = item_rarity;
+                                    effect_text = effect;
+                                    list.selected_index = 0;
+                                    clear()?;
+                                } else {
+                                    fight_text = tr!(DEFAULT_FIGHT_TEXT);
+                                    effect_text = "".to_string();
+                                    used_item = "".to_string();
+                                    used_item_rarity = ItemRarity::Unknown;
                                 }
-                                character.temp_stat_boosts = player_temp_stat_boosts;
-                                return Ok(true);
                             }
-                            if player_turn {
-                                player_turn = false;
-                                match enemy.kind {
-                                    EnemyKind::Boss | EnemyKind::Ancient => {
-                                        if is_chance_success(ENEMY_SKILL_CHANCE) {
-                                            let (event, effect) = enemy.use_skill(character);
-                                            fight_text = event.to_string();
-                                            effect_text = effect;
-                                        } else {
-                                            let (event, effect) = enemy.attack_player(character);
-                                            fight_text = event.to_string();
-                                            effect_text = effect;
+                            "menu.fight.stats" => {
+                                menu_enemy_fight_character_stats(character)?;
+                                fight_text = tr!(DEFAULT_FIGHT_TEXT);
+                                effect_text = "".to_string();
+                            }
+                            "menu.continue" => match advance_fight(enemy, character, &mut turn) {
+                                FightStep::Victory => {
+                                    combat_log.add(&tr!("fight.victory"));
+                                    combat_log.write_to_file()?;
+                                    if options.rewards {
+                                        enemy_defeated(enemy, character);
+                                        let character_level =
+                                            character.data.stats.general_stats.character_level;
+                                        match enemy.kind {
+                                            EnemyKind::Normal => {
+                                                menu_normal_enemy_fight_victory(
+                                                    enemy.level,
+                                                    character,
+                                                )?;
+                                            }
+                                            EnemyKind::Boss => {
+                                                menu_boss_enemy_fight_victory(
+                                                    enemy.level,
+                                                    enemy.name,
+                                                    character,
+                                                )?;
+                                            }
+                                            EnemyKind::Ancient => {
+                                                menu_ancient_enemy_fight_victory(
+                                                    enemy.level,
+                                                    character,
+                                                )?;
+                                            }
+                                        }
+                                        if character.data.stats.general_stats.character_level
+                                            > character_level
+                                        {
+                                            menu_level_up(
+                                                character.data.stats.general_stats.character_level,
+                                            )?;
                                         }
                                     }
-                                    _ => {
-                                        let (event, effect) = enemy.attack_player(character);
-                                        fight_text = event.to_string();
-                                        effect_text = effect;
-                                    }
+                                    end_fight(character, player_temp_stat_boosts);
+                                    return Ok(true);
                                 }
-                            } else {
-                                if character.is_dead() {
-                                    character.increase_deaths();
-                                    menu_enemy_fight_player_died(character)?;
-                                    character.temp_stat_boosts = player_temp_stat_boosts;
+                                FightStep::Defeat => {
+                                    combat_log.add(&tr!("combat_log.defeat"));
+                                    combat_log.write_to_file()?;
+                                    if options.character_death {
+                                        player_died(character);
+                                        menu_enemy_fight_player_died(character)?;
+                                    }
+                                    end_fight(character, player_temp_stat_boosts);
                                     return Ok(false);
                                 }
-                                action = false;
-                                player_turn = true;
-                                fight_text = DEFAULT_FIGHT_TEXT.to_string();
-                                effect_text = "".to_string();
-                            }
-                            execute!(stdout, Clear(ClearType::All))?;
+                                FightStep::Action(event, effect) => {
+                                    combat_log.add_action(&event, &effect);
+                                    fight_text = event;
+                                    effect_text = effect;
+                                    clear()?;
+                                }
+                                FightStep::Resurrection(item_name, item_rarity, effect) => {
+                                    combat_log.add_action(&get_used_item_text(&item_name), &effect);
+                                    used_item = item_name;
+                                    used_item_rarity = item_rarity;
+                                    effect_text = effect;
+                                    clear()?;
+                                }
+                                FightStep::PlayerTurn(restored_mana) => {
+                                    combat_log.next_turn();
+                                    action = false;
+                                    fight_text = tr!(DEFAULT_FIGHT_TEXT);
+                                    effect_text = "".to_string();
+                                    if restored_mana > 0 {
+                                        combat_log.add(&tr!(
+                                            "combat_log.mana_regen",
+                                            mana = restored_mana
+                                        ));
+                                        fight_text = tr!(
+                                            "fight.mana_regen",
+                                            mana = restored_mana,
+                                            text = tr!(DEFAULT_FIGHT_TEXT)
+                                        );
+                                    }
+                                    clear()?;
+                                }
+                            },
+                            _ => break,
                         }
-                        _ => break,
-                    },
+                    }
                     _ => {}
                 }
             }
         }
     }
-    character.temp_stat_boosts = player_temp_stat_boosts;
+    end_fight(character, player_temp_stat_boosts);
 
     Ok(false)
 }
 
+/// Text of an item used in a fight, e.g. `Player used Health Potion!`.
+fn get_used_item_text(item_name: &str) -> String {
+    format!(
+        "{}{}{}",
+        tr!("fight.player_used_item_prefix"),
+        item_name,
+        tr!("fight.player_used_item_suffix")
+    )
+}
+
+/// Displays the newest events of the fight in the rows left on the screen.
+/// Returns the number of rows for events. Nothing is displayed if the rows are too few.
+fn display_combat_log(combat_log: &mut CombatLog, row: u16) -> io::Result<usize> {
+    let rows = get_rows_left(row)?;
+    if rows < MIN_COMBAT_LOG_ROWS {
+        return Ok(0);
+    }
+    let event_rows = rows - 2;
+    let lines = combat_log.get_lines(get_text_width()?.saturating_sub(2));
+    let visible_lines = combat_log.get_visible_lines(lines.len(), event_rows);
+
+    move_to(0, row)?;
+    if visible_lines.len() < lines.len() {
+        print_line!(
+            "{}",
+            tr!(
+                "combat_log.title_position",
+                first = visible_lines.start + 1,
+                last = visible_lines.end,
+                len = lines.len()
+            )
+        )?;
+    } else {
+        print_line!("{}", tr!("combat_log.title"))?;
+    }
+    for (i, line) in lines[visible_lines.clone()].iter().enumerate() {
+        move_to(0, row + 1 + i as u16)?;
+        print_line!("  {}", line)?;
+    }
+    if visible_lines.len() < lines.len() {
+        draw_key_hints(
+            row + 1 + visible_lines.len() as u16,
+            &[
+                (KeyAction::ScrollUp, "hint.scroll_up"),
+                (KeyAction::ScrollDown, "hint.scroll_down"),
+            ],
+        )?;
+    }
+
+    Ok(event_rows)
+}
+
 fn menu_normal_enemy_fight_victory(
     enemy_level: u32,
     character: &mut PlayerCharacter,
 ) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
     let drops = give_normal_enemy_drops(character, enemy_level);
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("You defeated the enemy!");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Drops:");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
-        println!("  Gold: {}", drops.gold);
-        execute!(stdout, cursor::MoveTo(0, 3))?;
-        println!("  EXP: {}", drops.exp);
-        execute!(stdout, cursor::MoveTo(0, 4))?;
-        println!("  Items:");
-        execute!(stdout, cursor::MoveTo(0, 5))?;
-        set_rarity_text_color(&drops.equipment_item.rarity)?;
-        print!("    {}", drops.equipment_item.name);
+        move_to(0, 0)?;
+        print_line!("{}", tr!("fight.victory"))?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("fight.drops"))?;
+        move_to(0, 2)?;
+        print_line!("{}", tr!("fight.drops_gold", gold = drops.gold))?;
+        move_to(0, 3)?;
+        print_line!("{}", tr!("fight.drops_exp", exp = drops.exp))?;
+        move_to(0, 4)?;
+        print_line!("{}", tr!("fight.drops_items"))?;
+        move_to(0, 5)?;
+        print_text!("    ")?;
+        print_rarity_text(&drops.equipment_item.rarity, &drops.equipment_item.name)?;
         reset_text_color()?;
-        print!(" {}", get_item_level_display(drops.equipment_item.lvl));
-        execute!(stdout, cursor::MoveTo(0, 7))?;
-        println!("> Continue");
+        print_text!(" {}", get_item_level_display(drops.equipment_item.lvl))?;
+        let column = display_ingredient_drops(&drops.ingredients, 6)?;
+        move_to(0, column + 1)?;
+        print_line!("{}", tr!("level_up.continue"))?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Enter => {
+                    code if is_key_action(code, KeyAction::Select) => {
                         break;
                     }
                     _ => {}
@@ -311,58 +425,59 @@ fn menu_normal_enemy_fight_victory(
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
 
 fn menu_boss_enemy_fight_victory(
     enemy_level: u32,
+    enemy_name: &str,
     character: &mut PlayerCharacter,
 ) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
-    let drops = give_boss_enemy_drops(character, enemy_level);
+    clear()?;
+    let drops = give_boss_enemy_drops(character, enemy_level, enemy_name);
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("You defeated the enemy!");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Drops:");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
-        println!("  Gold: {}", drops.gold);
-        execute!(stdout, cursor::MoveTo(0, 3))?;
-        println!("  EXP: {}", drops.exp);
-        execute!(stdout, cursor::MoveTo(0, 4))?;
-        println!("  Items:");
-        execute!(stdout, cursor::MoveTo(0, 5))?;
-        set_rarity_text_color(&drops.consumable_item.rarity)?;
-        print!("    {}", drops.consumable_item.name);
+        move_to(0, 0)?;
+        print_line!("{}", tr!("fight.victory"))?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("fight.drops"))?;
+        move_to(0, 2)?;
+        print_line!("{}", tr!("fight.drops_gold", gold = drops.gold))?;
+        move_to(0, 3)?;
+        print_line!("{}", tr!("fight.drops_exp", exp = drops.exp))?;
+        move_to(0, 4)?;
+        print_line!("{}", tr!("fight.drops_items"))?;
+        move_to(0, 5)?;
+        print_text!("    ")?;
+        print_rarity_text(&drops.consumable_item.rarity, &drops.consumable_item.name)?;
         reset_text_color()?;
-        print!(" x{}", drops.consumable_item_amount);
-        execute!(stdout, cursor::MoveTo(0, 6))?;
+        print_text!(" x{}", drops.consumable_item_amount)?;
+        move_to(0, 6)?;
 
         let mut column = 6;
         for item in &drops.equipment_items {
-            set_rarity_text_color(&item.rarity)?;
-            print!("    {}", item.name);
+            print_text!("    ")?;
+            print_rarity_text(&item.rarity, &item.name)?;
             reset_text_color()?;
-            print!(" {}", get_item_level_display(item.lvl));
+            print_text!(" {}", get_item_level_display(item.lvl))?;
             column += 1;
-            execute!(stdout, cursor::MoveTo(0, column))?;
+            move_to(0, column)?;
             reset_text_color()?;
         }
         if drops.ancient_ruins_key {
             column += 1;
-            println!("    Ancient Ruins Key x1");
+            print_line!("{}", tr!("fight.ancient_ruins_key_drop"))?;
         }
-        execute!(stdout, cursor::MoveTo(0, column + 1))?;
-        println!("> Continue");
+        column = display_ingredient_drops(&drops.ingredients, column)?;
+        move_to(0, column + 1)?;
+        print_line!("{}", tr!("level_up.continue"))?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Enter => {
+                    code if is_key_action(code, KeyAction::Select) => {
                         break;
                     }
                     _ => {}
@@ -370,7 +485,7 @@ fn menu_boss_enemy_fight_victory(
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
@@ -379,38 +494,38 @@ fn menu_ancient_enemy_fight_victory(
     enemy_level: u32,
     character: &mut PlayerCharacter,
 ) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
     let drops = give_ancient_enemy_drops(character, enemy_level);
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("You defeated the enemy!");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Drops:");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
-        println!("  Gold: {}", drops.gold);
-        execute!(stdout, cursor::MoveTo(0, 3))?;
-        println!("  EXP: {}", drops.exp);
-        execute!(stdout, cursor::MoveTo(0, 4))?;
-        println!("  Items:");
-        execute!(stdout, cursor::MoveTo(0, 5))?;
-        set_rarity_text_color(&drops.consumable_item.rarity)?;
-        print!("    {}", drops.consumable_item.name);
+        move_to(0, 0)?;
+        print_line!("{}", tr!("fight.victory"))?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("fight.drops"))?;
+        move_to(0, 2)?;
+        print_line!("{}", tr!("fight.drops_gold", gold = drops.gold))?;
+        move_to(0, 3)?;
+        print_line!("{}", tr!("fight.drops_exp", exp = drops.exp))?;
+        move_to(0, 4)?;
+        print_line!("{}", tr!("fight.drops_items"))?;
+        move_to(0, 5)?;
+        print_text!("    ")?;
+        print_rarity_text(&drops.consumable_item.rarity, &drops.consumable_item.name)?;
         reset_text_color()?;
-        print!(" x{}", drops.consumable_item_amount);
-        execute!(stdout, cursor::MoveTo(0, 6))?;
-        set_rarity_text_color(&drops.equipment_item.rarity)?;
-        print!("    {}", drops.equipment_item.name);
+        print_text!(" x{}", drops.consumable_item_amount)?;
+        move_to(0, 6)?;
+        print_text!("    ")?;
+        print_rarity_text(&drops.equipment_item.rarity, &drops.equipment_item.name)?;
         reset_text_color()?;
-        print!(" {}", get_item_level_display(drops.equipment_item.lvl));
-        execute!(stdout, cursor::MoveTo(0, 8))?;
-        println!("> Continue");
+        print_text!(" {}", get_item_level_display(drops.equipment_item.lvl))?;
+        let column = display_ingredient_drops(&drops.ingredients, 7)?;
+        move_to(0, column + 1)?;
+        print_line!("{}", tr!("level_up.continue"))?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Enter => {
+                    code if is_key_action(code, KeyAction::Select) => {
                         break;
                     }
                     _ => {}
@@ -418,86 +533,77 @@ fn menu_ancient_enemy_fight_victory(
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
 
-pub fn menu_enemy_fight_player_died(character: &mut PlayerCharacter) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
-
-    let current_level = character.data.stats.general_stats.character_level;
-    let current_floor = character.data.stats.general_stats.current_dungeon_floor;
-    if current_level
-        > character
-            .data
-            .stats
-            .general_stats
-            .highest_character_level_achieved
-    {
-        character
-            .data
-            .stats
-            .general_stats
-            .highest_character_level_achieved = current_level
-    }
-    if current_floor
-        > character
-            .data
-            .stats
-            .general_stats
-            .highest_dungeon_floor_achieved
-    {
-        character
-            .data
-            .stats
-            .general_stats
-            .highest_dungeon_floor_achieved = current_floor;
-    }
+pub fn menu_enemy_fight_player_died(character: &PlayerCharacter) -> io::Result<()> {
+    clear()?;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("You Died!");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Character: {}", character.data.metadata.name);
-        execute!(stdout, cursor::MoveTo(0, 2))?;
-        println!(
-            "Level: {}",
-            character.data.stats.general_stats.character_level
-        );
-        execute!(stdout, cursor::MoveTo(0, 3))?;
-        println!(
-            "Highest Level Reached: {}",
-            character
-                .data
-                .stats
-                .general_stats
-                .highest_character_level_achieved
-        );
-        execute!(stdout, cursor::MoveTo(0, 4))?;
-        println!(
-            "Dungeon Floor: {}",
-            character.data.stats.general_stats.current_dungeon_floor
-        );
-        execute!(stdout, cursor::MoveTo(0, 5))?;
-        println!(
-            "Highest Dungeon Floor Reached: {}",
-            character
-                .data
-                .stats
-                .general_stats
-                .highest_dungeon_floor_achieved
-        );
-        execute!(stdout, cursor::MoveTo(0, 6))?;
-        println!("Deaths: {}", character.data.stats.general_stats.deaths);
-        execute!(stdout, cursor::MoveTo(0, 8))?;
-        println!("> Continue");
+        move_to(0, 0)?;
+        print_line!("{}", tr!("fight.died"))?;
+        move_to(0, 1)?;
+        print_line!(
+            "{}",
+            tr!("fight.died_character", name = character.data.metadata.name)
+        )?;
+        move_to(0, 2)?;
+        print_line!(
+            "{}",
+            tr!(
+                "stats.level",
+                level = character.data.stats.general_stats.character_level
+            )
+        )?;
+        move_to(0, 3)?;
+        print_line!(
+            "{}",
+            tr!(
+                "stats.highest_level",
+                level = character
+                    .data
+                    .stats
+                    .general_stats
+                    .highest_character_level_achieved
+            )
+        )?;
+        move_to(0, 4)?;
+        print_line!(
+            "{}",
+            tr!(
+                "fight.died_dungeon_floor",
+                floor = character.data.stats.general_stats.current_dungeon_floor
+            )
+        )?;
+        move_to(0, 5)?;
+        print_line!(
+            "{}",
+            tr!(
+                "stats.highest_dungeon_floor",
+                floor = character
+                    .data
+                    .stats
+                    .general_stats
+                    .highest_dungeon_floor_achieved
+            )
+        )?;
+        move_to(0, 6)?;
+        print_line!(
+            "{}",
+            tr!(
+                "fight.died_deaths",
+                deaths = character.data.stats.general_stats.deaths
+            )
+        )?;
+        move_to(0, 8)?;
+        print_line!("{}", tr!("level_up.continue"))?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Enter => {
+                    code if is_key_action(code, KeyAction::Select) => {
                         break;
                     }
                     _ => {}
@@ -505,52 +611,69 @@ pub fn menu_enemy_fight_player_died(character: &mut PlayerCharacter) -> io::Resu
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
 
 fn menu_enemy_fight_character_stats(character: &PlayerCharacter) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("(Esc) = Back");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Combat Stats");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
-        println!(
-            "  Health: {}/{}",
-            character.temp_stats.current_health,
-            character.get_total_health()
-        );
-        execute!(stdout, cursor::MoveTo(0, 3))?;
-        println!(
-            "  Mana: {}/{}",
-            character.temp_stats.current_mana,
-            character.get_total_mana()
-        );
-        execute!(stdout, cursor::MoveTo(0, 4))?;
-        println!("  Defense: {}", character.get_total_defense());
-        execute!(stdout, cursor::MoveTo(0, 5))?;
-        println!("  Damage: {}", character.get_total_damage());
-        execute!(stdout, cursor::MoveTo(0, 6))?;
-        println!(
-            "  Critical Damage Multiplier: {:.2}",
-            character.get_total_crit_damage_multiplier()
-        );
-        execute!(stdout, cursor::MoveTo(0, 7))?;
-        println!(
-            "  Critical Hit Rate: {:.2} ({:.2}%)",
-            character.get_total_crit_hit_rate(),
-            character.get_total_crit_hit_rate() * 100.0
-        );
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("tutorial.combat_stats.title"))?;
+        move_to(0, 2)?;
+        print_line!(
+            "  {}",
+            tr!(
+                "stats.health_current",
+                current = character.temp_stats.current_health,
+                max = character.get_total_health()
+            )
+        )?;
+        move_to(0, 3)?;
+        print_line!(
+            "  {}",
+            tr!(
+                "stats.mana_current",
+                current = character.temp_stats.current_mana,
+                max = character.get_total_mana()
+            )
+        )?;
+        move_to(0, 4)?;
+        print_line!(
+            "  {}",
+            tr!("stats.defense", value = character.get_total_defense())
+        )?;
+        move_to(0, 5)?;
+        print_line!(
+            "  {}",
+            tr!("stats.damage", value = character.get_total_damage())
+        )?;
+        move_to(0, 6)?;
+        print_line!(
+            "  {}",
+            tr!(
+                "stats.critical_damage_multiplier",
+                value = format!("{:.2}", character.get_total_crit_damage_multiplier())
+            )
+        )?;
+        move_to(0, 7)?;
+        print_line!(
+            "  {}",
+            tr!(
+                "stats.critical_hit_rate_percent",
+                value = format!("{:.2}", character.get_total_crit_hit_rate()),
+                percent = format!("{:.2}", character.get_total_crit_hit_rate() * 100.0)
+            )
+        )?;
+        display_special_stats(character, 8, "  ")?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Esc => {
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
                     _ => {}
@@ -558,29 +681,64 @@ fn menu_enemy_fight_character_stats(character: &PlayerCharacter) -> io::Result<(
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
 
-fn display_health_bar(percentage: u16, current_health: u32, max_health: u32) -> io::Result<()> {
-    display_status_bar(StatusBar::Health, percentage, current_health, max_health)?;
-    Ok(())
+/// Returns the next column after the panel.
+fn display_companion_panel(companion: &Companion, start_column: u16) -> io::Result<u16> {
+    move_to(0, start_column)?;
+    print_text!(
+        "{}",
+        tr!(
+            "fight.companion",
+            companion = companion.get_display_name(),
+            exp = companion.current_exp,
+            required_exp = companion.required_exp
+        )
+    )?;
+    if companion.is_knocked_out() {
+        print_text!("{}", tr!("fight.companion_knocked_out_tag"))?;
+    } else if companion.taunting {
+        print_text!("{}", tr!("fight.companion_taunting_tag"))?;
+    }
+    move_to(0, start_column + 1)?;
+    display_health_bar(
+        ((companion.stats.current_health as f64 / companion.stats.max_health as f64) * 100.0).ceil()
+            as u16,
+        companion.stats.current_health,
+        companion.stats.max_health,
+    )?;
+
+    Ok(start_column + 2)
 }
 
-fn display_mana_bar(percentage: u16, current_mana: u32, max_mana: u32) -> io::Result<()> {
-    display_status_bar(StatusBar::Mana, percentage, current_mana, max_mana)?;
-    Ok(())
+/// Returns the width of the bar with its label.
+fn display_health_bar(percentage: u16, current_health: u32, max_health: u32) -> io::Result<usize> {
+    display_status_bar(StatusBar::Health, percentage, current_health, max_health)
+}
+
+/// Returns the width of the bar with its label.
+fn display_mana_bar(percentage: u16, current_mana: u32, max_mana: u32) -> io::Result<usize> {
+    display_status_bar(StatusBar::Mana, percentage, current_mana, max_mana)
 }
 
+/// Returns the width of the bar with its label.
 fn display_status_bar(
     bar: StatusBar,
     percentage: u16,
     current_val: u32,
     max_val: u32,
-) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    let bar_length = 25;
+) -> io::Result<usize> {
+    let label = match bar {
+        StatusBar::Health => tr!("stats.health_current", current = current_val, max = max_val),
+        StatusBar::Mana => tr!("stats.mana_current", current = current_val, max = max_val),
+    } + " [";
+    // shorter bars in narrow terminals
+    let bar_length = get_text_width()?
+        .saturating_sub(label.chars().count() + 1)
+        .clamp(MIN_STATUS_BAR_LENGTH, STATUS_BAR_LENGTH);
     let mut filled_length = (percentage as usize * bar_length) / 100;
     let mut empty_length = bar_length - filled_length;
 
@@ -591,28 +749,18 @@ fn display_status_bar(
         }
     }
 
+    print_text!("{}", label)?;
     match bar {
-        StatusBar::Health => {
-            print!("Health: {}/{} [", current_val, max_val);
-            execute!(stdout, SetForegroundColor(Color::Red))?;
-        }
-        StatusBar::Mana => {
-            print!("Mana: {}/{} [", current_val, max_val);
-            execute!(stdout, SetForegroundColor(Color::DarkCyan))?;
-        }
+        StatusBar::Health => set_text_color(ThemeColor::Health)?,
+        StatusBar::Mana => set_text_color(ThemeColor::Mana)?,
     }
-    print!("{}", "=".repeat(filled_length));
-    execute!(
-        stdout,
-        SetForegroundColor(Color::Rgb {
-            r: 50,
-            g: 50,
-            b: 50
-        })
-    )?;
-    print!("{}", "=".repeat(empty_length));
+    print_text!("{}", "=".repeat(filled_length))?;
+    set_text_color(ThemeColor::BarEmpty)?;
+    // the empty part can't be told apart by color without colors
+    let empty_char = if uses_colors() { "=" } else { "-" };
+    print_text!("{}", empty_char.repeat(empty_length))?;
     reset_text_color()?;
-    print!("]");
+    print_text!("]")?;
 
-    Ok(())
+    Ok(label.chars().count() + bar_length + 1)
 }
@@ -1,31 +1,42 @@
 use crate::{
-    items::{get_item_display_name, get_item_level_display, CharacterItem, ItemRarity},
-    menu::inventory::{menu_armor_info, menu_ring_info, menu_weapon_info},
+    frontend::{clear, move_to},
+    items::{
+        get_enchantment_display, get_item_display_name, get_item_level_display, CharacterItem,
+        ItemRarity, ITEM_SET_PIECES,
+    },
+    keymap::{is_key_action, KeyAction},
+    menu::{
+        inventory::{menu_armor_info, menu_ring_info, menu_weapon_info},
+        widget::{draw_key_hints, SelectList},
+    },
+    print_line, print_text,
+    replay::read_event,
     session::PlayerCharacter,
-    util::{reset_text_color, set_rarity_text_color},
-};
-use crossterm::{
-    cursor,
-    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
-    execute,
-    terminal::{Clear, ClearType},
+    tr,
+    util::{print_rarity_text, reset_text_color},
 };
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
 use std::io;
 
 pub fn menu_equipment(character: &mut PlayerCharacter) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     let menu_items_num = 3;
-    let mut selected_index = 0;
+    let mut list = SelectList::new();
     let start_column: u16 = 2;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("(Esc) Back, (Enter) Item Info, (U) Unequip Item");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Equipment");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
+        draw_key_hints(
+            0,
+            &[
+                (KeyAction::Back, "hint.back"),
+                (KeyAction::Select, "hint.item_info"),
+                (KeyAction::Unequip, "hint.unequip_item"),
+            ],
+        )?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("equipment.title"))?;
+        move_to(0, 2)?;
 
         let (weapon_name, weapon_lvl, weapon_rarity) = match &character.equipped_items.weapon {
             Some(id) => match character.data.inventory.weapons.get(id) {
@@ -34,9 +45,9 @@ pub fn menu_equipment(character: &mut PlayerCharacter) -> io::Result<()> {
                     weapon.level,
                     &weapon.rarity,
                 ),
-                None => ("?Unknown?".to_owned(), 0, &ItemRarity::Unknown),
+                None => (tr!("item.unknown"), 0, &ItemRarity::Unknown),
             },
-            None => ("Not equipped".to_owned(), 0, &ItemRarity::Unknown),
+            None => (tr!("equipment.not_equipped"), 0, &ItemRarity::Unknown),
         };
         let (armor_name, armor_lvl, armor_rarity) = match &character.equipped_items.armor {
             Some(id) => match character.data.inventory.armors.get(id) {
@@ -45,9 +56,9 @@ pub fn menu_equipment(character: &mut PlayerCharacter) -> io::Result<()> {
                     armor.level,
                     &armor.rarity,
                 ),
-                None => ("?Unknown?".to_owned(), 0, &ItemRarity::Unknown),
+                None => (tr!("item.unknown"), 0, &ItemRarity::Unknown),
             },
-            None => ("Not equipped".to_owned(), 0, &ItemRarity::Unknown),
+            None => (tr!("equipment.not_equipped"), 0, &ItemRarity::Unknown),
         };
         let (ring_name, ring_lvl, ring_rarity) = match &character.equipped_items.ring {
             Some(id) => match character.data.inventory.rings.get(id) {
@@ -56,105 +67,123 @@ pub fn menu_equipment(character: &mut PlayerCharacter) -> io::Result<()> {
                     ring.level,
                     &ring.rarity,
                 ),
-                None => ("?Unknown?".to_owned(), 0, &ItemRarity::Unknown),
+                None => (tr!("item.unknown"), 0, &ItemRarity::Unknown),
             },
-            None => ("Not equipped".to_owned(), 0, &ItemRarity::Unknown),
+            None => (tr!("equipment.not_equipped"), 0, &ItemRarity::Unknown),
         };
 
         for i in 0..menu_items_num {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                print!("> ");
+            move_to(0, i as u16 + start_column)?;
+            if i == list.selected_index {
+                print_text!("> ")?;
             } else {
-                print!("  ");
+                print_text!("  ")?;
             }
             match i {
                 0 => {
-                    print!("Weapon:");
-                    set_rarity_text_color(weapon_rarity)?;
-                    print!(" {}", weapon_name);
+                    print_text!("{}", tr!("equipment.weapon"))?;
+                    print_rarity_text(weapon_rarity, &weapon_name)?;
                     if weapon_lvl > 0 {
                         reset_text_color()?;
-                        print!(" {}", get_item_level_display(weapon_lvl));
+                        print_text!(" {}", get_item_level_display(weapon_lvl))?;
                     }
                 }
                 1 => {
-                    print!("Armor:");
-                    set_rarity_text_color(armor_rarity)?;
-                    print!(" {}", armor_name);
+                    print_text!("{}", tr!("equipment.armor"))?;
+                    print_rarity_text(armor_rarity, &armor_name)?;
                     if armor_lvl > 0 {
                         reset_text_color()?;
-                        print!(" {}", get_item_level_display(armor_lvl));
+                        print_text!(" {}", get_item_level_display(armor_lvl))?;
                     }
                 }
                 2 => {
-                    print!("Ring:");
-                    set_rarity_text_color(ring_rarity)?;
-                    print!(" {}", ring_name);
+                    print_text!("{}", tr!("equipment.ring"))?;
+                    print_rarity_text(ring_rarity, &ring_name)?;
                     if ring_lvl > 0 {
                         reset_text_color()?;
-                        print!(" {}", get_item_level_display(ring_lvl));
+                        print_text!(" {}", get_item_level_display(ring_lvl))?;
                     }
                 }
-                _ => println!("?Unknown?"),
+                _ => print_line!("{}", tr!("item.unknown"))?,
             }
             reset_text_color()?;
         }
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        let mut current_column = start_column + menu_items_num as u16 + 1;
+        let equipped_sets = character.get_equipped_item_sets();
+        if !equipped_sets.is_empty() {
+            move_to(0, current_column)?;
+            print_line!("{}", tr!("equipment.set_bonuses"))?;
+            current_column += 1;
+            for (set, pieces) in &equipped_sets {
+                move_to(0, current_column)?;
+                print_line!(
+                    "  {}",
+                    tr!(
+                        "equipment.set",
+                        set = set,
+                        equipped = pieces,
+                        pieces = ITEM_SET_PIECES
+                    )
+                )?;
+                current_column += 1;
+            }
+            if character.set_bonuses.is_empty() {
+                move_to(0, current_column)?;
+                print_line!("    {}", tr!("equipment.no_active_bonuses"))?;
+            }
+            for enchantment in &character.set_bonuses {
+                move_to(0, current_column)?;
+                print_line!("    {}", get_enchantment_display(enchantment))?;
+                current_column += 1;
+            }
+        }
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if selected_index < menu_items_num - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Esc => {
+                    code if list.handle_key(code, menu_items_num) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
-                    KeyCode::Enter => match selected_index {
+                    code if list.is_select(code, menu_items_num) => match list.selected_index {
                         0 => {
                             if let Some(id) = &character.equipped_items.weapon {
                                 if let Some(weapon) = character.data.inventory.weapons.get(id) {
-                                    menu_weapon_info(weapon, false)?;
+                                    menu_weapon_info(weapon, character, false)?;
                                 }
                             }
                         }
                         1 => {
                             if let Some(id) = &character.equipped_items.armor {
                                 if let Some(armor) = character.data.inventory.armors.get(id) {
-                                    menu_armor_info(armor, false)?;
+                                    menu_armor_info(armor, character, false)?;
                                 }
                             }
                         }
                         2 => {
                             if let Some(id) = &character.equipped_items.ring {
                                 if let Some(ring) = character.data.inventory.rings.get(id) {
-                                    menu_ring_info(ring, false)?;
+                                    menu_ring_info(ring, character, false)?;
                                 }
                             }
                         }
                         _ => {}
                     },
-                    KeyCode::Char('U') | KeyCode::Char('u') => match selected_index {
+                    code if is_key_action(code, KeyAction::Unequip) => match list.selected_index {
                         0 => {
                             if character.unequip_weapon() {
-                                execute!(stdout, Clear(ClearType::All))?;
+                                clear()?;
                             }
                         }
                         1 => {
                             if character.unequip_armor() {
-                                execute!(stdout, Clear(ClearType::All))?;
+                                clear()?;
                             }
                         }
                         2 => {
                             if character.unequip_ring() {
-                                execute!(stdout, Clear(ClearType::All))?;
+                                clear()?;
                             }
                         }
                         _ => {}
@@ -164,7 +193,7 @@ pub fn menu_equipment(character: &mut PlayerCharacter) -> io::Result<()> {
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
@@ -1,72 +1,84 @@
-use crossterm::{
-    cursor,
-    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
-    execute,
-    terminal::{Clear, ClearType},
-};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind};
 use std::io;
 
 use crate::{
+    enemy::Enemy,
+    frontend::{clear, move_to},
+    i18n::translate_all,
+    inventory::{get_armor_list, get_ring_list, get_weapon_list, ItemListOptions, ItemSortOrder},
     items::{
-        get_item_display_name, get_item_level_display, get_item_sell_value, ArmorItem,
-        CharacterItem, ConsumableItem, Enchantment, ItemInfo, ItemRarity, RingItem, WeaponItem,
+        get_enchantment_display, get_item_display_name, get_item_level_display,
+        get_item_sell_value, get_item_set_bonuses, ArmorItem, CharacterItem, ConsumableItem,
+        Enchantment, ItemInfo, ItemRarity, ItemSet, RingItem, WeaponItem, ITEM_SET_PIECES,
+    },
+    keymap::{get_key_display, is_key_action, KeyAction},
+    layout::print_wrapped,
+    menu::{
+        comparison::menu_compare_item,
+        widget::{draw_key_hints, open_modal, ConfirmDialog, SelectList},
     },
+    print_line, print_text,
+    replay::read_event,
     session::PlayerCharacter,
-    shop::{sell_armor, sell_consumable, sell_ring, sell_weapon},
-    util::{reset_text_color, set_rarity_text_color, shift_index_back},
+    shop::{sell_consumable, ShopItems},
+    tr,
+    util::{print_rarity_text, reset_text_color, set_rarity_text_color, shift_index_back},
 };
 
-pub fn menu_inventory(character: &mut PlayerCharacter, sell_items: bool) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
-
-    let menu_items = vec!["Consumables", "Weapons", "Armors", "Rings"];
-    let mut selected_index = 0;
+/// Items can be sold if the shop items are passed.
+pub fn menu_inventory(
+    character: &mut PlayerCharacter,
+    mut shop_items: Option<&mut ShopItems>,
+) -> io::Result<()> {
+    let sell_items = shop_items.is_some();
+    clear()?;
+
+    let menu_items = vec![
+        "menu.inventory.consumables",
+        "menu.inventory.weapons",
+        "menu.inventory.armors",
+        "menu.inventory.rings",
+    ];
+    let mut list = SelectList::new();
     let mut start_column: u16 = 2;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("(Esc) Back");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Inventory (Gold: {})", character.data.currency.gold);
-        execute!(stdout, cursor::MoveTo(0, 2))?;
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!(
+            "{}",
+            tr!("inventory.title", gold = character.data.currency.gold)
+        )?;
+        move_to(0, 2)?;
         if sell_items {
-            println!("Sell Items");
+            print_line!("{}", tr!("inventory.sell_items"))?;
             start_column = 3;
         }
 
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
-            } else {
-                println!("  {}", item);
-            }
-        }
+        list.draw(start_column, &translate_all(&menu_items))?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Esc => {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
-                    KeyCode::Enter => match menu_items[selected_index] {
-                        "Consumables" => {
-                            let _ = menu_inventory_consumable_list(character, false, sell_items)?;
+                    code if list.is_select(code, menu_items.len()) => match menu_items
+                        [list.selected_index]
+                    {
+                        "menu.inventory.consumables" => {
+                            let _ = menu_inventory_consumable_list(character, None, sell_items)?;
+                        }
+                        "menu.inventory.weapons" => {
+                            menu_inventory_weapon_list(character, shop_items.as_deref_mut())?
+                        }
+                        "menu.inventory.armors" => {
+                            menu_inventory_armor_list(character, shop_items.as_deref_mut())?
+                        }
+                        "menu.inventory.rings" => {
+                            menu_inventory_ring_list(character, shop_items.as_deref_mut())?
                         }
-                        "Weapons" => menu_inventory_weapon_list(character, sell_items)?,
-                        "Armors" => menu_inventory_armor_list(character, sell_items)?,
-                        "Rings" => menu_inventory_ring_list(character, sell_items)?,
                         _ => break,
                     },
                     _ => {}
@@ -74,119 +86,139 @@ pub fn menu_inventory(character: &mut PlayerCharacter, sell_items: bool) -> io::
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
 
 /// Returns text telling what the item did if it was used.
+/// Items can be used only if `enemy` is Some, meaning the player is in a fight.
 pub fn menu_inventory_consumable_list(
     character: &mut PlayerCharacter,
-    in_fight: bool,
+    mut enemy: Option<&mut Enemy>,
     sell_items: bool,
 ) -> io::Result<(String, ItemRarity, String)> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     let mut menu_items = Vec::new();
-    let mut selected_index = 0;
+    let mut list = SelectList::new();
     let start_column: u16 = 2;
     let mut item_name = "".to_string();
     let mut item_rarity = ItemRarity::Unknown;
     let mut effect_text = "".to_string();
+    let in_fight = enemy.is_some();
 
     for (_, item) in &character.data.inventory.consumables {
         menu_items.push(item.clone());
     }
+    // sorted by the inventory key so the order is the same in every language
+    menu_items.sort_by_key(|item| item.get_inventory_key());
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
         if in_fight {
-            println!("(Esc) Back, (Enter) Item Info, (U) Use Item");
+            draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.item_info"),
+                    (KeyAction::Use, "hint.use_item"),
+                ],
+            )?;
         } else if sell_items {
-            println!("(Esc) Back, (Enter) Item Info, (S) Sell Item");
+            draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.item_info"),
+                    (KeyAction::Sell, "hint.sell_item"),
+                ],
+            )?;
         } else {
-            println!("(Esc) Back, (Enter) Item Info, (D) Delete Item");
+            draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.item_info"),
+                    (KeyAction::Delete, "hint.delete_item"),
+                ],
+            )?;
         }
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Consumables");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("inventory.consumables"))?;
+        move_to(0, 2)?;
 
         if menu_items.is_empty() {
-            println!("  No consumables in inventory");
+            print_line!("  {}", tr!("inventory.no_consumables"))?;
         }
 
         for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
+            move_to(0, i as u16 + start_column)?;
             let name = get_item_display_name(CharacterItem::Consumable(&item));
-            if i == selected_index {
-                print!("> ");
-                set_rarity_text_color(&item.rarity)?;
-                print!("{}", name);
+            if i == list.selected_index {
+                print_text!("> ")?;
+                print_rarity_text(&item.rarity, &name)?;
                 reset_text_color()?;
-                print!(" x{}", item.amount_in_inventory);
+                print_text!(" x{}", item.amount_in_inventory)?;
             } else {
-                set_rarity_text_color(&item.rarity)?;
-                print!("  {}", name);
+                print_text!("  ")?;
+                print_rarity_text(&item.rarity, &name)?;
                 reset_text_color()?;
-                print!(" x{}", item.amount_in_inventory);
+                print_text!(" x{}", item.amount_in_inventory)?;
             }
             reset_text_color()?;
         }
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if !menu_items.is_empty() && selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if !menu_items.is_empty() && selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Esc => {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
-                    KeyCode::Enter => {
+                    code if list.is_select(code, menu_items.len()) => {
                         if !menu_items.is_empty() {
-                            menu_consumable_info(&menu_items[selected_index], sell_items)?;
+                            menu_consumable_info(&menu_items[list.selected_index], sell_items)?;
                         }
                     }
-                    KeyCode::Char('U') | KeyCode::Char('u') => {
-                        if in_fight && !menu_items.is_empty() {
-                            let selected_item = &menu_items[selected_index];
-                            (item_name, item_rarity, effect_text) =
-                                selected_item.use_item(character);
-                            break;
+                    code if enemy.is_some() && is_key_action(code, KeyAction::Use) => {
+                        if let Some(enemy) = &mut enemy {
+                            if !menu_items.is_empty() {
+                                let selected_item = &menu_items[list.selected_index];
+                                (item_name, item_rarity, effect_text) =
+                                    selected_item.use_item(character, enemy);
+                                if !effect_text.is_empty() {
+                                    break;
+                                }
+                            }
                         }
                     }
-                    KeyCode::Char('D') | KeyCode::Char('d') => {
-                        if !in_fight && !sell_items && !menu_items.is_empty() {
-                            let deleted_all = menu_delete_consumable(
-                                character,
-                                &mut menu_items,
-                                selected_index,
-                                false,
-                            )?;
-                            if deleted_all {
-                                selected_index = shift_index_back(selected_index);
-                            }
+                    code if !in_fight
+                        && !sell_items
+                        && is_key_action(code, KeyAction::Delete)
+                        && !menu_items.is_empty() =>
+                    {
+                        let deleted_all = menu_delete_consumable(
+                            character,
+                            &mut menu_items,
+                            list.selected_index,
+                            false,
+                        )?;
+                        if deleted_all {
+                            list.selected_index = shift_index_back(list.selected_index);
                         }
                     }
-                    KeyCode::Char('S') | KeyCode::Char('s') => {
-                        if sell_items && !menu_items.is_empty() {
-                            let deleted_all = menu_delete_consumable(
-                                character,
-                                &mut menu_items,
-                                selected_index,
-                                true,
-                            )?;
-                            if deleted_all {
-                                selected_index = shift_index_back(selected_index);
-                            }
+                    code if sell_items
+                        && is_key_action(code, KeyAction::Sell)
+                        && !menu_items.is_empty() =>
+                    {
+                        let deleted_all = menu_delete_consumable(
+                            character,
+                            &mut menu_items,
+                            list.selected_index,
+                            true,
+                        )?;
+                        if deleted_all {
+                            list.selected_index = shift_index_back(list.selected_index);
                         }
                     }
                     _ => {}
@@ -194,7 +226,7 @@ pub fn menu_inventory_consumable_list(
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok((item_name, item_rarity, effect_text))
 }
@@ -206,58 +238,71 @@ pub fn menu_delete_consumable(
     selected_index: usize,
     sell_item: bool,
 ) -> io::Result<bool> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
     let selected_item = &mut menu_items[selected_index];
     let mut selected_amount: u32 = 1;
     let display_name = &get_item_display_name(CharacterItem::Consumable(&selected_item));
+    let inventory_key = &selected_item.get_inventory_key();
     let mut deleted_all = false;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
         if sell_item {
-            println!("(Esc) Back, (Enter) Sell, (<-) Decrease amount, (->) Increase amount");
+            draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.sell"),
+                    (KeyAction::Left, "hint.decrease_amount"),
+                    (KeyAction::Right, "hint.increase_amount"),
+                ],
+            )?;
         } else {
-            println!("(Esc) Back, (Enter) Delete, (<-) Decrease amount, (->) Increase amount");
+            draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.delete"),
+                    (KeyAction::Left, "hint.decrease_amount"),
+                    (KeyAction::Right, "hint.increase_amount"),
+                ],
+            )?;
         }
-        execute!(stdout, cursor::MoveTo(0, 1))?;
+        move_to(0, 1)?;
         if sell_item {
-            print!("Sell item ");
-            set_rarity_text_color(&selected_item.rarity)?;
-            print!("{}", display_name);
+            print_text!("{}", tr!("inventory.sell_item_prefix"))?;
+            print_rarity_text(&selected_item.rarity, display_name)?;
         } else {
-            print!("Delete item ");
-            set_rarity_text_color(&selected_item.rarity)?;
-            print!("{}", display_name);
+            print_text!("{}", tr!("inventory.delete_item_prefix"))?;
+            print_rarity_text(&selected_item.rarity, display_name)?;
         }
         reset_text_color()?;
-        execute!(stdout, cursor::MoveTo(0, 2))?;
+        move_to(0, 2)?;
         if sell_item {
-            println!("Specify the amount to sell:");
+            print_line!("{}", tr!("inventory.amount_to_sell"))?;
         } else {
-            println!("Specify the amount to delete:");
+            print_line!("{}", tr!("inventory.amount_to_delete"))?;
         }
-        execute!(stdout, cursor::MoveTo(0, 3))?;
-        println!("< x{} >", selected_amount);
-        execute!(stdout, cursor::MoveTo(0, 4))?;
+        move_to(0, 3)?;
+        print_line!("< x{} >", selected_amount)?;
+        move_to(0, 4)?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Left => {
+                    code if is_key_action(code, KeyAction::Left) => {
                         if selected_amount > 1 {
                             selected_amount -= 1;
                         }
                     }
-                    KeyCode::Right => {
+                    code if is_key_action(code, KeyAction::Right) => {
                         if selected_item.amount_in_inventory > selected_amount {
                             selected_amount += 1;
                         }
                     }
-                    KeyCode::Esc => {
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
-                    KeyCode::Enter => {
+                    code if is_key_action(code, KeyAction::Select) => {
                         if selected_amount == selected_item.amount_in_inventory {
                             if sell_item {
                                 let gold =
@@ -267,7 +312,7 @@ pub fn menu_delete_consumable(
                                     deleted_all = true;
                                 }
                             } else {
-                                if character.delete_consumable(display_name) {
+                                if character.delete_consumable(inventory_key) {
                                     menu_items.remove(selected_index);
                                     deleted_all = true;
                                 }
@@ -281,7 +326,7 @@ pub fn menu_delete_consumable(
                                 }
                             } else {
                                 if character.decrease_consumable_inventory_amount(
-                                    display_name,
+                                    inventory_key,
                                     selected_amount,
                                 ) {
                                     selected_item.amount_in_inventory -= selected_amount;
@@ -295,124 +340,162 @@ pub fn menu_delete_consumable(
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(deleted_all)
 }
 
 pub fn menu_inventory_weapon_list(
     character: &mut PlayerCharacter,
-    sell_items: bool,
+    mut shop_items: Option<&mut ShopItems>,
 ) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
-    let mut menu_items = Vec::new();
-    let mut selected_index = 0;
-    let start_column: u16 = 2;
-
-    for (_, item) in &character.data.inventory.weapons {
-        menu_items.push(item.clone());
-    }
+    let sell_items = shop_items.is_some();
+    clear()?;
+    let mut list_menu = ItemListMenu::new();
+    let mut list = SelectList::new();
+    let start_column: u16 = 3;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
+        let menu_items = get_weapon_list(character, &list_menu.options);
+        list.clamp(menu_items.len());
+        let visible_items = list.get_visible_items(menu_items.len(), start_column)?;
+
         if sell_items {
-            println!("(Esc) Back, (Enter) Item Info, (S) Sell Item");
+            draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.item_info"),
+                    (KeyAction::Compare, "hint.compare"),
+                    (KeyAction::Lock, "hint.lock"),
+                    (KeyAction::Sell, "hint.sell_item"),
+                ],
+            )?;
         } else {
-            println!("(Esc) Back, (Enter) Item Info, (E) Equip Item, (D) Delete Item");
+            draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.item_info"),
+                    (KeyAction::Compare, "hint.compare"),
+                    (KeyAction::Lock, "hint.lock"),
+                    (KeyAction::Equip, "hint.equip_item"),
+                    (KeyAction::Delete, "hint.delete_item"),
+                ],
+            )?;
         }
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Weapons");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("inventory.weapons", count = menu_items.len()))?;
+        list_menu.display_options(&tr!("item_stat.damage"), 2)?;
+        move_to(0, start_column)?;
 
         if menu_items.is_empty() {
-            println!("  No weapons in inventory");
+            print_line!("  {}", tr!("inventory.no_weapons"))?;
         }
 
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
+        for (i, item) in menu_items
+            .iter()
+            .enumerate()
+            .skip(visible_items.start)
+            .take(visible_items.len())
+        {
+            move_to(0, (i - visible_items.start) as u16 + start_column)?;
             let name = &get_item_display_name(CharacterItem::Weapon(&item));
             let lvl = &get_item_level_display(item.level);
-            if i == selected_index {
+            if i == list.selected_index {
                 if item.is_equipped(&character) {
-                    print!("> ");
-                    set_rarity_text_color(&item.rarity)?;
-                    print!("{}", name);
+                    print_text!("> ")?;
+                    print_rarity_text(&item.rarity, name)?;
                     reset_text_color()?;
-                    print!(" {} [Equipped]", lvl);
+                    print_text!(" {}{}", lvl, tr!("inventory.equipped_tag"))?;
                 } else {
-                    print!("> ");
-                    set_rarity_text_color(&item.rarity)?;
-                    print!("{}", name);
+                    print_text!("> ")?;
+                    print_rarity_text(&item.rarity, name)?;
                     reset_text_color()?;
-                    print!(" {}", lvl);
+                    print_text!(" {}", lvl)?;
                 }
             } else {
                 if item.is_equipped(&character) {
-                    set_rarity_text_color(&item.rarity)?;
-                    print!("  {}", name);
+                    print_text!("  ")?;
+                    print_rarity_text(&item.rarity, name)?;
                     reset_text_color()?;
-                    print!(" {} [Equipped]", lvl);
+                    print_text!(" {}{}", lvl, tr!("inventory.equipped_tag"))?;
                 } else {
-                    set_rarity_text_color(&item.rarity)?;
-                    print!("  {}", name);
+                    print_text!("  ")?;
+                    print_rarity_text(&item.rarity, name)?;
                     reset_text_color()?;
-                    print!(" {}", lvl);
+                    print_text!(" {}", lvl)?;
                 }
             }
+            if item.locked {
+                print_text!("{}", tr!("inventory.locked_tag"))?;
+            }
             reset_text_color()?;
         }
+        list.draw_position(&visible_items, menu_items.len(), start_column)?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
+                if list_menu.handle_key(code) {
+                    list.reset();
+                    clear()?;
+                    continue;
+                }
                 match code {
-                    KeyCode::Up => {
-                        if !menu_items.is_empty() && selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if !menu_items.is_empty() && selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Esc => {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
-                    KeyCode::Enter => {
+                    code if list.is_select(code, menu_items.len()) => {
                         if !menu_items.is_empty() {
-                            menu_weapon_info(&menu_items[selected_index], sell_items)?;
-                        }
-                    }
-                    KeyCode::Char('D') | KeyCode::Char('d') => {
-                        if !menu_items.is_empty() && !sell_items {
-                            let selected_item = &menu_items[selected_index];
-                            let delete = menu_confirm_item_deletion(
-                                &get_item_display_name(CharacterItem::Weapon(selected_item)),
-                                &selected_item.rarity,
+                            menu_weapon_info(
+                                &menu_items[list.selected_index],
+                                character,
+                                sell_items,
                             )?;
-                            if delete {
-                                if character.delete_weapon(&selected_item.id) {
-                                    menu_items.remove(selected_index);
-                                    selected_index = shift_index_back(selected_index);
-                                }
-                            }
-                            execute!(stdout, Clear(ClearType::All))?;
                         }
                     }
-                    KeyCode::Char('E') | KeyCode::Char('e') => {
-                        if !menu_items.is_empty() && !sell_items {
-                            if character.equip_weapon(&menu_items[selected_index].id) {
-                                execute!(stdout, Clear(ClearType::All))?;
-                            }
+                    code if is_key_action(code, KeyAction::Compare) && !menu_items.is_empty() => {
+                        menu_compare_item(
+                            CharacterItem::Weapon(&menu_items[list.selected_index]),
+                            character,
+                        )?;
+                    }
+                    code if !sell_items
+                        && is_key_action(code, KeyAction::Delete)
+                        && !menu_items.is_empty()
+                        && !menu_items[list.selected_index].locked =>
+                    {
+                        let selected_item = &menu_items[list.selected_index];
+                        let delete = menu_confirm_item_deletion(
+                            &get_item_display_name(CharacterItem::Weapon(selected_item)),
+                            &selected_item.rarity,
+                        )?;
+                        if delete && character.delete_weapon(&selected_item.id) {
+                            list.selected_index = shift_index_back(list.selected_index);
                         }
+                        clear()?;
+                    }
+                    code if is_key_action(code, KeyAction::Lock) && !menu_items.is_empty() => {
+                        character.toggle_weapon_lock(&menu_items[list.selected_index].id);
+                        clear()?;
                     }
-                    KeyCode::Char('S') | KeyCode::Char('s') => {
-                        if !menu_items.is_empty() && sell_items {
-                            if sell_weapon(&menu_items[selected_index], character) != 0 {
-                                menu_items.remove(selected_index);
-                                selected_index = shift_index_back(selected_index);
-                                execute!(stdout, Clear(ClearType::All))?;
+                    code if !sell_items
+                        && is_key_action(code, KeyAction::Equip)
+                        && !menu_items.is_empty()
+                        && character.equip_weapon(&menu_items[list.selected_index].id) =>
+                    {
+                        clear()?;
+                    }
+                    code if sell_items && is_key_action(code, KeyAction::Sell) => {
+                        if let Some(shop_items) = shop_items.as_deref_mut() {
+                            if !menu_items.is_empty()
+                                && shop_items
+                                    .sell_weapon(&menu_items[list.selected_index], character)
+                                    != 0
+                            {
+                                list.selected_index = shift_index_back(list.selected_index);
+                                clear()?;
                             }
                         }
                     }
@@ -421,124 +504,162 @@ pub fn menu_inventory_weapon_list(
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
 
 pub fn menu_inventory_armor_list(
     character: &mut PlayerCharacter,
-    sell_items: bool,
+    mut shop_items: Option<&mut ShopItems>,
 ) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
-    let mut menu_items = Vec::new();
-    let mut selected_index = 0;
-    let start_column: u16 = 2;
-
-    for (_, item) in &character.data.inventory.armors {
-        menu_items.push(item.clone());
-    }
+    let sell_items = shop_items.is_some();
+    clear()?;
+    let mut list_menu = ItemListMenu::new();
+    let mut list = SelectList::new();
+    let start_column: u16 = 3;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
+        let menu_items = get_armor_list(character, &list_menu.options);
+        list.clamp(menu_items.len());
+        let visible_items = list.get_visible_items(menu_items.len(), start_column)?;
+
         if sell_items {
-            println!("(Esc) Back, (Enter) Item Info, (S) Sell Item");
+            draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.item_info"),
+                    (KeyAction::Compare, "hint.compare"),
+                    (KeyAction::Lock, "hint.lock"),
+                    (KeyAction::Sell, "hint.sell_item"),
+                ],
+            )?;
         } else {
-            println!("(Esc) Back, (Enter) Item Info, (E) Equip Item, (D) Delete Item");
+            draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.item_info"),
+                    (KeyAction::Compare, "hint.compare"),
+                    (KeyAction::Lock, "hint.lock"),
+                    (KeyAction::Equip, "hint.equip_item"),
+                    (KeyAction::Delete, "hint.delete_item"),
+                ],
+            )?;
         }
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Armors");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("inventory.armors", count = menu_items.len()))?;
+        list_menu.display_options(&tr!("item_stat.health"), 2)?;
+        move_to(0, start_column)?;
 
         if menu_items.is_empty() {
-            println!("  No armors in inventory");
+            print_line!("  {}", tr!("inventory.no_armors"))?;
         }
 
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
+        for (i, item) in menu_items
+            .iter()
+            .enumerate()
+            .skip(visible_items.start)
+            .take(visible_items.len())
+        {
+            move_to(0, (i - visible_items.start) as u16 + start_column)?;
             let name = &get_item_display_name(CharacterItem::Armor(&item));
             let lvl = &get_item_level_display(item.level);
-            if i == selected_index {
+            if i == list.selected_index {
                 if item.is_equipped(&character) {
-                    print!("> ");
-                    set_rarity_text_color(&item.rarity)?;
-                    print!("{}", name);
+                    print_text!("> ")?;
+                    print_rarity_text(&item.rarity, name)?;
                     reset_text_color()?;
-                    print!(" {} [Equipped]", lvl);
+                    print_text!(" {}{}", lvl, tr!("inventory.equipped_tag"))?;
                 } else {
-                    print!("> ");
-                    set_rarity_text_color(&item.rarity)?;
-                    print!("{}", name);
+                    print_text!("> ")?;
+                    print_rarity_text(&item.rarity, name)?;
                     reset_text_color()?;
-                    print!(" {}", lvl);
+                    print_text!(" {}", lvl)?;
                 }
             } else {
                 if item.is_equipped(&character) {
-                    set_rarity_text_color(&item.rarity)?;
-                    print!("  {}", name);
+                    print_text!("  ")?;
+                    print_rarity_text(&item.rarity, name)?;
                     reset_text_color()?;
-                    print!(" {} [Equipped]", lvl);
+                    print_text!(" {}{}", lvl, tr!("inventory.equipped_tag"))?;
                 } else {
-                    set_rarity_text_color(&item.rarity)?;
-                    print!("  {}", name);
+                    print_text!("  ")?;
+                    print_rarity_text(&item.rarity, name)?;
                     reset_text_color()?;
-                    print!(" {}", lvl);
+                    print_text!(" {}", lvl)?;
                 }
             }
+            if item.locked {
+                print_text!("{}", tr!("inventory.locked_tag"))?;
+            }
             reset_text_color()?;
         }
+        list.draw_position(&visible_items, menu_items.len(), start_column)?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
+                if list_menu.handle_key(code) {
+                    list.reset();
+                    clear()?;
+                    continue;
+                }
                 match code {
-                    KeyCode::Up => {
-                        if !menu_items.is_empty() && selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if !menu_items.is_empty() && selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Esc => {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
-                    KeyCode::Enter => {
+                    code if list.is_select(code, menu_items.len()) => {
                         if !menu_items.is_empty() {
-                            menu_armor_info(&menu_items[selected_index], sell_items)?;
-                        }
-                    }
-                    KeyCode::Char('D') | KeyCode::Char('d') => {
-                        if !menu_items.is_empty() && !sell_items {
-                            let selected_item = &menu_items[selected_index];
-                            let delete = menu_confirm_item_deletion(
-                                &get_item_display_name(CharacterItem::Armor(selected_item)),
-                                &selected_item.rarity,
+                            menu_armor_info(
+                                &menu_items[list.selected_index],
+                                character,
+                                sell_items,
                             )?;
-                            if delete {
-                                if character.delete_armor(&selected_item.id) {
-                                    menu_items.remove(selected_index);
-                                    selected_index = shift_index_back(selected_index);
-                                }
-                            }
-                            execute!(stdout, Clear(ClearType::All))?;
                         }
                     }
-                    KeyCode::Char('E') | KeyCode::Char('e') => {
-                        if !menu_items.is_empty() && !sell_items {
-                            if character.equip_armor(&menu_items[selected_index].id) {
-                                execute!(stdout, Clear(ClearType::All))?;
-                            }
+                    code if is_key_action(code, KeyAction::Compare) && !menu_items.is_empty() => {
+                        menu_compare_item(
+                            CharacterItem::Armor(&menu_items[list.selected_index]),
+                            character,
+                        )?;
+                    }
+                    code if !sell_items
+                        && is_key_action(code, KeyAction::Delete)
+                        && !menu_items.is_empty()
+                        && !menu_items[list.selected_index].locked =>
+                    {
+                        let selected_item = &menu_items[list.selected_index];
+                        let delete = menu_confirm_item_deletion(
+                            &get_item_display_name(CharacterItem::Armor(selected_item)),
+                            &selected_item.rarity,
+                        )?;
+                        if delete && character.delete_armor(&selected_item.id) {
+                            list.selected_index = shift_index_back(list.selected_index);
                         }
+                        clear()?;
+                    }
+                    code if is_key_action(code, KeyAction::Lock) && !menu_items.is_empty() => {
+                        character.toggle_armor_lock(&menu_items[list.selected_index].id);
+                        clear()?;
+                    }
+                    code if !sell_items
+                        && is_key_action(code, KeyAction::Equip)
+                        && !menu_items.is_empty()
+                        && character.equip_armor(&menu_items[list.selected_index].id) =>
+                    {
+                        clear()?;
                     }
-                    KeyCode::Char('S') | KeyCode::Char('s') => {
-                        if !menu_items.is_empty() && sell_items {
-                            if sell_armor(&menu_items[selected_index], character) != 0 {
-                                menu_items.remove(selected_index);
-                                selected_index = shift_index_back(selected_index);
-                                execute!(stdout, Clear(ClearType::All))?;
+                    code if sell_items && is_key_action(code, KeyAction::Sell) => {
+                        if let Some(shop_items) = shop_items.as_deref_mut() {
+                            if !menu_items.is_empty()
+                                && shop_items
+                                    .sell_armor(&menu_items[list.selected_index], character)
+                                    != 0
+                            {
+                                list.selected_index = shift_index_back(list.selected_index);
+                                clear()?;
                             }
                         }
                     }
@@ -547,125 +668,161 @@ pub fn menu_inventory_armor_list(
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
 
 pub fn menu_inventory_ring_list(
     character: &mut PlayerCharacter,
-    sell_items: bool,
+    mut shop_items: Option<&mut ShopItems>,
 ) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
-    let mut menu_items = Vec::new();
-    let mut selected_index = 0;
-    let start_column: u16 = 2;
-
-    for (_, item) in &character.data.inventory.rings {
-        menu_items.push(item.clone());
-    }
+    let sell_items = shop_items.is_some();
+    clear()?;
+    let mut list_menu = ItemListMenu::new();
+    let mut list = SelectList::new();
+    let start_column: u16 = 3;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
+        let menu_items = get_ring_list(character, &list_menu.options);
+        list.clamp(menu_items.len());
+        let visible_items = list.get_visible_items(menu_items.len(), start_column)?;
+
         if sell_items {
-            println!("(Esc) Back, (Enter) Item Info, (S) Sell Item");
+            draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.item_info"),
+                    (KeyAction::Compare, "hint.compare"),
+                    (KeyAction::Lock, "hint.lock"),
+                    (KeyAction::Sell, "hint.sell_item"),
+                ],
+            )?;
         } else {
-            println!("(Esc) Back, (Enter) Item Info, (E) Equip Item, (D) Delete Item");
+            draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Select, "hint.item_info"),
+                    (KeyAction::Compare, "hint.compare"),
+                    (KeyAction::Lock, "hint.lock"),
+                    (KeyAction::Equip, "hint.equip_item"),
+                    (KeyAction::Delete, "hint.delete_item"),
+                ],
+            )?;
         }
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Rings");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("inventory.rings", count = menu_items.len()))?;
+        list_menu.display_options(&tr!("item_stat.mana"), 2)?;
+        move_to(0, start_column)?;
 
         if menu_items.is_empty() {
-            println!("  No rings in inventory");
+            print_line!("  {}", tr!("inventory.no_rings"))?;
         }
 
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
+        for (i, item) in menu_items
+            .iter()
+            .enumerate()
+            .skip(visible_items.start)
+            .take(visible_items.len())
+        {
+            move_to(0, (i - visible_items.start) as u16 + start_column)?;
             let name = &get_item_display_name(CharacterItem::Ring(&item));
             let lvl = &get_item_level_display(item.level);
-            if i == selected_index {
+            if i == list.selected_index {
                 if item.is_equipped(&character) {
-                    print!("> ");
-                    set_rarity_text_color(&item.rarity)?;
-                    print!("{}", name);
+                    print_text!("> ")?;
+                    print_rarity_text(&item.rarity, name)?;
                     reset_text_color()?;
-                    print!(" {} [Equipped]", lvl);
+                    print_text!(" {}{}", lvl, tr!("inventory.equipped_tag"))?;
                 } else {
-                    print!("> ");
-                    set_rarity_text_color(&item.rarity)?;
-                    print!("{}", name);
+                    print_text!("> ")?;
+                    print_rarity_text(&item.rarity, name)?;
                     reset_text_color()?;
-                    print!(" {}", lvl);
+                    print_text!(" {}", lvl)?;
                 }
             } else {
                 if item.is_equipped(&character) {
-                    set_rarity_text_color(&item.rarity)?;
-                    print!("  {}", name);
+                    print_text!("  ")?;
+                    print_rarity_text(&item.rarity, name)?;
                     reset_text_color()?;
-                    print!(" {} [Equipped]", lvl);
+                    print_text!(" {}{}", lvl, tr!("inventory.equipped_tag"))?;
                 } else {
-                    set_rarity_text_color(&item.rarity)?;
-                    print!("  {}", name);
+                    print_text!("  ")?;
+                    print_rarity_text(&item.rarity, name)?;
                     reset_text_color()?;
-                    print!(" {}", lvl);
+                    print_text!(" {}", lvl)?;
                 }
             }
+            if item.locked {
+                print_text!("{}", tr!("inventory.locked_tag"))?;
+            }
             reset_text_color()?;
         }
+        list.draw_position(&visible_items, menu_items.len(), start_column)?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
+                if list_menu.handle_key(code) {
+                    list.reset();
+                    clear()?;
+                    continue;
+                }
                 match code {
-                    KeyCode::Up => {
-                        if !menu_items.is_empty() && selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if !menu_items.is_empty() && selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Esc => {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
-                    KeyCode::Enter => {
+                    code if list.is_select(code, menu_items.len()) => {
                         if !menu_items.is_empty() {
-                            menu_ring_info(&menu_items[selected_index], sell_items)?;
-                        }
-                    }
-                    KeyCode::Char('D') | KeyCode::Char('d') => {
-                        if !menu_items.is_empty() && !sell_items {
-                            let selected_item = &menu_items[selected_index];
-                            let delete = menu_confirm_item_deletion(
-                                &get_item_display_name(CharacterItem::Ring(selected_item)),
-                                &selected_item.rarity,
+                            menu_ring_info(
+                                &menu_items[list.selected_index],
+                                character,
+                                sell_items,
                             )?;
-                            if delete {
-                                if character.delete_ring(&selected_item.id) {
-                                    menu_items.remove(selected_index);
-                                    selected_index = shift_index_back(selected_index);
-                                }
-                            }
-                            execute!(stdout, Clear(ClearType::All))?;
                         }
                     }
-                    KeyCode::Char('E') | KeyCode::Char('e') => {
-                        if !menu_items.is_empty() && !sell_items {
-                            let selected_item = &menu_items[selected_index];
-                            if character.equip_ring(&selected_item.id) {
-                                execute!(stdout, Clear(ClearType::All))?;
-                            }
+                    code if is_key_action(code, KeyAction::Compare) && !menu_items.is_empty() => {
+                        menu_compare_item(
+                            CharacterItem::Ring(&menu_items[list.selected_index]),
+                            character,
+                        )?;
+                    }
+                    code if !sell_items
+                        && is_key_action(code, KeyAction::Delete)
+                        && !menu_items.is_empty()
+                        && !menu_items[list.selected_index].locked =>
+                    {
+                        let selected_item = &menu_items[list.selected_index];
+                        let delete = menu_confirm_item_deletion(
+                            &get_item_display_name(CharacterItem::Ring(selected_item)),
+                            &selected_item.rarity,
+                        )?;
+                        if delete && character.delete_ring(&selected_item.id) {
+                            list.selected_index = shift_index_back(list.selected_index);
                         }
+                        clear()?;
+                    }
+                    code if is_key_action(code, KeyAction::Lock) && !menu_items.is_empty() => {
+                        character.toggle_ring_lock(&menu_items[list.selected_index].id);
+                        clear()?;
+                    }
+                    code if !sell_items
+                        && is_key_action(code, KeyAction::Equip)
+                        && !menu_items.is_empty()
+                        && character.equip_ring(&menu_items[list.selected_index].id) =>
+                    {
+                        clear()?;
                     }
-                    KeyCode::Char('S') | KeyCode::Char('s') => {
-                        if !menu_items.is_empty() && sell_items {
-                            if sell_ring(&menu_items[selected_index], character) != 0 {
-                                menu_items.remove(selected_index);
-                                selected_index = shift_index_back(selected_index);
-                                execute!(stdout, Clear(ClearType::All))?;
+                    code if sell_items && is_key_action(code, KeyAction::Sell) => {
+                        if let Some(shop_items) = shop_items.as_deref_mut() {
+                            if !menu_items.is_empty()
+                                && shop_items.sell_ring(&menu_items[list.selected_index], character)
+                                    != 0
+                            {
+                                list.selected_index = shift_index_back(list.selected_index);
+                                clear()?;
                             }
                         }
                     }
@@ -674,38 +831,45 @@ pub fn menu_inventory_ring_list(
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
 
 pub fn menu_consumable_info(item: &ConsumableItem, sell_item: bool) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("(Esc) Back");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Item Info");
-        let start_column = display_item_basic_info(&item.info, 2)?;
-        print!("  Rarity: ");
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("item_info.title"))?;
+        let start_column = display_item_basic_info(&item.info, &item.info.get_display_name(), 2)?;
+        print_text!("  {}", tr!("item_info.rarity"))?;
         set_rarity_text_color(&item.rarity)?;
-        print!("{:?}", item.rarity);
+        print_text!("{}", item.rarity)?;
         reset_text_color()?;
-        execute!(stdout, cursor::MoveTo(0, start_column + 1))?;
-        println!("  Effect: {}", item.effect);
-        execute!(stdout, cursor::MoveTo(0, start_column + 2))?;
-        println!("  Amount in Inventory: {}", item.amount_in_inventory);
-        execute!(stdout, cursor::MoveTo(0, start_column + 3))?;
+        move_to(0, start_column + 1)?;
+        print_line!("  {}", tr!("item_info.effect", effect = item.get_effect()))?;
+        move_to(0, start_column + 2)?;
+        print_line!(
+            "  {}",
+            tr!("item_info.amount", amount = item.amount_in_inventory)
+        )?;
+        move_to(0, start_column + 3)?;
         if sell_item {
-            println!("  Sell Value: {} Gold", get_item_sell_value(&item.rarity));
+            print_line!(
+                "  {}",
+                tr!(
+                    "item_info.sell_value",
+                    gold = get_item_sell_value(&item.rarity)
+                )
+            )?;
         }
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Esc => {
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
                     _ => {}
@@ -713,41 +877,57 @@ pub fn menu_consumable_info(item: &ConsumableItem, sell_item: bool) -> io::Resul
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
 
-pub fn menu_weapon_info(item: &WeaponItem, sell_item: bool) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+pub fn menu_weapon_info(
+    item: &WeaponItem,
+    character: &PlayerCharacter,
+    sell_item: bool,
+) -> io::Result<()> {
+    clear()?;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("(Esc) Back");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Item Info");
-        let start_column = display_item_basic_info(&item.info, 2)?;
-        println!("  Level: {}", item.level);
-        execute!(stdout, cursor::MoveTo(0, start_column + 1))?;
-        print!("  Rarity: ");
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("item_info.title"))?;
+        let start_column = display_item_basic_info(&item.info, &item.get_name(), 2)?;
+        print_line!("  {}", tr!("stats.level", level = item.level))?;
+        move_to(0, start_column + 1)?;
+        print_text!("  {}", tr!("item_info.rarity"))?;
         set_rarity_text_color(&item.rarity)?;
-        print!("{:?}", item.rarity);
+        print_text!("{}", item.rarity)?;
         reset_text_color()?;
-        execute!(stdout, cursor::MoveTo(0, start_column + 2))?;
-        println!("  Damage: {}", item.stats.damage);
-        execute!(stdout, cursor::MoveTo(0, start_column + 3))?;
-        println!("  Critical Hit Rate: {:.2}", item.stats.crit_hit_rate);
+        move_to(0, start_column + 2)?;
+        print_line!("  {}", tr!("stats.damage", value = item.stats.damage))?;
+        move_to(0, start_column + 3)?;
+        print_line!(
+            "  {}",
+            tr!(
+                "stats.critical_hit_rate",
+                value = format!("{:.2}", item.stats.crit_hit_rate)
+            )
+        )?;
         let column = display_item_enchantments(&item.enchantments, start_column + 4)?;
+        let column = display_item_set_info(&item.set, character, column)?;
+        let column = display_item_lore(&item.lore, column)?;
         if sell_item {
-            execute!(stdout, cursor::MoveTo(0, column))?;
-            println!("  Sell Value: {} Gold", get_item_sell_value(&item.rarity));
+            move_to(0, column)?;
+            print_line!(
+                "  {}",
+                tr!(
+                    "item_info.sell_value",
+                    gold = get_item_sell_value(&item.rarity)
+                )
+            )?;
         }
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Esc => {
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
                     _ => {}
@@ -755,41 +935,51 @@ pub fn menu_weapon_info(item: &WeaponItem, sell_item: bool) -> io::Result<()> {
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
 
-pub fn menu_armor_info(item: &ArmorItem, sell_item: bool) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+pub fn menu_armor_info(
+    item: &ArmorItem,
+    character: &PlayerCharacter,
+    sell_item: bool,
+) -> io::Result<()> {
+    clear()?;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("(Esc) Back");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Item Info");
-        let start_column = display_item_basic_info(&item.info, 2)?;
-        println!("  Level: {}", item.level);
-        execute!(stdout, cursor::MoveTo(0, start_column + 1))?;
-        print!("  Rarity: ");
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("item_info.title"))?;
+        let start_column = display_item_basic_info(&item.info, &item.get_name(), 2)?;
+        print_line!("  {}", tr!("stats.level", level = item.level))?;
+        move_to(0, start_column + 1)?;
+        print_text!("  {}", tr!("item_info.rarity"))?;
         set_rarity_text_color(&item.rarity)?;
-        print!("{:?}", item.rarity);
+        print_text!("{}", item.rarity)?;
         reset_text_color()?;
-        execute!(stdout, cursor::MoveTo(0, start_column + 2))?;
-        println!("  Health: {}", item.stats.health);
-        execute!(stdout, cursor::MoveTo(0, start_column + 3))?;
-        println!("  Defense: {}", item.stats.defense);
+        move_to(0, start_column + 2)?;
+        print_line!("  {}", tr!("stats.health", value = item.stats.health))?;
+        move_to(0, start_column + 3)?;
+        print_line!("  {}", tr!("stats.defense", value = item.stats.defense))?;
         let column = display_item_enchantments(&item.enchantments, start_column + 4)?;
+        let column = display_item_set_info(&item.set, character, column)?;
+        let column = display_item_lore(&item.lore, column)?;
         if sell_item {
-            execute!(stdout, cursor::MoveTo(0, column))?;
-            println!("  Sell Value: {} Gold", get_item_sell_value(&item.rarity));
+            move_to(0, column)?;
+            print_line!(
+                "  {}",
+                tr!(
+                    "item_info.sell_value",
+                    gold = get_item_sell_value(&item.rarity)
+                )
+            )?;
         }
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Esc => {
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
                     _ => {}
@@ -797,39 +987,49 @@ pub fn menu_armor_info(item: &ArmorItem, sell_item: bool) -> io::Result<()> {
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
 
-pub fn menu_ring_info(item: &RingItem, sell_item: bool) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+pub fn menu_ring_info(
+    item: &RingItem,
+    character: &PlayerCharacter,
+    sell_item: bool,
+) -> io::Result<()> {
+    clear()?;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("(Esc) Back");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Item Info");
-        let start_column = display_item_basic_info(&item.info, 2)?;
-        println!("  Level: {}", item.level);
-        execute!(stdout, cursor::MoveTo(0, start_column + 1))?;
-        print!("  Rarity: ");
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("item_info.title"))?;
+        let start_column = display_item_basic_info(&item.info, &item.get_name(), 2)?;
+        print_line!("  {}", tr!("stats.level", level = item.level))?;
+        move_to(0, start_column + 1)?;
+        print_text!("  {}", tr!("item_info.rarity"))?;
         set_rarity_text_color(&item.rarity)?;
-        print!("{:?}", item.rarity);
+        print_text!("{}", item.rarity)?;
         reset_text_color()?;
-        execute!(stdout, cursor::MoveTo(0, start_column + 2))?;
-        println!("  Mana: {}", item.stats.mana);
+        move_to(0, start_column + 2)?;
+        print_line!("  {}", tr!("stats.mana", value = item.stats.mana))?;
         let column = display_item_enchantments(&item.enchantments, start_column + 3)?;
+        let column = display_item_set_info(&item.set, character, column)?;
+        let column = display_item_lore(&item.lore, column)?;
         if sell_item {
-            execute!(stdout, cursor::MoveTo(0, column))?;
-            println!("  Sell Value: {} Gold", get_item_sell_value(&item.rarity));
+            move_to(0, column)?;
+            print_line!(
+                "  {}",
+                tr!(
+                    "item_info.sell_value",
+                    gold = get_item_sell_value(&item.rarity)
+                )
+            )?;
         }
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Esc => {
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
                     _ => {}
@@ -837,117 +1037,206 @@ pub fn menu_ring_info(item: &RingItem, sell_item: bool) -> io::Result<()> {
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
 
-pub fn display_item_basic_info(info: &ItemInfo, start_column: u16) -> io::Result<u16> {
-    let mut stdout = io::stdout();
-    execute!(stdout, cursor::MoveTo(0, start_column))?;
-    println!("  Name: {}", info.name);
-    execute!(stdout, cursor::MoveTo(0, start_column + 1))?;
-    println!("  Description: {}", info.description);
-    execute!(stdout, cursor::MoveTo(0, start_column + 2))?;
-    println!("  Category: {:?}", info.category);
-    execute!(stdout, cursor::MoveTo(0, start_column + 3))?;
-
-    Ok(start_column + 3)
+pub fn display_item_basic_info(info: &ItemInfo, name: &str, start_column: u16) -> io::Result<u16> {
+    move_to(0, start_column)?;
+    print_line!("  {}", tr!("stats.name", name = name))?;
+    let description_rows = print_wrapped(
+        start_column + 1,
+        "  ",
+        &tr!(
+            "item_info.description",
+            description = info.get_display_description()
+        ),
+    )?;
+    let row = start_column + 1 + description_rows;
+    move_to(0, row)?;
+    print_line!("  {}", tr!("item_info.category", category = info.category))?;
+    move_to(0, row + 1)?;
+
+    Ok(row + 1)
 }
 
 pub fn display_item_enchantments(
     enchantments: &Vec<Enchantment>,
     start_column: u16,
 ) -> io::Result<u16> {
-    let mut stdout = io::stdout();
-    execute!(stdout, cursor::MoveTo(0, start_column))?;
-    println!("  Enchantments:");
+    move_to(0, start_column)?;
+    print_line!("  {}", tr!("item_info.enchantments"))?;
     let mut current_column = start_column + 1;
 
     if enchantments.is_empty() {
-        execute!(stdout, cursor::MoveTo(0, current_column))?;
-        println!("    No Enchantments");
+        move_to(0, current_column)?;
+        print_line!("    {}", tr!("item_info.no_enchantments"))?;
         return Ok(current_column + 1);
     }
 
     for enchantment in enchantments {
-        execute!(stdout, cursor::MoveTo(0, current_column))?;
-        match enchantment {
-            Enchantment::Damage(val) => {
-                println!("    Damage: +{}", val);
-            }
-            Enchantment::CritHitRate(val) => {
-                println!("    Critical Hit Rate: +{:.2}", val);
-            }
-            Enchantment::Health(val) => {
-                println!("    Health: +{}", val);
-            }
-            Enchantment::Defense(val) => {
-                println!("    Defense: +{}", val);
-            }
-            Enchantment::Mana(val) => {
-                println!("    Mana: +{}", val);
-            }
-            _ => println!("?Unknown?"),
-        }
+        move_to(0, current_column)?;
+        print_line!("    {}", get_enchantment_display(enchantment))?;
         current_column += 1;
     }
 
     Ok(current_column)
 }
 
-fn menu_confirm_item_deletion(item_name: &str, rarity: &ItemRarity) -> io::Result<bool> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+/// Displays the lore text of unique items. Returns the next free column.
+pub fn display_item_lore(lore: &Option<String>, start_column: u16) -> io::Result<u16> {
+    let lore = match lore {
+        Some(lore) => lore,
+        None => return Ok(start_column),
+    };
+    move_to(0, start_column)?;
+    print_line!("  {}", tr!("item_info.lore", lore = tr!(lore)))?;
 
-    let menu_items = vec!["No", "Yes"];
-    let mut selected_index = 0;
-    let start_column: u16 = 1;
+    Ok(start_column + 1)
+}
 
-    loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        print!("Delete item ");
-        set_rarity_text_color(rarity)?;
-        print!("{}", item_name);
-        reset_text_color()?;
-        print!("? It cannot be restored once deleted.");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
+/// Displays the item set of an item and the set bonuses.
+/// Returns the next free column.
+pub fn display_item_set_info(
+    set: &Option<ItemSet>,
+    character: &PlayerCharacter,
+    start_column: u16,
+) -> io::Result<u16> {
+    let set = match set {
+        Some(set) => set,
+        None => return Ok(start_column),
+    };
+    let equipped_pieces = character.get_equipped_set_pieces(set);
+    move_to(0, start_column)?;
+    print_line!(
+        "  {}",
+        tr!(
+            "item_info.set",
+            set = set,
+            equipped = equipped_pieces,
+            pieces = ITEM_SET_PIECES
+        )
+    )?;
+    let mut current_column = start_column + 1;
 
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
-            } else {
-                println!("  {}", item);
-            }
+    for bonus in get_item_set_bonuses(set) {
+        move_to(0, current_column)?;
+        let bonus_text = bonus
+            .enchantments
+            .iter()
+            .map(get_enchantment_display)
+            .collect::<Vec<String>>()
+            .join(", ");
+        if equipped_pieces >= bonus.pieces {
+            print_line!(
+                "    ({}) {}{}",
+                bonus.pieces,
+                bonus_text,
+                tr!("item_info.active_tag")
+            )?;
+        } else {
+            print_line!("    ({}) {}", bonus.pieces, bonus_text)?;
         }
+        current_column += 1;
+    }
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
-            if kind == KeyEventKind::Press {
-                match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Enter => {
-                        break;
-                    }
-                    _ => {}
+    Ok(current_column)
+}
+
+/// Sorting, filtering and search state of an item list menu.
+struct ItemListMenu {
+    options: ItemListOptions,
+    /// True if typed characters are added to the search text.
+    searching: bool,
+}
+
+impl ItemListMenu {
+    fn new() -> Self {
+        Self {
+            options: ItemListOptions::default(),
+            searching: false,
+        }
+    }
+
+    /// Handles keys that change the list options.
+    /// Returns true if the key was handled and the list changed.
+    fn handle_key(&mut self, code: KeyCode) -> bool {
+        if self.searching {
+            match code {
+                KeyCode::Char(c) => self.options.search.push(c),
+                KeyCode::Backspace => {
+                    self.options.search.pop();
                 }
+                KeyCode::Enter | KeyCode::Esc => self.searching = false,
+                _ => return false,
             }
+            return true;
         }
+        match code {
+            code if is_key_action(code, KeyAction::Search) => self.searching = true,
+            code if is_key_action(code, KeyAction::Sort) => {
+                self.options.sort_order = self.options.sort_order.next()
+            }
+            code if is_key_action(code, KeyAction::RarityFilter) => {
+                self.options.next_rarity_filter()
+            }
+            code if is_key_action(code, KeyAction::EquippedFilter) => {
+                self.options.equipped_filter = self.options.equipped_filter.next()
+            }
+            _ => return false,
+        }
+        true
     }
 
-    match menu_items[selected_index] {
-        "Yes" => return Ok(true),
-        _ => {}
+    /// Displays the list options. `stat_name` is the main stat used in sorting.
+    fn display_options(&self, stat_name: &str, column: u16) -> io::Result<()> {
+        let sort_order = match self.options.sort_order {
+            ItemSortOrder::Stat => stat_name.to_string(),
+            sort_order => sort_order.to_string(),
+        };
+        let rarity = match &self.options.rarity_filter {
+            Some(rarity) => rarity.to_string(),
+            None => tr!("filter.all"),
+        };
+        move_to(0, column)?;
+        print_text!(
+            "{}",
+            tr!(
+                "inventory.list_options",
+                sort_key = get_key_display(KeyAction::Sort),
+                sort = sort_order,
+                rarity_key = get_key_display(KeyAction::RarityFilter),
+                rarity = rarity,
+                show_key = get_key_display(KeyAction::EquippedFilter),
+                show = self.options.equipped_filter
+            )
+        )?;
+        if self.searching {
+            print_line!(
+                "{}",
+                tr!("inventory.search_typing", search = self.options.search)
+            )?;
+        } else {
+            print_line!(
+                "{}",
+                tr!(
+                    "inventory.search",
+                    key = get_key_display(KeyAction::Search),
+                    search = self.options.search
+                )
+            )?;
+        }
+        Ok(())
     }
+}
 
-    Ok(false)
+fn menu_confirm_item_deletion(item_name: &str, rarity: &ItemRarity) -> io::Result<bool> {
+    let mut dialog = ConfirmDialog::new(1, || {
+        print_text!("{}", tr!("inventory.delete_item_prefix"))?;
+        print_rarity_text(rarity, item_name)?;
+        reset_text_color()?;
+        print_text!("{}", tr!("inventory.confirm_delete_suffix"))
+    });
+    open_modal(&mut dialog)
 }
@@ -1,85 +1,83 @@
-use crossterm::{
-    cursor::{self, Hide, Show},
-    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, Clear, ClearType},
-};
-use std::io::{self, Write};
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
+use std::io::{self};
 
 use crate::{
+    art::{display_art, get_class_art, ART_MARGIN, ART_TITLE},
     character::{
         create_new_game_character, delete_game_character, get_character_skill,
         get_character_skill_description, get_class_starting_stats, load_game_character,
         max_game_characters_reached, CharacterClass,
     },
     config::GameConfig,
+    frontend::{clear, clear_below, move_to},
     game::save_game,
-    menu::dungeon::menu_start_dungeon_floor,
+    i18n::{get_missing_message_count, translate_all},
+    keymap::{is_key_action, KeyAction},
+    layout::print_wrapped,
+    menu::{
+        daily::menu_daily_challenge,
+        dungeon::menu_start_dungeon_floor,
+        widget::{draw_key_hints, menu_confirm, SelectList, TextInput},
+    },
+    print_line,
+    replay::read_event,
     session::Player,
+    tr,
     util::extract_first_word,
     validation::{character_name_already_exists, character_name_empty, character_name_too_long},
 };
 
-fn print_ascii_title(mut out: &io::Stdout) -> io::Result<()> {
-    println!("||||||||  ||||||  |||||    |||     |||  ||  |||   ||   ||||||   ||           |||||    ||||||    ||||||  ");
-    execute!(out, cursor::MoveTo(0, 1))?;
-    println!("   ||     ||      ||   ||  ||||| |||||  ||  ||||  ||  ||    ||  ||           ||   ||  ||   ||  ||       ");
-    execute!(out, cursor::MoveTo(0, 2))?;
-    println!("   ||     ||||||  |||||    ||  |||  ||  ||  || || ||  ||||||||  ||           |||||    ||||||   ||  |||  ");
-    execute!(out, cursor::MoveTo(0, 3))?;
-    println!("   ||     ||      ||   ||  ||       ||  ||  ||  ||||  ||    ||  ||           ||   ||  ||       ||    || ");
-    execute!(out, cursor::MoveTo(0, 4))?;
-    println!("   ||     ||||||  ||   ||  ||       ||  ||  ||   |||  ||    ||  ||||||       ||   ||  ||        ||||||\n");
-    execute!(out, cursor::MoveTo(0, 6))?;
+/// Prints the title to the first 5 rows. Plain text is used if the title doesn't fit.
+fn print_ascii_title() -> io::Result<()> {
+    if !display_art(ART_TITLE, 0, 0)? {
+        move_to(0, 2)?;
+        print_line!("TERMINAL RPG")?;
+    }
+    move_to(0, 6)?;
     Ok(())
 }
 
 /// Returned bool is true if the menu should be rerendered.
 pub fn main_menu(player: &mut Player, cfg: &GameConfig) -> io::Result<bool> {
-    let mut stdout = io::stdout();
-    let menu_items = vec!["Load Game", "New Game", "Credits", "Quit Game"];
-    let mut selected_index = 0;
+    let menu_items = vec![
+        "menu.main.load_game",
+        "menu.main.new_game",
+        "menu.main.daily_challenge",
+        "menu.main.credits",
+        "menu.main.quit_game",
+    ];
+    let mut list = SelectList::new();
     let mut start_column: u16 = 6;
     let mut rerender = false;
     let version = env!("CARGO_PKG_VERSION");
 
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        let _ = print_ascii_title(&stdout);
+        move_to(0, 0)?;
+        let _ = print_ascii_title();
 
         if cfg.dev_mode {
-            println!("Running in development mode");
-            execute!(stdout, cursor::MoveTo(0, 7))?;
+            match get_missing_message_count() {
+                0 => print_line!("{}", tr!("main_menu.dev_mode"))?,
+                missing => print_line!(
+                    "{}",
+                    tr!("main_menu.dev_mode_missing_messages", missing = missing)
+                )?,
+            }
+            move_to(0, 7)?;
             start_column = 7;
         }
 
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
-            } else {
-                println!("  {}", item);
-            }
-        }
+        list.draw(start_column, &translate_all(&menu_items))?;
 
-        execute!(stdout, cursor::MoveTo(0, start_column + 5))?;
-        println!("v{}", version);
+        move_to(0, start_column + menu_items.len() as u16)?;
+        print_line!("v{}", version)?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Enter => {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if list.is_select(code, menu_items.len()) => {
                         break;
                     }
                     _ => {}
@@ -88,8 +86,8 @@ pub fn main_menu(player: &mut Player, cfg: &GameConfig) -> io::Result<bool> {
         }
     }
 
-    match menu_items[selected_index] {
-        "Load Game" => {
+    match menu_items[list.selected_index] {
+        "menu.main.load_game" => {
             if let Ok(go_back) = menu_load_game(player) {
                 if go_back {
                     rerender = true;
@@ -102,7 +100,7 @@ pub fn main_menu(player: &mut Player, cfg: &GameConfig) -> io::Result<bool> {
                 }
             }
         }
-        "New Game" => {
+        "menu.main.new_game" => {
             if let Ok(go_back) = menu_new_game(player, cfg) {
                 if go_back {
                     rerender = true;
@@ -115,7 +113,11 @@ pub fn main_menu(player: &mut Player, cfg: &GameConfig) -> io::Result<bool> {
                 }
             }
         }
-        "Credits" => {
+        "menu.main.daily_challenge" => {
+            menu_daily_challenge(player)?;
+            rerender = true;
+        }
+        "menu.main.credits" => {
             menu_credits()?;
             rerender = true;
         }
@@ -126,23 +128,27 @@ pub fn main_menu(player: &mut Player, cfg: &GameConfig) -> io::Result<bool> {
 }
 
 fn menu_credits() -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("(Esc) Back");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Credits");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
-        println!("©2024 Juuso Hakala");
-        execute!(stdout, cursor::MoveTo(0, 3))?;
-        println!("Source Code: https://github.com/hollowdll/terminal-game");
-
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("main_menu.credits"))?;
+        move_to(0, 2)?;
+        print_line!("©2024 Juuso Hakala")?;
+        move_to(0, 3)?;
+        print_line!(
+            "{}",
+            tr!(
+                "main_menu.source_code",
+                url = "https://github.com/hollowdll/terminal-game"
+            )
+        )?;
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Esc => break,
+                    code if is_key_action(code, KeyAction::Back) => break,
                     _ => {}
                 }
             }
@@ -154,76 +160,66 @@ fn menu_credits() -> io::Result<()> {
 
 /// Returns true if should go back in menu.
 fn menu_load_game(player: &mut Player) -> io::Result<bool> {
-    let mut stdout = io::stdout();
     let mut menu_items = Vec::new();
-    let mut selected_index = 0;
+    let mut list = SelectList::new();
     let start_column: u16 = 2;
 
-    for (key, val) in &player.data.characters {
-        menu_items.push(format!(
-            "{} (Level {} {:?}, Dungeon Floor {})",
-            key,
-            val.stats.general_stats.character_level,
-            &val.metadata.class,
-            val.stats.general_stats.current_dungeon_floor,
+    let mut characters: Vec<_> = player.data.characters.iter().collect();
+    characters.sort_by_key(|(key, _)| *key);
+    for (key, val) in characters {
+        menu_items.push(tr!(
+            "main_menu.character",
+            name = key,
+            level = val.stats.general_stats.character_level,
+            class = val.metadata.class,
+            floor = val.stats.general_stats.current_dungeon_floor,
         ))
     }
 
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
         if player.data.characters.is_empty() {
-            println!("(Esc) Back");
-            execute!(stdout, cursor::MoveTo(0, 1))?;
-            println!("No characters found");
-            execute!(stdout, cursor::MoveTo(0, 2))?;
+            draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+            move_to(0, 1)?;
+            print_line!("{}", tr!("main_menu.no_characters"))?;
+            move_to(0, 2)?;
         } else {
-            println!("(Esc) = Back, (D) = Delete character");
-            execute!(stdout, cursor::MoveTo(0, 1))?;
-            println!("Select a character");
-            execute!(stdout, cursor::MoveTo(0, 2))?;
+            draw_key_hints(
+                0,
+                &[
+                    (KeyAction::Back, "hint.back"),
+                    (KeyAction::Delete, "hint.delete_character"),
+                ],
+            )?;
+            move_to(0, 1)?;
+            print_line!("{}", tr!("main_menu.select_character"))?;
+            move_to(0, 2)?;
         }
 
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
-            } else {
-                println!("  {}", item);
-            }
-        }
+        list.draw_scrolled(start_column, &menu_items)?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if !menu_items.is_empty() && selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if !menu_items.is_empty() && selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Esc => break,
-                    KeyCode::Enter => {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => break,
+                    code if list.is_select(code, menu_items.len()) => {
                         if !menu_items.is_empty() {
                             let character_name =
-                                extract_first_word(menu_items[selected_index].as_str());
+                                extract_first_word(menu_items[list.selected_index].as_str());
                             load_game_character(character_name, player);
                             return Ok(false);
                         }
                     }
-                    KeyCode::Char('d') | KeyCode::Char('D') => {
+                    code if is_key_action(code, KeyAction::Delete) => {
                         if !menu_items.is_empty() {
-                            let name = extract_first_word(menu_items[selected_index].as_str());
+                            let name = extract_first_word(menu_items[list.selected_index].as_str());
                             let deleted = menu_confirm_character_deletion(player, name)?;
                             if deleted {
-                                menu_items.remove(selected_index);
-                                selected_index = 0;
+                                menu_items.remove(list.selected_index);
+                                list.reset();
                             }
-                            execute!(stdout, Clear(ClearType::All))?;
+                            clear()?;
                         }
                     }
                     _ => {}
@@ -236,73 +232,22 @@ fn menu_load_game(player: &mut Player) -> io::Result<bool> {
 }
 
 fn menu_confirm_character_deletion(player: &mut Player, character_name: &str) -> io::Result<bool> {
-    let mut stdout = io::stdout();
-    let menu_items = vec!["No", "Yes"];
-    let mut selected_index = 0;
-    let start_column: u16 = 1;
-    let mut character_deleted = false;
-
-    execute!(stdout, Clear(ClearType::All))?;
-    loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!(
-            "Delete character {}? It cannot be restored once deleted.",
-            character_name
-        );
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
-            } else {
-                println!("  {}", item);
-            }
-        }
-
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
-            if kind == KeyEventKind::Press {
-                match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Enter => {
-                        break;
-                    }
-                    _ => {}
-                }
-            }
-        }
+    let prompt = tr!("main_menu.delete_character_confirm", name = character_name);
+    if !menu_confirm(&[&prompt])? {
+        return Ok(false);
     }
-
-    match menu_items[selected_index] {
-        "Yes" => {
-            delete_game_character(character_name, player);
-            save_game(player)?;
-            character_deleted = true;
-        }
-        "No" => {}
-        _ => {}
-    }
-
-    Ok(character_deleted)
+    delete_game_character(character_name, player);
+    save_game(player)?;
+    Ok(true)
 }
 
 /// Returns true if menu option "Back" was selected.
 fn menu_new_game(player: &mut Player, cfg: &GameConfig) -> io::Result<bool> {
-    let mut stdout = io::stdout();
-    let menu_items = vec!["Back"];
-    let mut selected_index = 0;
+    let menu_items = vec!["menu.back"];
+    let mut list = SelectList::new();
     let start_column: u16 = 1;
 
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
     if !max_game_characters_reached(player) {
         match menu_create_character(player, cfg) {
             Ok(character_created) => {
@@ -318,33 +263,17 @@ fn menu_new_game(player: &mut Player, cfg: &GameConfig) -> io::Result<bool> {
     }
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("Maximum characters reached. Delete a character to create a new one.");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
-            } else {
-                println!("  {}", item);
-            }
-        }
+        move_to(0, 0)?;
+        print_line!("{}", tr!("main_menu.max_characters"))?;
+        move_to(0, 1)?;
+
+        list.draw(start_column, &translate_all(&menu_items))?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Enter => {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if list.is_select(code, menu_items.len()) => {
                         break;
                     }
                     _ => {}
@@ -353,92 +282,47 @@ fn menu_new_game(player: &mut Player, cfg: &GameConfig) -> io::Result<bool> {
         }
     }
 
-    match menu_items[selected_index] {
-        "Back" => {}
-        _ => {}
-    }
-
-    Ok(true)
+    Ok(menu_items[list.selected_index] == "menu.back")
 }
 
 pub fn menu_create_character(player: &mut Player, cfg: &GameConfig) -> io::Result<bool> {
-    let mut stdout = io::stdout();
-    let menu_items = vec!["Yes", "No"];
-    let mut selected_index = 0;
+    let menu_items = vec!["menu.yes", "menu.no"];
+    let mut list = SelectList::new();
     let start_column: u16 = 4;
-    let mut enter_name_column: u16 = 2;
     let mut character_created = false;
-    let mut msg = "";
-    let mut name = String::new();
-
-    execute!(stdout, cursor::MoveTo(0, 0), Clear(ClearType::All), Show)?;
-    println!("Create a new character");
-    execute!(stdout, cursor::MoveTo(0, 1))?;
-    disable_raw_mode()?;
 
-    loop {
-        let mut is_invalid_name = false;
-        print!("Enter name: ");
-        stdout.flush()?;
-        io::stdin().read_line(&mut name)?;
-        name = name.trim().to_string();
-
-        if character_name_empty(&name) {
-            msg = "Name cannot be blank";
-            is_invalid_name = true;
-        } else if character_name_too_long(&name) {
-            msg = "Name is too long";
-            is_invalid_name = true;
-        } else if character_name_already_exists(player, &name) {
-            msg = "Character with the name already exists";
-            is_invalid_name = true;
-        }
-
-        if is_invalid_name {
-            execute!(stdout, cursor::MoveTo(0, enter_name_column))?;
-            println!("{}", msg);
-            execute!(stdout, cursor::MoveTo(0, enter_name_column + 1))?;
-            enter_name_column += 2;
-            name = "".to_string();
+    clear()?;
+    move_to(0, 0)?;
+    print_line!("{}", tr!("main_menu.create_character"))?;
+    let name = TextInput::new(&tr!("main_menu.enter_name")).read(1, |name| {
+        if character_name_empty(name) {
+            Err(tr!("main_menu.name_blank"))
+        } else if character_name_too_long(name) {
+            Err(tr!("main_menu.name_too_long"))
+        } else if character_name_already_exists(player, name) {
+            Err(tr!("main_menu.name_exists"))
         } else {
-            break;
+            Ok(())
         }
-    }
-    enable_raw_mode()?;
-    execute!(stdout, Clear(ClearType::All), Hide)?;
+    })?;
+    clear()?;
     let selected_class = menu_choose_character_class()?;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("Create the following character?");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Name: {}", name);
-        execute!(stdout, cursor::MoveTo(0, 2))?;
-        println!("Class: {:?}", selected_class);
-
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
-            } else {
-                println!("  {}", item);
-            }
-        }
+        move_to(0, 0)?;
+        print_line!("{}", tr!("main_menu.create_character_confirm"))?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("main_menu.name", name = name))?;
+        move_to(0, 2)?;
+        print_line!("{}", tr!("main_menu.class", class = selected_class))?;
+
+        list.draw(start_column, &translate_all(&menu_items))?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Enter => {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if list.is_select(code, menu_items.len()) => {
                         break;
                     }
                     _ => {}
@@ -447,13 +331,13 @@ pub fn menu_create_character(player: &mut Player, cfg: &GameConfig) -> io::Resul
         }
     }
 
-    match menu_items[selected_index] {
-        "Yes" => {
+    match menu_items[list.selected_index] {
+        "menu.yes" => {
             create_new_game_character(&name, selected_class, player, cfg);
             save_game(player)?;
             character_created = true;
         }
-        "No" => {}
+        "menu.no" => {}
         _ => {}
     }
 
@@ -461,85 +345,93 @@ pub fn menu_create_character(player: &mut Player, cfg: &GameConfig) -> io::Resul
 }
 
 pub fn menu_choose_character_class() -> io::Result<CharacterClass> {
-    let mut stdout = io::stdout();
-    let menu_items = vec!["Mage", "Cleric", "Assassin", "Warrior", "Knight"];
-    let mut selected_index = 0;
+    let classes = [
+        CharacterClass::Mage,
+        CharacterClass::Cleric,
+        CharacterClass::Assassin,
+        CharacterClass::Warrior,
+        CharacterClass::Knight,
+    ];
+    let menu_items: Vec<String> = classes.iter().map(|class| class.to_string()).collect();
+    // the selection marker takes two columns
+    let list_width = menu_items
+        .iter()
+        .map(|item| item.chars().count() as u16 + 2)
+        .max()
+        .unwrap_or(0);
+    let mut list = SelectList::new();
     let start_column: u16 = 1;
 
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("Choose your class");
-
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
-            } else {
-                println!("  {}", item);
-            }
-        }
-        execute!(
-            stdout,
-            cursor::MoveTo(0, start_column + 6),
-            Clear(ClearType::FromCursorDown)
+        move_to(0, 0)?;
+        print_line!("{}", tr!("main_menu.choose_class"))?;
+
+        list.draw(start_column, &menu_items)?;
+        move_to(0, start_column + 6)?;
+        clear_below()?;
+        let selected_class = classes[list.selected_index].clone();
+        display_art(
+            get_class_art(&selected_class),
+            start_column,
+            list_width + ART_MARGIN,
         )?;
-        let selected_class = match menu_items[selected_index] {
-            "Mage" => CharacterClass::Mage,
-            "Cleric" => CharacterClass::Cleric,
-            "Assassin" => CharacterClass::Assassin,
-            "Warrior" => CharacterClass::Warrior,
-            "Knight" => CharacterClass::Knight,
-            _ => CharacterClass::Mage,
-        };
+        move_to(0, start_column + 6)?;
         let skill = get_character_skill(&selected_class);
         let starting_stats = get_class_starting_stats(&selected_class);
 
-        println!("Class: {:?}", selected_class);
-        execute!(stdout, cursor::MoveTo(0, start_column + 7))?;
-        println!("Skill: {}", &skill);
-        execute!(stdout, cursor::MoveTo(0, start_column + 8))?;
-        println!(
-            "Skill Description: {}",
-            get_character_skill_description(&skill)
-        );
-        execute!(stdout, cursor::MoveTo(0, start_column + 9))?;
-        println!("Starting Stats:");
-        execute!(stdout, cursor::MoveTo(0, start_column + 10))?;
-        println!("  Health: {}", starting_stats.max_health);
-        execute!(stdout, cursor::MoveTo(0, start_column + 11))?;
-        println!("  Mana: {}", starting_stats.max_mana);
-        execute!(stdout, cursor::MoveTo(0, start_column + 12))?;
-        println!("  Defense: {}", starting_stats.defense);
-        execute!(stdout, cursor::MoveTo(0, start_column + 13))?;
-        println!("  Damage: {}", starting_stats.damage);
-        execute!(stdout, cursor::MoveTo(0, start_column + 14))?;
-        println!(
-            "  Critical Damage Multiplier: {:.2}",
-            starting_stats.critical_damage_multiplier
-        );
-        execute!(stdout, cursor::MoveTo(0, start_column + 15))?;
-        println!(
-            "  Critical Hit Rate: {:.2} ({:.2}%)",
-            starting_stats.critical_hit_rate,
-            starting_stats.critical_hit_rate * 100.0
-        );
-
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        print_line!("{}", tr!("main_menu.class", class = selected_class))?;
+        move_to(0, start_column + 7)?;
+        print_line!("{}", tr!("main_menu.skill", skill = skill))?;
+        let description_rows = print_wrapped(
+            start_column + 8,
+            "",
+            &tr!(
+                "main_menu.skill_description",
+                description = get_character_skill_description(&skill)
+            ),
+        )?;
+        let row = start_column + 8 + description_rows;
+        move_to(0, row)?;
+        print_line!("{}", tr!("main_menu.starting_stats"))?;
+        move_to(0, row + 1)?;
+        print_line!(
+            "  {}",
+            tr!("stats.health", value = starting_stats.max_health)
+        )?;
+        move_to(0, row + 2)?;
+        print_line!("  {}", tr!("stats.mana", value = starting_stats.max_mana))?;
+        move_to(0, row + 3)?;
+        print_line!("  {}", tr!("stats.defense", value = starting_stats.defense))?;
+        move_to(0, row + 4)?;
+        print_line!("  {}", tr!("stats.damage", value = starting_stats.damage))?;
+        move_to(0, row + 5)?;
+        print_line!(
+            "  {}",
+            tr!(
+                "stats.critical_damage_multiplier",
+                value = format!("{:.2}", starting_stats.critical_damage_multiplier)
+            )
+        )?;
+        move_to(0, row + 6)?;
+        print_line!(
+            "  {}",
+            tr!(
+                "stats.critical_hit_rate_percent",
+                value = format!("{:.2}", starting_stats.critical_hit_rate),
+                percent = format!("{:.2}", starting_stats.critical_hit_rate * 100.0)
+            )
+        )?;
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
+                    code if list.handle_key(code, menu_items.len()) => {
+                        // portraits have different widths
+                        clear()?;
                     }
-                    KeyCode::Enter => {
-                        execute!(stdout, Clear(ClearType::All))?;
+                    code if list.is_select(code, menu_items.len()) => {
+                        clear()?;
                         return Ok(selected_class);
                     }
                     _ => {}
@@ -550,178 +442,155 @@ pub fn menu_choose_character_class() -> io::Result<CharacterClass> {
 }
 
 pub fn menu_tutorial() -> io::Result<()> {
-    let mut stdout = io::stdout();
-    let menu_items = vec!["Continue", "Skip Tutorial"];
-    let mut selected_index = 0;
+    let menu_items = vec!["menu.continue", "menu.tutorial.skip"];
+    let mut list = SelectList::new();
     let mut page: u8 = 1;
 
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("[Tutorial]");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
+        move_to(0, 0)?;
+        print_line!("{}", tr!("tutorial.title"))?;
+        move_to(0, 1)?;
 
         let start_column: u16 = match page {
             1 => {
-                println!("About the game");
-                execute!(stdout, cursor::MoveTo(0, 3))?;
-                println!("This game is a fantasy RPG game that you play in your terminal.");
-                execute!(stdout, cursor::MoveTo(0, 4))?;
-                println!("The goal is to build your character as strong as possible");
-                execute!(stdout, cursor::MoveTo(0, 5))?;
-                println!("and get as far in the dungeon as you can.");
+                print_line!("{}", tr!("tutorial.about.title"))?;
+                move_to(0, 3)?;
+                print_line!("{}", tr!("tutorial.about.line1"))?;
+                move_to(0, 4)?;
+                print_line!("{}", tr!("tutorial.about.line2"))?;
+                move_to(0, 5)?;
+                print_line!("{}", tr!("tutorial.about.line3"))?;
                 7
             }
             2 => {
-                println!("How to play");
-                execute!(stdout, cursor::MoveTo(0, 3))?;
-                println!("You play with your keyboard. No mouse required.");
-                execute!(stdout, cursor::MoveTo(0, 4))?;
-                println!("In the game you travel in dungeon floors defeating enemies.");
-                execute!(stdout, cursor::MoveTo(0, 5))?;
-                println!("Enemy fights are turn based.");
-                execute!(stdout, cursor::MoveTo(0, 6))?;
-                println!("Enemies drop items, gold and EXP.");
-                execute!(stdout, cursor::MoveTo(0, 7))?;
-                println!(
-                    "Defeating the boss enemy of the floor allows you to enter the next floor."
-                );
-                execute!(stdout, cursor::MoveTo(0, 8))?;
-                println!(
-                    "Your progress will be saved when you complete the current dungeon floor."
-                );
+                print_line!("{}", tr!("tutorial.how_to_play.title"))?;
+                move_to(0, 3)?;
+                print_line!("{}", tr!("tutorial.how_to_play.line1"))?;
+                move_to(0, 4)?;
+                print_line!("{}", tr!("tutorial.how_to_play.line2"))?;
+                move_to(0, 5)?;
+                print_line!("{}", tr!("tutorial.how_to_play.line3"))?;
+                move_to(0, 6)?;
+                print_line!("{}", tr!("tutorial.how_to_play.line4"))?;
+                move_to(0, 7)?;
+                print_line!("{}", tr!("tutorial.how_to_play.line5"))?;
+                move_to(0, 8)?;
+                print_line!("{}", tr!("tutorial.how_to_play.line6"))?;
                 10
             }
             3 => {
-                println!("Characters");
-                execute!(stdout, cursor::MoveTo(0, 3))?;
-                println!("You can create different characters with different classes.");
-                execute!(stdout, cursor::MoveTo(0, 4))?;
-                println!("Each class has different starting stats and grows differently.");
-                execute!(stdout, cursor::MoveTo(0, 5))?;
-                println!("Each class also has a unique skill that you can use in enemy fights.");
-                execute!(stdout, cursor::MoveTo(0, 6))?;
-                println!(
-                    "Characters level up when enough EXP is gained. Leveling up increases stats."
-                );
-                execute!(stdout, cursor::MoveTo(0, 7))?;
-                println!("You can have 5 characters active at a time.");
+                print_line!("{}", tr!("tutorial.characters.title"))?;
+                move_to(0, 3)?;
+                print_line!("{}", tr!("tutorial.characters.line1"))?;
+                move_to(0, 4)?;
+                print_line!("{}", tr!("tutorial.characters.line2"))?;
+                move_to(0, 5)?;
+                print_line!("{}", tr!("tutorial.characters.line3"))?;
+                move_to(0, 6)?;
+                print_line!("{}", tr!("tutorial.characters.line4"))?;
+                move_to(0, 7)?;
+                print_line!("{}", tr!("tutorial.characters.line5"))?;
                 9
             }
             4 => {
-                println!("Combat Stats");
-                execute!(stdout, cursor::MoveTo(0, 3))?;
-                println!("Damage: The amount of damage you deal to enemies when attacking");
-                execute!(stdout, cursor::MoveTo(0, 4))?;
-                println!("Defense: Decreases the amount of damage taken from enemy attacks");
-                execute!(stdout, cursor::MoveTo(0, 5))?;
-                println!("Health: The total amount of damage you can take from enemy attacks before dying");
-                execute!(stdout, cursor::MoveTo(0, 6))?;
-                println!("Mana: Needed to use skills");
-                execute!(stdout, cursor::MoveTo(0, 7))?;
-                println!("Critical Damage Multiplier: The damage multiplier of critical hits");
-                execute!(stdout, cursor::MoveTo(0, 8))?;
-                println!("Critical Hit Rate: The chance to land a critical hit when attacking");
+                print_line!("{}", tr!("tutorial.combat_stats.title"))?;
+                move_to(0, 3)?;
+                print_line!("{}", tr!("tutorial.combat_stats.line1"))?;
+                move_to(0, 4)?;
+                print_line!("{}", tr!("tutorial.combat_stats.line2"))?;
+                move_to(0, 5)?;
+                print_line!("{}", tr!("tutorial.combat_stats.line3"))?;
+                move_to(0, 6)?;
+                print_line!("{}", tr!("tutorial.combat_stats.line4"))?;
+                move_to(0, 7)?;
+                print_line!("{}", tr!("tutorial.combat_stats.line5"))?;
+                move_to(0, 8)?;
+                print_line!("{}", tr!("tutorial.combat_stats.line6"))?;
                 10
             }
             5 => {
-                println!("Dungeons");
-                execute!(stdout, cursor::MoveTo(0, 3))?;
-                println!("Dungeon floors consist of different rooms.");
-                execute!(stdout, cursor::MoveTo(0, 4))?;
-                println!("Floors and the enemies in them are randomly generated.");
-                execute!(stdout, cursor::MoveTo(0, 5))?;
-                println!("Enemies get stronger on each floor.");
+                print_line!("{}", tr!("tutorial.dungeons.title"))?;
+                move_to(0, 3)?;
+                print_line!("{}", tr!("tutorial.dungeons.line1"))?;
+                move_to(0, 4)?;
+                print_line!("{}", tr!("tutorial.dungeons.line2"))?;
+                move_to(0, 5)?;
+                print_line!("{}", tr!("tutorial.dungeons.line3"))?;
                 7
             }
             6 => {
-                println!("Items");
-                execute!(stdout, cursor::MoveTo(0, 3))?;
-                println!("There are equipment and consumable items.");
-                execute!(stdout, cursor::MoveTo(0, 4))?;
-                println!("Equipment items make your character stronger when equipped.");
-                execute!(stdout, cursor::MoveTo(0, 5))?;
-                println!("Consumables can be used in enemy fights.");
-                execute!(stdout, cursor::MoveTo(0, 6))?;
-                println!("Items have different rarities.");
-                execute!(stdout, cursor::MoveTo(0, 7))?;
-                println!("Item rarities are Common, Uncommon, Rare, Epic, Legendary and Mythical.");
-                execute!(stdout, cursor::MoveTo(0, 8))?;
-                println!("Items can be dropped from enemies or bought in the shop.");
-                execute!(stdout, cursor::MoveTo(0, 9))?;
-                println!("Mythical items are the strongest items");
-                execute!(stdout, cursor::MoveTo(0, 10))?;
-                println!("and can only be dropped from the boss of Ancient Ruins.");
+                print_line!("{}", tr!("tutorial.items.title"))?;
+                move_to(0, 3)?;
+                print_line!("{}", tr!("tutorial.items.line1"))?;
+                move_to(0, 4)?;
+                print_line!("{}", tr!("tutorial.items.line2"))?;
+                move_to(0, 5)?;
+                print_line!("{}", tr!("tutorial.items.line3"))?;
+                move_to(0, 6)?;
+                print_line!("{}", tr!("tutorial.items.line4"))?;
+                move_to(0, 7)?;
+                print_line!("{}", tr!("tutorial.items.line5"))?;
+                move_to(0, 8)?;
+                print_line!("{}", tr!("tutorial.items.line6"))?;
+                move_to(0, 9)?;
+                print_line!("{}", tr!("tutorial.items.line7"))?;
+                move_to(0, 10)?;
+                print_line!("{}", tr!("tutorial.items.line8"))?;
                 12
             }
             7 => {
-                println!("Ancient Ruins");
-                execute!(stdout, cursor::MoveTo(0, 3))?;
-                println!("Ancient ruins is a special type of dungeon.");
-                execute!(stdout, cursor::MoveTo(0, 4))?;
-                println!("You need an Ancient Ruins Key to enter it.");
-                execute!(stdout, cursor::MoveTo(0, 5))?;
-                println!("Ancient Ruins Key has a chance to drop from dungeon bosses.");
-                execute!(stdout, cursor::MoveTo(0, 6))?;
-                println!("Ancient Ruins has a much stronger boss that you need to fight.");
-                execute!(stdout, cursor::MoveTo(0, 7))?;
-                println!(
-                    "The boss has better rewards than normal bosses and can drop mythical items."
-                );
+                print_line!("{}", tr!("tutorial.ancient_ruins.title"))?;
+                move_to(0, 3)?;
+                print_line!("{}", tr!("tutorial.ancient_ruins.line1"))?;
+                move_to(0, 4)?;
+                print_line!("{}", tr!("tutorial.ancient_ruins.line2"))?;
+                move_to(0, 5)?;
+                print_line!("{}", tr!("tutorial.ancient_ruins.line3"))?;
+                move_to(0, 6)?;
+                print_line!("{}", tr!("tutorial.ancient_ruins.line4"))?;
+                move_to(0, 7)?;
+                print_line!("{}", tr!("tutorial.ancient_ruins.line5"))?;
                 9
             }
             8 => {
-                println!("Dying");
-                execute!(stdout, cursor::MoveTo(0, 3))?;
-                println!("This game is permadeath.");
-                execute!(stdout, cursor::MoveTo(0, 4))?;
-                println!("When you die, your character's progress resets");
-                execute!(stdout, cursor::MoveTo(0, 5))?;
-                println!("and you need to start from dungeon floor 1.");
-                execute!(stdout, cursor::MoveTo(0, 6))?;
-                println!("Your character's items also reset on death.");
-                execute!(stdout, cursor::MoveTo(0, 8))?;
-                println!("This game can be challenging and requires some strategy at some points.");
-                execute!(stdout, cursor::MoveTo(0, 9))?;
-                println!("Good luck and have fun!");
+                print_line!("{}", tr!("tutorial.dying.title"))?;
+                move_to(0, 3)?;
+                print_line!("{}", tr!("tutorial.dying.line1"))?;
+                move_to(0, 4)?;
+                print_line!("{}", tr!("tutorial.dying.line2"))?;
+                move_to(0, 5)?;
+                print_line!("{}", tr!("tutorial.dying.line3"))?;
+                move_to(0, 6)?;
+                print_line!("{}", tr!("tutorial.dying.line4"))?;
+                move_to(0, 8)?;
+                print_line!("{}", tr!("tutorial.dying.line5"))?;
+                move_to(0, 9)?;
+                print_line!("{}", tr!("tutorial.dying.line6"))?;
                 11
             }
             _ => 1,
         };
 
-        for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
-            } else {
-                println!("  {}", item);
-            }
-        }
+        list.draw(start_column, &translate_all(&menu_items))?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Enter => match menu_items[selected_index] {
-                        "Skip Tutorial" => break,
-                        "Continue" => {
-                            if page == 8 {
-                                break;
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if list.is_select(code, menu_items.len()) => {
+                        match menu_items[list.selected_index] {
+                            "menu.tutorial.skip" => break,
+                            "menu.continue" => {
+                                if page == 8 {
+                                    break;
+                                }
+                                page += 1;
+                                clear()?;
                             }
-                            page += 1;
-                            execute!(stdout, Clear(ClearType::All))?;
+                            _ => {}
                         }
-                        _ => {}
-                    },
+                    }
                     _ => {}
                 }
             }
@@ -730,3 +599,46 @@ pub fn menu_tutorial() -> io::Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        frontend::MemoryFrontend,
+        game_data::GameData,
+        test_util::{install_frontend, key, test_config},
+    };
+    use crossterm::event::KeyCode;
+
+    #[test]
+    fn main_menu_opens_credits() {
+        let mut events = vec![key(KeyCode::Down); 3];
+        events.extend([key(KeyCode::Enter), key(KeyCode::Esc)]);
+        let frames = install_frontend(MemoryFrontend::new(events));
+        let rerender = main_menu(&mut Player::new(GameData::new()), &test_config()).unwrap();
+        assert!(rerender);
+
+        let frames = frames.borrow();
+        assert_eq!(frames.len(), 5);
+        assert!(frames[0].contains("> Load Game\n  New Game"));
+        assert!(frames[3].contains("  Daily Challenge\n> Credits\n  Quit Game"));
+        assert!(frames[4].starts_with("(Esc) Back\nCredits"));
+    }
+
+    #[test]
+    fn main_menu_quits() {
+        let mut events = vec![key(KeyCode::Down); 4];
+        events.push(key(KeyCode::Enter));
+        let frames = install_frontend(MemoryFrontend::new(events));
+        let rerender = main_menu(&mut Player::new(GameData::new()), &test_config()).unwrap();
+        assert!(!rerender);
+        assert!(frames.borrow()[4].contains("> Quit Game"));
+    }
+    #[test]
+    fn main_menu_quits_with_number_key() {
+        let frames = install_frontend(MemoryFrontend::new(vec![key(KeyCode::Char('5'))]));
+        let rerender = main_menu(&mut Player::new(GameData::new()), &test_config()).unwrap();
+        assert!(!rerender);
+        assert_eq!(frames.borrow().len(), 1);
+    }
+}
@@ -0,0 +1,145 @@
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
+use std::io;
+
+use crate::{
+    frontend::{clear, move_to},
+    keymap::{is_key_action, KeyAction},
+    menu::widget::{draw_key_hints, SelectList},
+    print_line, print_text,
+    quest::{give_quest_reward, Quest, MAX_ACTIVE_QUESTS},
+    replay::read_event,
+    session::PlayerCharacter,
+    theme::ThemeColor,
+    tr,
+    util::{reset_text_color, set_text_color, shift_index_back},
+};
+
+/// Active quests are listed before the quests offered on the board.
+pub fn menu_bounty_board(
+    quest_board: &mut Vec<Quest>,
+    character: &mut PlayerCharacter,
+) -> io::Result<()> {
+    let mut list = SelectList::new();
+    let mut message = "".to_string();
+
+    clear()?;
+    loop {
+        let active_len = character.data.quests.len();
+        let total_len = active_len + quest_board.len();
+        if list.selected_index >= total_len {
+            list.selected_index = shift_index_back(total_len);
+        }
+
+        draw_key_hints(
+            0,
+            &[
+                (KeyAction::Back, "hint.back"),
+                (KeyAction::Select, "hint.accept_quest_claim_reward"),
+                (KeyAction::Abandon, "hint.abandon_quest"),
+            ],
+        )?;
+        move_to(0, 1)?;
+        print_line!(
+            "{}",
+            tr!("quest.board", active = active_len, max = MAX_ACTIVE_QUESTS)
+        )?;
+        move_to(0, 2)?;
+        print_line!("{}", tr!("quest.active"))?;
+        let mut column = 3;
+        if character.data.quests.is_empty() {
+            move_to(0, column)?;
+            print_line!("  {}", tr!("quest.no_active"))?;
+            column += 1;
+        }
+        for (i, quest) in character.data.quests.iter().enumerate() {
+            move_to(0, column)?;
+            display_quest(quest, i == list.selected_index, true)?;
+            column += 1;
+        }
+
+        move_to(0, column + 1)?;
+        print_line!("{}", tr!("quest.offered"))?;
+        column += 2;
+        if quest_board.is_empty() {
+            move_to(0, column)?;
+            print_line!("  {}", tr!("quest.no_offered"))?;
+            column += 1;
+        }
+        for (i, quest) in quest_board.iter().enumerate() {
+            move_to(0, column)?;
+            display_quest(quest, i + active_len == list.selected_index, false)?;
+            column += 1;
+        }
+
+        if !message.is_empty() {
+            move_to(0, column + 1)?;
+            print_line!("{}", message)?;
+        }
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, total_len) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    code if list.is_select(code, total_len) => {
+                        if list.selected_index < active_len {
+                            if character.data.quests[list.selected_index].is_completed() {
+                                let quest = character.data.quests.remove(list.selected_index);
+                                message = give_quest_reward(&quest.reward, character);
+                            } else {
+                                message = tr!("quest.not_completed");
+                            }
+                        } else if list.selected_index < total_len {
+                            if active_len >= MAX_ACTIVE_QUESTS {
+                                message = tr!("quest.too_many", max = MAX_ACTIVE_QUESTS);
+                            } else {
+                                let quest = quest_board.remove(list.selected_index - active_len);
+                                character.data.quests.push(quest);
+                                message = tr!("quest.accepted");
+                            }
+                        }
+                        clear()?;
+                    }
+                    code if is_key_action(code, KeyAction::Abandon)
+                        && list.selected_index < active_len =>
+                    {
+                        character.data.quests.remove(list.selected_index);
+                        message = tr!("quest.abandoned");
+                        clear()?;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
+
+/// Progress is only shown for active quests.
+fn display_quest(quest: &Quest, selected: bool, active: bool) -> io::Result<()> {
+    if selected {
+        print_text!("> ")?;
+    } else {
+        print_text!("  ")?;
+    }
+    print_text!("{}", quest.objective)?;
+    if active {
+        print_text!(
+            " ({}/{})",
+            quest.progress.min(quest.get_goal()),
+            quest.get_goal()
+        )?;
+    }
+    print_text!("   [{}]", tr!("quest.reward", reward = quest.reward))?;
+    if active && quest.is_completed() {
+        set_text_color(ThemeColor::Positive)?;
+        print_text!("{}", tr!("quest.completed_tag"))?;
+        reset_text_color()?;
+    }
+
+    Ok(())
+}
@@ -1,211 +1,519 @@
-use crossterm::{
-    cursor,
-    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
-    execute,
-    terminal::{Clear, ClearType},
-};
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
 use std::io;
 
 use crate::{
-    items::{
-        get_item_display_name, get_item_level_display, get_item_purchase_value, CharacterItem,
-        CharacterItemOwned, ItemRarity,
-    },
-    menu::inventory::{
-        menu_armor_info, menu_consumable_info, menu_inventory, menu_ring_info, menu_weapon_info,
+    frontend::{clear, move_to},
+    i18n::translate_all,
+    items::{get_item_display_name, get_item_level_display, CharacterItemOwned, ItemRarity},
+    keymap::{is_key_action, KeyAction},
+    menu::{
+        companion::menu_companion_offer,
+        comparison::menu_compare_item,
+        inventory::{
+            menu_armor_info, menu_consumable_info, menu_inventory, menu_ring_info, menu_weapon_info,
+        },
+        widget::{draw_key_hints, SelectList},
     },
+    print_line, print_text,
+    replay::read_event,
     session::PlayerCharacter,
-    shop::{buy_consumable, ShopItems},
-    util::{reset_text_color, set_rarity_text_color, shift_index_back},
+    shop::{
+        buy_consumable, get_equipment_purchase_value, get_junk_items, get_junk_sell_value,
+        get_key_trade_items, ShopItems, WanderingMerchant,
+    },
+    tr,
+    util::{print_rarity_text, reset_text_color, set_rarity_text_color, shift_index_back},
 };
 
 pub fn menu_shop(shop_items: &mut ShopItems, character: &mut PlayerCharacter) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    let menu_items = vec!["Buy Items", "Sell Items"];
-    let mut selected_index = 0;
+    let menu_items = [
+        "menu.shop.buy_items",
+        "menu.shop.sell_items",
+        "menu.shop.sell_junk",
+        "menu.shop.buy_back",
+        "menu.shop.buy_companion",
+        "menu.shop.reroll_stock",
+    ];
+    let mut list = SelectList::new();
     let start_column: u16 = 2;
+    let mut message = "".to_string();
 
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("(Esc) Back");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Shop");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("shop.title", gold = character.data.currency.gold))?;
 
         for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            if i == selected_index {
-                println!("> {}", item);
+            move_to(0, i as u16 + start_column)?;
+            if i == list.selected_index {
+                print_text!("> {}", tr!(item))?;
             } else {
-                println!("  {}", item);
+                print_text!("  {}", tr!(item))?;
+            }
+            match *item {
+                "menu.shop.buy_back" => print_line!(
+                    "   [{}]",
+                    tr!("shop.item_count", count = shop_items.buy_back.len())
+                )?,
+                "menu.shop.buy_companion" => match &shop_items.companion {
+                    Some(companion) => print_line!("   [{}]", companion.get_display_name())?,
+                    None => print_line!("   [{}]", tr!("shop.sold_out"))?,
+                },
+                "menu.shop.reroll_stock" => print_line!(
+                    "   [{}]",
+                    tr!("shop.cost", gold = shop_items.get_reroll_cost())
+                )?,
+                _ => print_line!()?,
             }
         }
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if !message.is_empty() {
+            move_to(0, start_column + menu_items.len() as u16 + 1)?;
+            print_line!("{}", message)?;
+        }
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
                     }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
+                    code if list.is_select(code, menu_items.len()) => {
+                        message = "".to_string();
+                        match menu_items[list.selected_index] {
+                            "menu.shop.buy_items" => menu_shop_buy_items(shop_items, character)?,
+                            "menu.shop.sell_items" => menu_inventory(character, Some(shop_items))?,
+                            "menu.shop.sell_junk" => menu_shop_sell_junk(shop_items, character)?,
+                            "menu.shop.buy_back" => menu_shop_buy_back(shop_items, character)?,
+                            "menu.shop.buy_companion" => {
+                                if let Some(companion) = &shop_items.companion {
+                                    let price = companion.get_purchase_value();
+                                    if menu_companion_offer(companion, character, Some(price))? {
+                                        if shop_items.buy_companion(character) {
+                                            message = tr!("shop.companion_joined");
+                                        } else {
+                                            message = tr!("shop.not_enough_gold");
+                                        }
+                                    }
+                                }
+                            }
+                            "menu.shop.reroll_stock" => {
+                                if shop_items.reroll_stock(character) {
+                                    message = tr!("shop.rerolled");
+                                } else {
+                                    message = tr!("shop.not_enough_gold");
+                                }
+                            }
+                            _ => break,
                         }
+                        clear()?;
                     }
-                    KeyCode::Esc => {
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
+
+/// Equipment offers are listed before consumables.
+pub fn menu_shop_buy_items(
+    shop_items: &mut ShopItems,
+    character: &mut PlayerCharacter,
+) -> io::Result<()> {
+    let mut list = SelectList::new();
+    let start_column: u16 = 2;
+
+    clear()?;
+    loop {
+        let mut menu_items = shop_items.equipment.iter().collect::<Vec<_>>();
+        let consumables = shop_items
+            .consumables
+            .iter()
+            .map(|item| CharacterItemOwned::Consumable(item.clone()))
+            .collect::<Vec<_>>();
+        menu_items.extend(consumables.iter());
+        list.clamp(menu_items.len());
+
+        draw_key_hints(
+            0,
+            &[
+                (KeyAction::Back, "hint.back"),
+                (KeyAction::Select, "hint.item_info"),
+                (KeyAction::Compare, "hint.compare"),
+                (KeyAction::Buy, "hint.buy_item"),
+            ],
+        )?;
+        move_to(0, 1)?;
+        print_line!(
+            "{}",
+            tr!("shop.buy_items", gold = character.data.currency.gold)
+        )?;
+
+        for (i, item) in menu_items.iter().enumerate() {
+            move_to(0, i as u16 + start_column)?;
+            display_shop_item(
+                item,
+                get_equipment_purchase_value(item.as_character_item()),
+                i == list.selected_index,
+            )?;
+        }
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
-                    KeyCode::Enter => match menu_items[selected_index] {
-                        "Buy Items" => menu_shop_buy_items(shop_items, character)?,
-                        "Sell Items" => menu_inventory(character, true)?,
-                        _ => break,
-                    },
+                    code if list.is_select(code, menu_items.len()) => {
+                        menu_shop_item_info(menu_items[list.selected_index], character)?
+                    }
+                    code if is_key_action(code, KeyAction::Compare) => menu_compare_item(
+                        menu_items[list.selected_index].as_character_item(),
+                        character,
+                    )?,
+                    code if is_key_action(code, KeyAction::Buy) => {
+                        match menu_items[list.selected_index] {
+                            CharacterItemOwned::Consumable(item) => {
+                                buy_consumable(item, character);
+                                clear()?;
+                            }
+                            _ => {
+                                if shop_items.buy_equipment(list.selected_index, character) {
+                                    clear()?;
+                                }
+                            }
+                        }
+                    }
                     _ => {}
                 }
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
 
-pub fn menu_shop_buy_items(
+/// Items sold on the current floor can be bought back for the price they were sold for.
+pub fn menu_shop_buy_back(
     shop_items: &mut ShopItems,
     character: &mut PlayerCharacter,
 ) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    let mut menu_items = vec![];
-    let mut selected_index = 0;
+    let mut list = SelectList::new();
     let start_column: u16 = 2;
 
-    if let Some(item) = &shop_items.weapon {
-        menu_items.push(CharacterItemOwned::Weapon(item.clone()));
+    clear()?;
+    loop {
+        if list.selected_index >= shop_items.buy_back.len() {
+            list.selected_index = shift_index_back(shop_items.buy_back.len());
+        }
+
+        draw_key_hints(
+            0,
+            &[
+                (KeyAction::Back, "hint.back"),
+                (KeyAction::Select, "hint.item_info"),
+                (KeyAction::Compare, "hint.compare"),
+                (KeyAction::Buy, "hint.buy_back_item"),
+            ],
+        )?;
+        move_to(0, 1)?;
+        print_line!(
+            "{}",
+            tr!("shop.buy_back", gold = character.data.currency.gold)
+        )?;
+        move_to(0, start_column)?;
+
+        if shop_items.buy_back.is_empty() {
+            print_line!("  {}", tr!("shop.no_items_sold"))?;
+        }
+
+        for (i, buy_back) in shop_items.buy_back.iter().enumerate() {
+            move_to(0, i as u16 + start_column)?;
+            display_shop_item(&buy_back.item, buy_back.price, i == list.selected_index)?;
+        }
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, shop_items.buy_back.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    code if list.is_select(code, shop_items.buy_back.len()) => {
+                        if let Some(buy_back) = shop_items.buy_back.get(list.selected_index) {
+                            menu_shop_item_info(&buy_back.item, character)?;
+                        }
+                    }
+                    code if is_key_action(code, KeyAction::Compare) => {
+                        if let Some(buy_back) = shop_items.buy_back.get(list.selected_index) {
+                            menu_compare_item(buy_back.item.as_character_item(), character)?;
+                        }
+                    }
+                    code if is_key_action(code, KeyAction::Buy) => {
+                        let bought = shop_items.buy_back(list.selected_index, character);
+                        if bought {
+                            clear()?;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
+
+fn menu_shop_item_info(item: &CharacterItemOwned, character: &PlayerCharacter) -> io::Result<()> {
+    match item {
+        CharacterItemOwned::Consumable(item) => menu_consumable_info(item, false),
+        CharacterItemOwned::Weapon(item) => menu_weapon_info(item, character, false),
+        CharacterItemOwned::Armor(item) => menu_armor_info(item, character, false),
+        CharacterItemOwned::Ring(item) => menu_ring_info(item, character, false),
+        _ => Ok(()),
+    }
+}
+
+fn display_shop_item(item: &CharacterItemOwned, price: u32, selected: bool) -> io::Result<()> {
+    let (lvl, rarity) = match item {
+        CharacterItemOwned::Consumable(item) => (0, &item.rarity),
+        CharacterItemOwned::Weapon(item) => (item.level, &item.rarity),
+        CharacterItemOwned::Armor(item) => (item.level, &item.rarity),
+        CharacterItemOwned::Ring(item) => (item.level, &item.rarity),
+        _ => (0, &ItemRarity::Unknown),
+    };
+    if selected {
+        print_text!("> ")?;
+    } else {
+        print_text!("  ")?;
     }
-    if let Some(item) = &shop_items.armor {
-        menu_items.push(CharacterItemOwned::Armor(item.clone()));
+    print_rarity_text(rarity, get_item_display_name(item.as_character_item()))?;
+    reset_text_color()?;
+    if lvl > 0 {
+        print_text!(" {}", get_item_level_display(lvl))?;
     }
-    if let Some(item) = &shop_items.ring {
-        menu_items.push(CharacterItemOwned::Ring(item.clone()));
+    print_text!("   [{}]", tr!("shop.cost", gold = price))?;
+
+    Ok(())
+}
+
+/// Sells all unlocked and unequipped weapons, armors and rings at or below the selected rarity.
+pub fn menu_shop_sell_junk(
+    shop_items: &mut ShopItems,
+    character: &mut PlayerCharacter,
+) -> io::Result<()> {
+    let menu_items = [
+        ItemRarity::Common,
+        ItemRarity::Uncommon,
+        ItemRarity::Rare,
+        ItemRarity::Epic,
+        ItemRarity::Legendary,
+        ItemRarity::Mythical,
+    ];
+    let mut list = SelectList::new();
+    let start_column: u16 = 3;
+    let mut sale_summary = "".to_string();
+
+    clear()?;
+    loop {
+        draw_key_hints(
+            0,
+            &[
+                (KeyAction::Back, "hint.back"),
+                (KeyAction::Select, "hint.sell_junk"),
+            ],
+        )?;
+        move_to(0, 1)?;
+        print_line!(
+            "{}",
+            tr!("shop.sell_junk", gold = character.data.currency.gold)
+        )?;
+        move_to(0, 2)?;
+        print_line!("{}", tr!("shop.sell_junk_info"))?;
+
+        for (i, rarity) in menu_items.iter().enumerate() {
+            move_to(0, i as u16 + start_column)?;
+            let junk_items = get_junk_items(character, rarity);
+            if i == list.selected_index {
+                print_text!("> ")?;
+            } else {
+                print_text!("  ")?;
+            }
+            set_rarity_text_color(rarity)?;
+            print_text!("{}", rarity)?;
+            reset_text_color()?;
+            print_text!(
+                "   [{}]",
+                tr!(
+                    "shop.junk_summary",
+                    count = junk_items.len(),
+                    gold = get_junk_sell_value(&junk_items)
+                )
+            )?;
+        }
+
+        if !sale_summary.is_empty() {
+            move_to(0, start_column + menu_items.len() as u16 + 1)?;
+            print_line!("{}", sale_summary)?;
+        }
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    code if list.is_select(code, menu_items.len()) => {
+                        let sale =
+                            shop_items.sell_junk(character, &menu_items[list.selected_index]);
+                        sale_summary =
+                            tr!("shop.junk_sold", count = sale.items_sold, gold = sale.gold);
+                        clear()?;
+                    }
+                    _ => {}
+                }
+            }
+        }
     }
+    clear()?;
+
+    Ok(())
+}
+
+/// The merchant leaves the floor after this menu is closed.
+pub fn menu_wandering_merchant(
+    merchant: &mut WanderingMerchant,
+    character: &mut PlayerCharacter,
+) -> io::Result<()> {
+    let mut list = SelectList::new();
+    let start_column: u16 = 3;
+
+    clear()?;
+    loop {
+        let mut menu_items = vec!["menu.shop.buy_items"];
+        if merchant.key_trade {
+            menu_items.push("menu.shop.trade_for_key");
+        }
+        list.clamp(menu_items.len());
 
-    for item in &shop_items.consumables {
-        menu_items.push(CharacterItemOwned::Consumable(item.clone()));
+        draw_key_hints(0, &[(KeyAction::Back, "hint.leave")])?;
+        move_to(0, 1)?;
+        print_line!(
+            "{}",
+            tr!("shop.merchant", gold = character.data.currency.gold)
+        )?;
+        move_to(0, 2)?;
+        print_line!("{}", tr!("shop.merchant_info"))?;
+
+        list.draw(start_column, &translate_all(&menu_items))?;
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    code if list.is_select(code, menu_items.len()) => {
+                        match menu_items[list.selected_index] {
+                            "menu.shop.buy_items" => {
+                                menu_shop_buy_items(&mut merchant.shop_items, character)?
+                            }
+                            "menu.shop.trade_for_key" => {
+                                menu_merchant_key_trade(merchant, character)?
+                            }
+                            _ => break,
+                        }
+                        clear()?;
+                    }
+                    _ => {}
+                }
+            }
+        }
     }
+    clear()?;
+
+    Ok(())
+}
+
+/// Lists the items the merchant accepts in exchange for an Ancient Ruins Key.
+fn menu_merchant_key_trade(
+    merchant: &mut WanderingMerchant,
+    character: &mut PlayerCharacter,
+) -> io::Result<()> {
+    let mut list = SelectList::new();
+    let start_column: u16 = 3;
+    let menu_items = get_key_trade_items(character);
 
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("(Esc) Back, (Enter) Item Info, (B) Buy Item");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Buy Items (Gold: {})", character.data.currency.gold);
+        draw_key_hints(
+            0,
+            &[
+                (KeyAction::Back, "hint.back"),
+                (KeyAction::Select, "hint.item_info"),
+                (KeyAction::Trade, "hint.trade_item"),
+            ],
+        )?;
+        move_to(0, 1)?;
+        print_line!(
+            "{}",
+            tr!(
+                "shop.key_trade",
+                keys = character.data.inventory.ancient_ruins_keys
+            )
+        )?;
+        move_to(0, 2)?;
+        print_line!("{}", tr!("shop.key_trade_info"))?;
+        move_to(0, start_column)?;
+
+        if menu_items.is_empty() {
+            print_line!("  {}", tr!("shop.no_items_to_trade"))?;
+        }
 
         for (i, item) in menu_items.iter().enumerate() {
-            execute!(stdout, cursor::MoveTo(0, i as u16 + start_column))?;
-            let (name, lvl, rarity, purchase_value) = match item {
-                CharacterItemOwned::Consumable(item) => (
-                    get_item_display_name(CharacterItem::Consumable(item)),
-                    0,
-                    &item.rarity,
-                    get_item_purchase_value(&item.rarity),
-                ),
-                CharacterItemOwned::Weapon(item) => (
-                    get_item_display_name(CharacterItem::Weapon(item)),
-                    item.level,
-                    &item.rarity,
-                    get_item_purchase_value(&item.rarity),
-                ),
-                CharacterItemOwned::Armor(item) => (
-                    get_item_display_name(CharacterItem::Armor(item)),
-                    item.level,
-                    &item.rarity,
-                    get_item_purchase_value(&item.rarity),
-                ),
-                CharacterItemOwned::Ring(item) => (
-                    get_item_display_name(CharacterItem::Ring(item)),
-                    item.level,
-                    &item.rarity,
-                    get_item_purchase_value(&item.rarity),
-                ),
-                _ => ("?Unknown?".to_string(), 0, &ItemRarity::Unknown, 0),
+            move_to(0, i as u16 + start_column)?;
+            let (lvl, rarity) = match item {
+                CharacterItemOwned::Weapon(item) => (item.level, &item.rarity),
+                CharacterItemOwned::Armor(item) => (item.level, &item.rarity),
+                CharacterItemOwned::Ring(item) => (item.level, &item.rarity),
+                _ => continue,
             };
-
-            if i == selected_index {
-                print!("> ");
-                set_rarity_text_color(rarity)?;
-                print!("{}", name);
-                reset_text_color()?;
-                if lvl > 0 {
-                    print!(" {}", get_item_level_display(lvl));
-                }
-                print!("   [Cost: {} Gold]", purchase_value);
+            if i == list.selected_index {
+                print_text!("> ")?;
             } else {
-                set_rarity_text_color(rarity)?;
-                print!("  {}", name);
-                reset_text_color()?;
-                if lvl > 0 {
-                    print!(" {}", get_item_level_display(lvl));
-                }
-                print!("   [Cost: {} Gold]", purchase_value);
+                print_text!("  ")?;
             }
+            print_rarity_text(rarity, get_item_display_name(item.as_character_item()))?;
             reset_text_color()?;
+            print_text!(" {}", get_item_level_display(lvl))?;
         }
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
                     }
-                    KeyCode::Down => {
-                        if selected_index < menu_items.len() - 1 {
-                            selected_index += 1;
+                    code if list.is_select(code, menu_items.len()) => {
+                        if let Some(item) = menu_items.get(list.selected_index) {
+                            menu_shop_item_info(item, character)?;
                         }
                     }
-                    KeyCode::Esc => {
-                        break;
-                    }
-                    KeyCode::Enter => match &menu_items[selected_index] {
-                        CharacterItemOwned::Consumable(item) => menu_consumable_info(item, false)?,
-                        CharacterItemOwned::Weapon(item) => menu_weapon_info(item, false)?,
-                        CharacterItemOwned::Armor(item) => menu_armor_info(item, false)?,
-                        CharacterItemOwned::Ring(item) => menu_ring_info(item, false)?,
-                        _ => {}
-                    },
-                    KeyCode::Char('B') | KeyCode::Char('b') => {
-                        if !menu_items.is_empty() {
-                            match &menu_items[selected_index] {
-                                CharacterItemOwned::Consumable(item) => {
-                                    buy_consumable(item, character);
-                                    execute!(stdout, Clear(ClearType::All))?;
-                                }
-                                CharacterItemOwned::Weapon(_) => {
-                                    if shop_items.buy_weapon(character) {
-                                        menu_items.remove(selected_index);
-                                        selected_index = shift_index_back(selected_index);
-                                        execute!(stdout, Clear(ClearType::All))?;
-                                    }
-                                }
-                                CharacterItemOwned::Armor(_) => {
-                                    if shop_items.buy_armor(character) {
-                                        menu_items.remove(selected_index);
-                                        selected_index = shift_index_back(selected_index);
-                                        execute!(stdout, Clear(ClearType::All))?;
-                                    }
-                                }
-                                CharacterItemOwned::Ring(_) => {
-                                    if shop_items.buy_ring(character) {
-                                        menu_items.remove(selected_index);
-                                        selected_index = shift_index_back(selected_index);
-                                        execute!(stdout, Clear(ClearType::All))?;
-                                    }
-                                }
-                                _ => {}
+                    code if is_key_action(code, KeyAction::Trade) => {
+                        if let Some(item) = menu_items.get(list.selected_index) {
+                            if merchant.trade_for_key(item, character) {
+                                break;
                             }
                         }
                     }
@@ -214,7 +522,7 @@ pub fn menu_shop_buy_items(
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
@@ -1,39 +1,42 @@
 use crate::{
     character::{get_character_skill, get_character_skill_description, SKILL_MANA_COST},
+    frontend::{clear, move_to},
+    keymap::{is_key_action, KeyAction},
+    layout::print_wrapped,
+    menu::widget::draw_key_hints,
+    print_line,
+    replay::read_event,
     session::PlayerCharacter,
+    tr,
 };
-use crossterm::{
-    cursor,
-    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
-    execute,
-    terminal::{Clear, ClearType},
-};
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
 use std::io;
 
 pub fn menu_skill(character: &PlayerCharacter) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     loop {
         let skill = get_character_skill(&character.data.metadata.class);
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("(Esc) Back");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Skill");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
-        println!("  Name: {}", &skill);
-        execute!(stdout, cursor::MoveTo(0, 3))?;
-        println!(
-            "  Description: {}",
-            &get_character_skill_description(&skill)
-        );
-        execute!(stdout, cursor::MoveTo(0, 4))?;
-        println!("  Mana Cost: {}", SKILL_MANA_COST);
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("skill.title"))?;
+        move_to(0, 2)?;
+        print_line!("  {}", tr!("stats.name", name = skill))?;
+        let description_rows = print_wrapped(
+            3,
+            "  ",
+            &tr!(
+                "item.description",
+                description = get_character_skill_description(&skill)
+            ),
+        )?;
+        move_to(0, 3 + description_rows)?;
+        print_line!("  {}", tr!("skill.mana_cost", cost = SKILL_MANA_COST))?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Esc => {
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
                     _ => {}
@@ -41,7 +44,7 @@ pub fn menu_skill(character: &PlayerCharacter) -> io::Result<()> {
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
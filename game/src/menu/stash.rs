@@ -0,0 +1,217 @@
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
+use std::io;
+
+use crate::{
+    frontend::{clear, move_to},
+    game::save_game,
+    game_data::STASH_CAPACITY,
+    i18n::translate_all,
+    items::{get_item_display_name, get_item_level_display, CharacterItemOwned},
+    keymap::{is_key_action, KeyAction},
+    menu::widget::{draw_key_hints, SelectList},
+    print_line, print_text,
+    replay::read_event,
+    session::Player,
+    stash::{
+        deposit_armor, deposit_ring, deposit_weapon, get_depositable_items, get_stash_items,
+        withdraw_armor, withdraw_ring, withdraw_weapon,
+    },
+    tr,
+    util::{print_rarity_text, reset_text_color},
+};
+
+enum StashAction {
+    Deposit,
+    Withdraw,
+}
+
+pub fn menu_stash(player: &mut Player) -> io::Result<()> {
+    let menu_items = vec!["menu.stash.deposit_items", "menu.stash.withdraw_items"];
+    let mut list = SelectList::new();
+    let start_column: u16 = 2;
+
+    clear()?;
+    loop {
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!(
+            "{}",
+            tr!(
+                "stash.title",
+                count = player.data.stash.len(),
+                capacity = STASH_CAPACITY
+            )
+        )?;
+
+        list.draw(start_column, &translate_all(&menu_items))?;
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    code if list.is_select(code, menu_items.len()) => {
+                        match menu_items[list.selected_index] {
+                            "menu.stash.deposit_items" => {
+                                menu_stash_item_list(player, StashAction::Deposit)?
+                            }
+                            "menu.stash.withdraw_items" => {
+                                menu_stash_item_list(player, StashAction::Withdraw)?
+                            }
+                            _ => break,
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
+
+/// The game is saved after every deposit and withdrawal
+/// so the stash and the character can't get out of sync.
+fn menu_stash_item_list(player: &mut Player, action: StashAction) -> io::Result<()> {
+    let mut list = SelectList::new();
+    let start_column: u16 = 2;
+    let mut message = "".to_string();
+
+    clear()?;
+    loop {
+        let (character, stash) = player.get_character_and_stash_mut()?;
+        let menu_items = match action {
+            StashAction::Deposit => get_depositable_items(character),
+            StashAction::Withdraw => get_stash_items(stash),
+        };
+        list.clamp(menu_items.len());
+        let visible_items = list.get_visible_items(menu_items.len(), start_column)?;
+
+        match action {
+            StashAction::Deposit => {
+                draw_key_hints(
+                    0,
+                    &[
+                        (KeyAction::Back, "hint.back"),
+                        (KeyAction::Select, "hint.deposit_item"),
+                    ],
+                )?;
+                move_to(0, 1)?;
+                print_text!("{}", tr!("menu.stash.deposit_items"))?;
+            }
+            StashAction::Withdraw => {
+                draw_key_hints(
+                    0,
+                    &[
+                        (KeyAction::Back, "hint.back"),
+                        (KeyAction::Select, "hint.withdraw_item"),
+                    ],
+                )?;
+                move_to(0, 1)?;
+                print_text!("{}", tr!("menu.stash.withdraw_items"))?;
+            }
+        }
+        print_line!(
+            " ({})",
+            tr!(
+                "stash.capacity",
+                count = stash.len(),
+                capacity = STASH_CAPACITY
+            )
+        )?;
+        move_to(0, start_column)?;
+
+        if menu_items.is_empty() {
+            match action {
+                StashAction::Deposit => print_line!("  {}", tr!("blacksmith.no_items"))?,
+                StashAction::Withdraw => print_line!("  {}", tr!("stash.no_items"))?,
+            }
+        }
+
+        for (i, item) in menu_items
+            .iter()
+            .enumerate()
+            .skip(visible_items.start)
+            .take(visible_items.len())
+        {
+            move_to(0, (i - visible_items.start) as u16 + start_column)?;
+            let (lvl, rarity, equipped) = match item {
+                CharacterItemOwned::Weapon(item) => {
+                    (item.level, &item.rarity, item.is_equipped(character))
+                }
+                CharacterItemOwned::Armor(item) => {
+                    (item.level, &item.rarity, item.is_equipped(character))
+                }
+                CharacterItemOwned::Ring(item) => {
+                    (item.level, &item.rarity, item.is_equipped(character))
+                }
+                _ => continue,
+            };
+            if i == list.selected_index {
+                print_text!("> ")?;
+            } else {
+                print_text!("  ")?;
+            }
+            print_rarity_text(rarity, get_item_display_name(item.as_character_item()))?;
+            reset_text_color()?;
+            print_text!(" {}", get_item_level_display(lvl))?;
+            if equipped {
+                print_text!("{}", tr!("inventory.equipped_tag"))?;
+            }
+        }
+        list.draw_position(&visible_items, menu_items.len(), start_column)?;
+
+        if !message.is_empty() {
+            move_to(0, start_column + visible_items.len() as u16 + 2)?;
+            print_line!("{}", message)?;
+        }
+
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                match code {
+                    code if list.handle_key(code, menu_items.len()) => {}
+                    code if is_key_action(code, KeyAction::Back) => {
+                        break;
+                    }
+                    code if list.is_select(code, menu_items.len()) && !menu_items.is_empty() => {
+                        let moved = match (&action, &menu_items[list.selected_index]) {
+                            (StashAction::Deposit, CharacterItemOwned::Weapon(item)) => {
+                                deposit_weapon(stash, character, &item.id)
+                            }
+                            (StashAction::Deposit, CharacterItemOwned::Armor(item)) => {
+                                deposit_armor(stash, character, &item.id)
+                            }
+                            (StashAction::Deposit, CharacterItemOwned::Ring(item)) => {
+                                deposit_ring(stash, character, &item.id)
+                            }
+                            (StashAction::Withdraw, CharacterItemOwned::Weapon(item)) => {
+                                withdraw_weapon(stash, character, &item.id)
+                            }
+                            (StashAction::Withdraw, CharacterItemOwned::Armor(item)) => {
+                                withdraw_armor(stash, character, &item.id)
+                            }
+                            (StashAction::Withdraw, CharacterItemOwned::Ring(item)) => {
+                                withdraw_ring(stash, character, &item.id)
+                            }
+                            _ => false,
+                        };
+                        if moved {
+                            save_game(player)?;
+                            message = "".to_string();
+                        } else if stash.is_full() {
+                            message = tr!("stash.full");
+                        }
+                        clear()?;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    clear()?;
+
+    Ok(())
+}
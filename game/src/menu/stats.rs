@@ -1,105 +1,156 @@
-use crossterm::{
-    cursor,
-    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
-    execute,
-    terminal::{Clear, ClearType},
-};
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
 use std::io;
 
-use crate::{session::PlayerCharacter, util::timestamp_to_datetime};
+use crate::{
+    frontend::{clear, move_to},
+    keymap::{is_key_action, KeyAction},
+    menu::widget::draw_key_hints,
+    print_line,
+    replay::read_event,
+    session::PlayerCharacter,
+    tr,
+    util::timestamp_to_datetime,
+};
 
 pub fn menu_character_stats(character: &PlayerCharacter) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     loop {
-        execute!(stdout, cursor::MoveTo(0, 0))?;
-        println!("(Esc) Back");
-        execute!(stdout, cursor::MoveTo(0, 1))?;
-        println!("Character Stats");
-        execute!(stdout, cursor::MoveTo(0, 2))?;
-        println!("  General:");
-        execute!(stdout, cursor::MoveTo(0, 3))?;
-        println!("    Name: {}", character.data.metadata.name);
-        execute!(stdout, cursor::MoveTo(0, 4))?;
-        println!("    Class: {:?}", character.data.metadata.class);
-        execute!(stdout, cursor::MoveTo(0, 5))?;
-        println!(
-            "    Created At: {}",
-            timestamp_to_datetime(character.data.metadata.created_at)
-        );
-        execute!(stdout, cursor::MoveTo(0, 6))?;
-        println!(
-            "    Current Dungeon Floor: {}",
-            character.data.stats.general_stats.current_dungeon_floor
-        );
-        execute!(stdout, cursor::MoveTo(0, 7))?;
-        println!(
-            "    Level: {}",
-            character.data.stats.general_stats.character_level
-        );
-        execute!(stdout, cursor::MoveTo(0, 8))?;
-        println!(
-            "    EXP: {}/{}",
-            character.data.stats.general_stats.current_exp,
-            character.data.stats.general_stats.required_exp,
-        );
-        execute!(stdout, cursor::MoveTo(0, 9))?;
-        println!(
-            "    Total EXP: {}",
-            character.data.stats.general_stats.total_exp
-        );
-        execute!(stdout, cursor::MoveTo(0, 10))?;
+        draw_key_hints(0, &[(KeyAction::Back, "hint.back")])?;
+        move_to(0, 1)?;
+        print_line!("{}", tr!("stats.title"))?;
+        move_to(0, 2)?;
+        print_line!("  {}", tr!("stats.general"))?;
+        move_to(0, 3)?;
+        print_line!(
+            "    {}",
+            tr!("stats.name", name = character.data.metadata.name)
+        )?;
+        move_to(0, 4)?;
+        print_line!(
+            "    {}",
+            tr!("stats.class", class = character.data.metadata.class)
+        )?;
+        move_to(0, 5)?;
+        print_line!(
+            "    {}",
+            tr!(
+                "stats.created_at",
+                date = timestamp_to_datetime(character.data.metadata.created_at)
+            )
+        )?;
+        move_to(0, 6)?;
+        print_line!(
+            "    {}",
+            tr!(
+                "stats.current_dungeon_floor",
+                floor = character.data.stats.general_stats.current_dungeon_floor
+            )
+        )?;
+        move_to(0, 7)?;
+        print_line!(
+            "    {}",
+            tr!(
+                "stats.level",
+                level = character.data.stats.general_stats.character_level
+            )
+        )?;
+        move_to(0, 8)?;
+        print_line!(
+            "    {}",
+            tr!(
+                "stats.exp",
+                current = character.data.stats.general_stats.current_exp,
+                required = character.data.stats.general_stats.required_exp,
+            )
+        )?;
+        move_to(0, 9)?;
+        print_line!(
+            "    {}",
+            tr!(
+                "stats.total_exp",
+                exp = character.data.stats.general_stats.total_exp
+            )
+        )?;
+        move_to(0, 10)?;
 
-        println!(
-            "    Highest Dungeon Floor Reached: {}",
-            character
-                .data
-                .stats
-                .general_stats
-                .highest_dungeon_floor_achieved
-        );
-        execute!(stdout, cursor::MoveTo(0, 11))?;
-        println!(
-            "    Highest Level Reached: {}",
-            character
-                .data
-                .stats
-                .general_stats
-                .highest_character_level_achieved
-        );
-        execute!(stdout, cursor::MoveTo(0, 12))?;
-        println!(
-            "    Total Deaths: {}",
-            character.data.stats.general_stats.deaths
-        );
-        execute!(stdout, cursor::MoveTo(0, 13))?;
+        print_line!(
+            "    {}",
+            tr!(
+                "stats.highest_dungeon_floor",
+                floor = character
+                    .data
+                    .stats
+                    .general_stats
+                    .highest_dungeon_floor_achieved
+            )
+        )?;
+        move_to(0, 11)?;
+        print_line!(
+            "    {}",
+            tr!(
+                "stats.highest_level",
+                level = character
+                    .data
+                    .stats
+                    .general_stats
+                    .highest_character_level_achieved
+            )
+        )?;
+        move_to(0, 12)?;
+        print_line!(
+            "    {}",
+            tr!(
+                "stats.total_deaths",
+                deaths = character.data.stats.general_stats.deaths
+            )
+        )?;
+        move_to(0, 13)?;
 
-        println!("  Combat:");
-        execute!(stdout, cursor::MoveTo(0, 14))?;
-        println!("    Health: {}", character.get_total_health());
-        execute!(stdout, cursor::MoveTo(0, 15))?;
-        println!("    Mana: {}", character.get_total_mana());
-        execute!(stdout, cursor::MoveTo(0, 16))?;
-        println!("    Defense: {}", character.get_total_defense());
-        execute!(stdout, cursor::MoveTo(0, 17))?;
-        println!("    Damage: {}", character.get_total_damage());
-        execute!(stdout, cursor::MoveTo(0, 18))?;
-        println!(
-            "    Critical Damage Multiplier: {:.2}",
-            character.get_total_crit_damage_multiplier()
-        );
-        execute!(stdout, cursor::MoveTo(0, 19))?;
-        println!(
-            "    Critical Hit Rate: {:.2} ({:.2}%)",
-            character.get_total_crit_hit_rate(),
-            character.get_total_crit_hit_rate() * 100.0
-        );
+        print_line!("  {}", tr!("stats.combat"))?;
+        move_to(0, 14)?;
+        print_line!(
+            "    {}",
+            tr!("stats.health", value = character.get_total_health())
+        )?;
+        move_to(0, 15)?;
+        print_line!(
+            "    {}",
+            tr!("stats.mana", value = character.get_total_mana())
+        )?;
+        move_to(0, 16)?;
+        print_line!(
+            "    {}",
+            tr!("stats.defense", value = character.get_total_defense())
+        )?;
+        move_to(0, 17)?;
+        print_line!(
+            "    {}",
+            tr!("stats.damage", value = character.get_total_damage())
+        )?;
+        move_to(0, 18)?;
+        print_line!(
+            "    {}",
+            tr!(
+                "stats.critical_damage_multiplier",
+                value = format!("{:.2}", character.get_total_crit_damage_multiplier())
+            )
+        )?;
+        move_to(0, 19)?;
+        print_line!(
+            "    {}",
+            tr!(
+                "stats.critical_hit_rate_percent",
+                value = format!("{:.2}", character.get_total_crit_hit_rate()),
+                percent = format!("{:.2}", character.get_total_crit_hit_rate() * 100.0)
+            )
+        )?;
+        display_special_stats(character, 20, "    ")?;
 
-        if let Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
             if kind == KeyEventKind::Press {
                 match code {
-                    KeyCode::Esc => {
+                    code if is_key_action(code, KeyAction::Back) => {
                         break;
                     }
                     _ => {}
@@ -107,7 +158,48 @@ pub fn menu_character_stats(character: &PlayerCharacter) -> io::Result<()> {
             }
         }
     }
-    execute!(stdout, Clear(ClearType::All))?;
+    clear()?;
 
     Ok(())
 }
+
+/// Displays stats that come from enchantments. Returns the next free column.
+pub fn display_special_stats(
+    character: &PlayerCharacter,
+    start_column: u16,
+    indent: &str,
+) -> io::Result<u16> {
+    let boosts = &character.temp_stat_boosts;
+    let stats = [
+        tr!(
+            "stats.lifesteal",
+            value = format!("{:.2}", boosts.lifesteal * 100.0)
+        ),
+        tr!("stats.thorns", value = boosts.thorns),
+        tr!(
+            "stats.evasion",
+            value = format!("{:.2}", character.get_total_evasion() * 100.0)
+        ),
+        tr!("stats.mana_regen", value = boosts.mana_regen),
+        tr!(
+            "stats.exp_bonus",
+            value = format!("{:.2}", boosts.exp_bonus * 100.0)
+        ),
+        tr!(
+            "stats.gold_find",
+            value = format!("{:.2}", boosts.gold_find * 100.0)
+        ),
+        tr!(
+            "stats.magic_find",
+            value = format!("{:.2}", boosts.magic_find * 100.0)
+        ),
+    ];
+    let mut column = start_column;
+    for stat in stats {
+        move_to(0, column)?;
+        print_line!("{}{}", indent, stat)?;
+        column += 1;
+    }
+
+    Ok(column)
+}
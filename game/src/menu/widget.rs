@@ -0,0 +1,341 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind};
+use std::{io, ops::Range};
+
+use crate::{
+    frontend::{clear, draw_list, flush, move_to, show_cursor, size},
+    i18n::translate_all,
+    keymap::{format_key_hints, get_number_key_index, is_key_action, KeyAction},
+    print_line, print_text,
+    replay::{read_event, read_line_input},
+    tr,
+    util::shift_index_back,
+};
+
+/// Selection and scrolling of a list menu.
+/// The items are passed in when drawing so menus can rebuild them between inputs.
+#[derive(Default)]
+pub struct SelectList {
+    pub selected_index: usize,
+    /// Index of the first visible item.
+    scroll: usize,
+}
+
+impl SelectList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the selection with the Up and Down keys.
+    /// Returns true if the key was handled.
+    /// Number keys select an item but are not handled so `is_select` can activate it.
+    pub fn handle_key(&mut self, code: KeyCode, len: usize) -> bool {
+        if is_key_action(code, KeyAction::Up) {
+            if self.selected_index > 0 {
+                self.selected_index -= 1;
+            }
+        } else if is_key_action(code, KeyAction::Down) {
+            if self.selected_index + 1 < len {
+                self.selected_index += 1;
+            }
+        } else {
+            if let Some(index) = get_number_key_index(code).filter(|i| *i < len) {
+                self.selected_index = index;
+            }
+            return false;
+        }
+        true
+    }
+
+    /// Returns true if the key activates the selected item.
+    /// Number keys of items in the list also activate them.
+    pub fn is_select(&self, code: KeyCode, len: usize) -> bool {
+        is_key_action(code, KeyAction::Select)
+            || get_number_key_index(code).is_some_and(|i| i < len)
+    }
+
+    /// Selects the first item and scrolls to the top.
+    pub fn reset(&mut self) {
+        self.selected_index = 0;
+        self.scroll = 0;
+    }
+
+    /// Keeps the selection in the list if items were removed.
+    pub fn clamp(&mut self, len: usize) {
+        if self.selected_index >= len {
+            self.selected_index = shift_index_back(len);
+        }
+    }
+
+    /// Draws all items starting from the row.
+    pub fn draw<T: AsRef<str>>(&self, row: u16, items: &[T]) -> io::Result<()> {
+        draw_list(row, items, self.selected_index)
+    }
+
+    /// Draws the items that fit on the screen starting from the row.
+    pub fn draw_scrolled<T: AsRef<str>>(&mut self, row: u16, items: &[T]) -> io::Result<()> {
+        let visible_items = self.get_visible_items(items.len(), row)?;
+        draw_list(
+            row,
+            &items[visible_items.clone()],
+            self.selected_index - visible_items.start,
+        )?;
+        self.draw_position(&visible_items, items.len(), row)
+    }
+
+    /// Scrolls the list so the selected item is visible.
+    /// Returns the range of items that fit on the screen.
+    pub fn get_visible_items(&mut self, len: usize, row: u16) -> io::Result<Range<usize>> {
+        let (_, height) = size()?;
+        // Leave one row for the list position.
+        let rows = (height.saturating_sub(row + 1) as usize).max(1);
+        let new_scroll = if self.selected_index < self.scroll {
+            self.selected_index
+        } else if self.selected_index >= self.scroll + rows {
+            self.selected_index + 1 - rows
+        } else {
+            self.scroll
+        };
+        if new_scroll != self.scroll {
+            self.scroll = new_scroll;
+            clear()?;
+        }
+        Ok(self.scroll..len.min(self.scroll + rows))
+    }
+
+    /// Draws the position in the list if not all items fit on the screen.
+    pub fn draw_position(
+        &self,
+        visible_items: &Range<usize>,
+        len: usize,
+        row: u16,
+    ) -> io::Result<()> {
+        if visible_items.len() < len {
+            move_to(0, row + visible_items.len() as u16)?;
+            print_line!(
+                "  {}",
+                tr!(
+                    "list.position",
+                    first = visible_items.start + 1,
+                    last = visible_items.end,
+                    len = len
+                )
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Draws key hints with the keys bound to the actions on a row, e.g. `(Esc) Back, (D) Delete`.
+/// The texts are message IDs.
+pub fn draw_key_hints(row: u16, hints: &[(KeyAction, &str)]) -> io::Result<()> {
+    move_to(0, row)?;
+    print_line!("{}", format_key_hints(hints))
+}
+
+/// Menu that takes over the screen until it is closed.
+pub trait Modal {
+    type Output;
+
+    /// Draws the modal. Called before every input.
+    fn draw(&mut self) -> io::Result<()>;
+
+    /// Handles a key press. Returns the output when the modal should close.
+    fn handle_key(&mut self, code: KeyCode) -> io::Result<Option<Self::Output>>;
+}
+
+/// Shows a modal until it closes and returns its output.
+/// Modals can open other modals from `handle_key`. They form a stack where
+/// the screen is cleared when a modal opens and closes, so the modal under it is drawn again.
+pub fn open_modal<M: Modal>(modal: &mut M) -> io::Result<M::Output> {
+    clear()?;
+    loop {
+        modal.draw()?;
+        if let Event::Key(KeyEvent { code, kind, .. }) = read_event()? {
+            if kind == KeyEventKind::Press {
+                if let Some(output) = modal.handle_key(code)? {
+                    clear()?;
+                    return Ok(output);
+                }
+            }
+        }
+    }
+}
+
+/// Yes or no question. No is selected by default.
+pub struct ConfirmDialog<F: FnMut() -> io::Result<()>> {
+    draw_prompt: F,
+    prompt_rows: u16,
+    list: SelectList,
+}
+
+impl<F: FnMut() -> io::Result<()>> ConfirmDialog<F> {
+    const ITEMS: [&'static str; 2] = ["menu.no", "menu.yes"];
+
+    /// `draw_prompt` draws the question to the first `prompt_rows` rows.
+    pub fn new(prompt_rows: u16, draw_prompt: F) -> Self {
+        Self {
+            draw_prompt,
+            prompt_rows,
+            list: SelectList::new(),
+        }
+    }
+}
+
+impl<F: FnMut() -> io::Result<()>> Modal for ConfirmDialog<F> {
+    type Output = bool;
+
+    fn draw(&mut self) -> io::Result<()> {
+        move_to(0, 0)?;
+        (self.draw_prompt)()?;
+        self.list
+            .draw(self.prompt_rows, &translate_all(&Self::ITEMS))
+    }
+
+    fn handle_key(&mut self, code: KeyCode) -> io::Result<Option<bool>> {
+        if self.list.handle_key(code, Self::ITEMS.len()) {
+            return Ok(None);
+        }
+        if self.list.is_select(code, Self::ITEMS.len()) {
+            return Ok(Some(Self::ITEMS[self.list.selected_index] == "menu.yes"));
+        }
+        Ok(None)
+    }
+}
+
+/// Asks a yes or no question with a line of text on each row.
+/// Returns true if the answer is yes.
+pub fn menu_confirm(lines: &[&str]) -> io::Result<bool> {
+    let mut dialog = ConfirmDialog::new(lines.len() as u16, || {
+        for (i, line) in lines.iter().enumerate() {
+            move_to(0, i as u16)?;
+            print_line!("{}", line)?;
+        }
+        Ok(())
+    });
+    open_modal(&mut dialog)
+}
+
+/// Text input that asks again until the text is valid.
+pub struct TextInput<'a> {
+    prompt: &'a str,
+}
+
+impl<'a> TextInput<'a> {
+    pub fn new(prompt: &'a str) -> Self {
+        Self { prompt }
+    }
+
+    /// Reads text starting from the row. Surrounding whitespace is removed.
+    /// `validate` returns an error message shown above the next prompt if the text is invalid.
+    pub fn read(
+        &self,
+        row: u16,
+        validate: impl Fn(&str) -> Result<(), String>,
+    ) -> io::Result<String> {
+        let mut row = row;
+        show_cursor(true)?;
+        let text = loop {
+            move_to(0, row)?;
+            print_text!("{}: ", self.prompt)?;
+            flush()?;
+            let text = read_line_input()?.trim().to_string();
+            match validate(&text) {
+                Ok(()) => break text,
+                Err(msg) => {
+                    move_to(0, row + 1)?;
+                    print_line!("{}", msg)?;
+                    row += 2;
+                }
+            }
+        };
+        show_cursor(false)?;
+        Ok(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        frontend::MemoryFrontend,
+        test_util::{install_frontend, key},
+    };
+
+    #[test]
+    fn confirm_yes() {
+        let frames = install_frontend(MemoryFrontend::new(vec![
+            key(KeyCode::Down),
+            key(KeyCode::Enter),
+        ]));
+        assert!(menu_confirm(&["Delete the item?"]).unwrap());
+
+        let frames = frames.borrow();
+        assert_eq!(frames[0], "Delete the item?\n> No\n  Yes");
+        assert_eq!(frames[1], "Delete the item?\n  No\n> Yes");
+    }
+
+    #[test]
+    fn confirm_no() {
+        let frames = install_frontend(MemoryFrontend::new(vec![key(KeyCode::Enter)]));
+        assert!(!menu_confirm(&["Delete the item?"]).unwrap());
+        assert_eq!(frames.borrow().len(), 1);
+    }
+
+    #[test]
+    fn select_list_scrolls_past_visible_items() {
+        // the list starts low on a 60x20 screen so only 5 items fit
+        const ROW: u16 = 14;
+        let items: Vec<String> = (1..=10).map(|i| format!("Item {}", i)).collect();
+        let frames = install_frontend(MemoryFrontend::with_size(
+            vec![key(KeyCode::Down); 6],
+            60,
+            20,
+        ));
+        let mut list = SelectList::new();
+        loop {
+            list.draw_scrolled(ROW, &items).unwrap();
+            match read_event() {
+                Ok(Event::Key(KeyEvent { code, .. })) => {
+                    list.handle_key(code, items.len());
+                }
+                _ => break,
+            }
+        }
+
+        let frames: Vec<String> = frames
+            .borrow()
+            .iter()
+            .map(|frame| {
+                frame
+                    .lines()
+                    .skip(ROW as usize)
+                    .collect::<Vec<&str>>()
+                    .join("\n")
+            })
+            .collect();
+        assert_eq!(
+            frames[0],
+            "> Item 1\n  Item 2\n  Item 3\n  Item 4\n  Item 5\n  [1-5 of 10]"
+        );
+        assert_eq!(
+            frames[6],
+            "  Item 3\n  Item 4\n  Item 5\n  Item 6\n> Item 7\n  [3-7 of 10]"
+        );
+    }
+
+    #[test]
+    fn text_input_asks_again_until_valid() {
+        let frames = install_frontend(
+            MemoryFrontend::new(Vec::new()).with_lines(vec![" ".to_string(), "Hero".to_string()]),
+        );
+        let text = TextInput::new("Name")
+            .read(0, |text| match text.is_empty() {
+                true => Err("Name is empty".to_string()),
+                false => Ok(()),
+            })
+            .unwrap();
+
+        assert_eq!(text, "Hero");
+        assert_eq!(frames.borrow()[1], "Name:\nName is empty\nName:");
+    }
+}
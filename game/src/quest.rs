@@ -0,0 +1,226 @@
+use core::fmt;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enemy::{get_enemy_display_name, EnemyKind, BOSS_ENEMY_NAMES, NORMAL_ENEMY_NAMES},
+    items::{CharacterItemOwned, ItemRarity},
+    rng::game_rng,
+    session::PlayerCharacter,
+    shop::generate_equipment,
+    tr,
+};
+
+pub const MAX_ACTIVE_QUESTS: usize = 3;
+pub const MIN_QUEST_BOARD_OFFERS: usize = 2;
+pub const MAX_QUEST_BOARD_OFFERS: usize = 3;
+pub const MIN_ENEMIES_TO_DEFEAT: u32 = 3;
+pub const MAX_ENEMIES_TO_DEFEAT: u32 = 6;
+/// Boss quests require a floor that is a multiple of this.
+pub const BOSS_QUEST_FLOOR_STEP: u32 = 5;
+pub const QUEST_GOLD_REWARD_PER_FLOOR: u32 = 100;
+
+/// Something that happened in combat or in the dungeon that can progress quests.
+pub enum QuestEvent<'a> {
+    EnemyDefeated {
+        name: &'a str,
+        kind: &'a EnemyKind,
+        level: u32,
+    },
+    /// Health or mana potion was used.
+    PotionUsed,
+    FloorCompleted,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum QuestObjective {
+    /// Defeat normal enemies with the name.
+    DefeatEnemies { name: String, amount: u32 },
+    /// Complete a dungeon floor without using health or mana potions.
+    ClearFloorWithoutPotions,
+    /// Defeat the boss on the floor or deeper.
+    DefeatBoss { name: String, min_floor: u32 },
+}
+
+impl fmt::Display for QuestObjective {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DefeatEnemies { name, amount } => write!(
+                f,
+                "{}",
+                tr!(
+                    "quest.defeat_enemies",
+                    amount = amount,
+                    enemy = get_enemy_display_name(name)
+                )
+            ),
+            Self::ClearFloorWithoutPotions => write!(f, "{}", tr!("quest.clear_floor")),
+            Self::DefeatBoss { name, min_floor } => write!(
+                f,
+                "{}",
+                tr!(
+                    "quest.defeat_boss",
+                    boss = get_enemy_display_name(name),
+                    floor = min_floor
+                )
+            ),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum QuestReward {
+    Gold(u32),
+    AncientRuinsKeys(u32),
+    /// Random weapon, armor or ring of the rarity.
+    Equipment(ItemRarity),
+}
+
+impl fmt::Display for QuestReward {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gold(amount) => write!(f, "{}", tr!("quest.reward.gold", amount = amount)),
+            Self::AncientRuinsKeys(amount) => {
+                write!(f, "{}", tr!("quest.reward.keys", amount = amount))
+            }
+            Self::Equipment(rarity) => {
+                write!(f, "{}", tr!("quest.reward.equipment", rarity = rarity))
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Quest {
+    pub objective: QuestObjective,
+    pub reward: QuestReward,
+    pub progress: u32,
+    /// True if a potion has been used on the current floor.
+    /// Only used by floor clearing quests.
+    #[serde(default)]
+    pub potion_used: bool,
+}
+
+impl Quest {
+    pub fn new(objective: QuestObjective, reward: QuestReward) -> Self {
+        Self {
+            objective,
+            reward,
+            progress: 0,
+            potion_used: false,
+        }
+    }
+
+    /// Returns the progress needed to complete the quest.
+    pub fn get_goal(&self) -> u32 {
+        match &self.objective {
+            QuestObjective::DefeatEnemies { amount, .. } => *amount,
+            _ => 1,
+        }
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.progress >= self.get_goal()
+    }
+
+    /// Updates the quest progress from the event.
+    pub fn handle_event(&mut self, event: &QuestEvent) {
+        if self.is_completed() {
+            return;
+        }
+        match (&self.objective, event) {
+            (
+                QuestObjective::DefeatEnemies { name, .. },
+                QuestEvent::EnemyDefeated {
+                    name: enemy_name,
+                    kind: EnemyKind::Normal,
+                    ..
+                },
+            ) if name == enemy_name => {
+                self.progress += 1;
+            }
+            (QuestObjective::ClearFloorWithoutPotions, QuestEvent::PotionUsed) => {
+                self.potion_used = true;
+            }
+            (QuestObjective::ClearFloorWithoutPotions, QuestEvent::FloorCompleted) => {
+                if !self.potion_used {
+                    self.progress += 1;
+                }
+                // the next floor is a new attempt
+                self.potion_used = false;
+            }
+            (
+                QuestObjective::DefeatBoss { name, min_floor },
+                QuestEvent::EnemyDefeated {
+                    name: enemy_name,
+                    kind: EnemyKind::Boss,
+                    level,
+                },
+            ) if name == enemy_name && level >= min_floor => {
+                self.progress += 1;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Gives the quest reward to the player.
+/// Returns text telling what was received.
+pub fn give_quest_reward(reward: &QuestReward, character: &mut PlayerCharacter) -> String {
+    match reward {
+        QuestReward::Gold(amount) => character.give_gold(*amount),
+        QuestReward::AncientRuinsKeys(amount) => character.give_ancient_ruins_key(*amount),
+        QuestReward::Equipment(rarity) => {
+            let mut rng = game_rng();
+            let floor = character.data.stats.general_stats.current_dungeon_floor;
+            let class = character.data.metadata.class.clone();
+            match generate_equipment(rng.gen_range(0..3), rarity.clone(), floor, &class) {
+                CharacterItemOwned::Weapon(item) => character.give_weapon(&item),
+                CharacterItemOwned::Armor(item) => character.give_armor(&item),
+                CharacterItemOwned::Ring(item) => character.give_ring(&item),
+                _ => {}
+            }
+        }
+    }
+    tr!("quest.reward_received", reward = reward)
+}
+
+fn random_quest_reward(floor: u32) -> QuestReward {
+    let mut rng = game_rng();
+    match rng.gen_range(0..3) {
+        0 => QuestReward::Gold(QUEST_GOLD_REWARD_PER_FLOOR * floor.max(1)),
+        1 => QuestReward::AncientRuinsKeys(1),
+        _ => {
+            if rng.gen_bool(0.5) {
+                QuestReward::Equipment(ItemRarity::Rare)
+            } else {
+                QuestReward::Equipment(ItemRarity::Epic)
+            }
+        }
+    }
+}
+
+pub fn generate_random_quest(floor: u32) -> Quest {
+    let mut rng = game_rng();
+    let objective = match rng.gen_range(0..3) {
+        0 => QuestObjective::DefeatEnemies {
+            name: NORMAL_ENEMY_NAMES[rng.gen_range(0..NORMAL_ENEMY_NAMES.len())].to_string(),
+            amount: rng.gen_range(MIN_ENEMIES_TO_DEFEAT..=MAX_ENEMIES_TO_DEFEAT),
+        },
+        1 => QuestObjective::ClearFloorWithoutPotions,
+        _ => QuestObjective::DefeatBoss {
+            name: BOSS_ENEMY_NAMES[rng.gen_range(0..BOSS_ENEMY_NAMES.len())].to_string(),
+            min_floor: (floor / BOSS_QUEST_FLOOR_STEP + 1) * BOSS_QUEST_FLOOR_STEP,
+        },
+    };
+    Quest::new(objective, random_quest_reward(floor))
+}
+
+/// Generates the quests offered on the bounty board of a dungeon floor.
+pub fn generate_quest_board(floor: u32) -> Vec<Quest> {
+    let mut rng = game_rng();
+    (0..rng.gen_range(MIN_QUEST_BOARD_OFFERS..=MAX_QUEST_BOARD_OFFERS))
+        .map(|_| generate_random_quest(floor))
+        .collect()
+}
@@ -0,0 +1,451 @@
+use chrono::Utc;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use rand::{thread_rng, Rng};
+use serde::{Deserialize, Serialize};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    fs,
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+    time::Duration,
+};
+
+use crate::{
+    frontend::{
+        clear, clear_line, flush, move_to, poll_frontend_event, read_frontend_event, read_line,
+        size,
+    },
+    game_data::GameData,
+    keymap::{format_key_hints, is_key_action, KeyAction, KeymapFile},
+    layout::wait_for_terminal_size,
+    print_line, print_text,
+    rng::seed_session_rng,
+    tr,
+};
+
+/// Increased when the replay file format changes.
+pub const REPLAY_FORMAT_VERSION: u32 = 1;
+/// Delay between replayed inputs at normal speed.
+pub const REPLAY_STEP_DELAY_MILLIS: f64 = 400.0;
+pub const REPLAY_SPEEDS: [f64; 6] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0];
+const REPLAY_DEFAULT_SPEED_INDEX: usize = 2;
+
+thread_local! {
+    static SESSION: RefCell<ReplaySession> = const {
+        RefCell::new(ReplaySession {
+            input: InputSource::Terminal,
+            date: None,
+            replaying: false,
+        })
+    };
+}
+
+/// First line of a replay file.
+/// Contains everything needed to start the session from the same state.
+#[derive(Serialize, Deserialize)]
+pub struct ReplayHeader {
+    pub version: u32,
+    /// Seed of the session random number generator.
+    pub seed: u64,
+    /// UTC date in format YYYY-MM-DD when the recording was started.
+    pub date: String,
+    pub dev_mode: bool,
+    /// Game data loaded from the save file when the recording was started.
+    pub game_data: GameData,
+    /// Key bindings used during the recording. The keys are recorded, not the actions.
+    #[serde(default)]
+    pub keymap: KeymapFile,
+}
+
+/// Key press saved to a replay file.
+/// Only the keys the game reacts to are recorded.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ReplayKey {
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    Esc,
+    Backspace,
+    PageUp,
+    PageDown,
+    Char(char),
+}
+
+/// Input saved to a replay file, one per line.
+/// Keys are saved as they are, e.g. `"Enter"`, and lines of text input as `{"line":"Hero"}`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ReplayInput {
+    Key(ReplayKey),
+    Line { line: String },
+}
+
+impl ReplayKey {
+    pub fn from_key_code(code: KeyCode) -> Option<Self> {
+        match code {
+            KeyCode::Up => Some(Self::Up),
+            KeyCode::Down => Some(Self::Down),
+            KeyCode::Left => Some(Self::Left),
+            KeyCode::Right => Some(Self::Right),
+            KeyCode::Enter => Some(Self::Enter),
+            KeyCode::Esc => Some(Self::Esc),
+            KeyCode::Backspace => Some(Self::Backspace),
+            KeyCode::PageUp => Some(Self::PageUp),
+            KeyCode::PageDown => Some(Self::PageDown),
+            KeyCode::Char(c) => Some(Self::Char(c)),
+            _ => None,
+        }
+    }
+
+    pub fn to_key_code(self) -> KeyCode {
+        match self {
+            Self::Up => KeyCode::Up,
+            Self::Down => KeyCode::Down,
+            Self::Left => KeyCode::Left,
+            Self::Right => KeyCode::Right,
+            Self::Enter => KeyCode::Enter,
+            Self::Esc => KeyCode::Esc,
+            Self::Backspace => KeyCode::Backspace,
+            Self::PageUp => KeyCode::PageUp,
+            Self::PageDown => KeyCode::PageDown,
+            Self::Char(c) => KeyCode::Char(c),
+        }
+    }
+}
+
+struct ReplaySession {
+    input: InputSource,
+    /// Date used as the current UTC date during the session.
+    date: Option<String>,
+    /// True if the session was started from a replay file.
+    /// Stays true after the replayed inputs run out.
+    replaying: bool,
+}
+
+enum InputSource {
+    Terminal,
+    Recording(fs::File),
+    Replay(ReplayPlayback),
+}
+
+struct ReplayPlayback {
+    inputs: VecDeque<ReplayInput>,
+    total_inputs: usize,
+    speed_index: usize,
+    paused: bool,
+}
+
+impl ReplayPlayback {
+    fn get_speed(&self) -> f64 {
+        REPLAY_SPEEDS[self.speed_index]
+    }
+
+    fn get_step_delay(&self) -> Duration {
+        Duration::from_millis((REPLAY_STEP_DELAY_MILLIS / self.get_speed()) as u64)
+    }
+
+    /// Returns the number of the next replayed input starting from 1.
+    fn get_step(&self) -> usize {
+        self.total_inputs - self.inputs.len() + 1
+    }
+}
+
+/// What to do after a control key was pressed during playback.
+enum PlaybackControl {
+    Continue,
+    /// Replay the next input immediately.
+    Step,
+    Stop,
+}
+
+/// Starts recording inputs to the file.
+/// The session random number generator is seeded with a new random seed.
+pub fn start_recording(
+    path: &Path,
+    game_data: &GameData,
+    keymap: &KeymapFile,
+    dev_mode: bool,
+) -> io::Result<()> {
+    let header = ReplayHeader {
+        version: REPLAY_FORMAT_VERSION,
+        seed: thread_rng().gen(),
+        date: Utc::now().format("%Y-%m-%d").to_string(),
+        dev_mode,
+        game_data: game_data.clone(),
+        keymap: keymap.clone(),
+    };
+    let mut file = fs::File::create(path)?;
+    writeln!(file, "{}", serde_json::to_string(&header)?)?;
+    file.flush()?;
+
+    seed_session_rng(header.seed);
+    SESSION.with(|session| {
+        let mut session = session.borrow_mut();
+        session.input = InputSource::Recording(file);
+        session.date = Some(header.date);
+    });
+    Ok(())
+}
+
+/// Loads the replay file and starts replaying its inputs.
+/// Returns the header with the game data the session should start with.
+pub fn start_replay(path: &Path) -> io::Result<ReplayHeader> {
+    let mut lines = BufReader::new(fs::File::open(path)?).lines();
+    let header: ReplayHeader = match lines.next() {
+        Some(line) => serde_json::from_str(&line?)?,
+        None => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "replay file is empty",
+            ))
+        }
+    };
+    if header.version != REPLAY_FORMAT_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "unsupported replay file version {}, expected {}",
+                header.version, REPLAY_FORMAT_VERSION
+            ),
+        ));
+    }
+    let mut inputs = VecDeque::new();
+    for line in lines {
+        let line = line?;
+        // the last line may be cut if the recorded session crashed
+        match serde_json::from_str(&line) {
+            Ok(input) => inputs.push_back(input),
+            Err(_) => break,
+        }
+    }
+
+    seed_session_rng(header.seed);
+    SESSION.with(|session| {
+        let mut session = session.borrow_mut();
+        session.input = InputSource::Replay(ReplayPlayback {
+            total_inputs: inputs.len(),
+            inputs,
+            speed_index: REPLAY_DEFAULT_SPEED_INDEX,
+            paused: false,
+        });
+        session.date = Some(header.date.clone());
+        session.replaying = true;
+    });
+    Ok(header)
+}
+
+/// Returns true if the session was started from a replay file.
+/// The game is never saved in replay sessions.
+pub fn is_replay_session() -> bool {
+    SESSION.with(|session| session.borrow().replaying)
+}
+
+/// Returns the UTC date of a recorded or replayed session.
+/// The date stays the same for the whole session so replays match the recording.
+pub fn get_session_date() -> Option<String> {
+    SESSION.with(|session| session.borrow().date.clone())
+}
+
+/// Reads the next input event.
+/// Key presses are written to the replay file when recording.
+/// When replaying, the recorded key presses are returned before reading from the terminal.
+/// The screen is cleared when the terminal is resized, and a resize event is returned
+/// after the terminal was too small, so menus draw again.
+pub fn read_event() -> io::Result<Event> {
+    if wait_for_terminal_size()? {
+        let (width, height) = size()?;
+        return Ok(Event::Resize(width, height));
+    }
+    let event = read_session_event()?;
+    if let Event::Resize(..) = event {
+        clear()?;
+        wait_for_terminal_size()?;
+    }
+    Ok(event)
+}
+
+fn read_session_event() -> io::Result<Event> {
+    SESSION.with(|session| {
+        let mut session = session.borrow_mut();
+        match &mut session.input {
+            InputSource::Terminal => read_frontend_event(),
+            InputSource::Recording(file) => {
+                let event = read_frontend_event()?;
+                if let Event::Key(KeyEvent {
+                    code,
+                    kind: KeyEventKind::Press,
+                    ..
+                }) = event
+                {
+                    if let Some(key) = ReplayKey::from_key_code(code) {
+                        write_replay_input(file, &ReplayInput::Key(key))?;
+                    }
+                }
+                Ok(event)
+            }
+            InputSource::Replay(playback) => match next_replay_input(playback)? {
+                Some(ReplayInput::Key(key)) => Ok(Event::Key(KeyEvent::new(
+                    key.to_key_code(),
+                    KeyModifiers::NONE,
+                ))),
+                // a line where a key was expected means the replay is out of sync
+                _ => {
+                    session.input = InputSource::Terminal;
+                    display_replay_status(&tr!("replay.finished"))?;
+                    read_frontend_event()
+                }
+            },
+        }
+    })
+}
+
+/// Reads a line of text input.
+/// Lines are written to the replay file when recording.
+/// When replaying, the recorded lines are returned before reading from the terminal.
+pub fn read_line_input() -> io::Result<String> {
+    SESSION.with(|session| {
+        let mut session = session.borrow_mut();
+        match &mut session.input {
+            InputSource::Terminal => read_line(),
+            InputSource::Recording(file) => {
+                let line = read_line()?;
+                write_replay_input(
+                    file,
+                    &ReplayInput::Line {
+                        line: line.trim_end_matches(['\r', '\n']).to_string(),
+                    },
+                )?;
+                Ok(line)
+            }
+            InputSource::Replay(playback) => match next_replay_input(playback)? {
+                Some(ReplayInput::Line { line }) => {
+                    // the typed text is shown like in the recorded session
+                    print_line!("{}", line)?;
+                    Ok(line)
+                }
+                // a key where a line was expected means the replay is out of sync
+                _ => {
+                    session.input = InputSource::Terminal;
+                    display_replay_status(&tr!("replay.finished"))?;
+                    read_line()
+                }
+            },
+        }
+    })
+}
+
+fn write_replay_input(file: &mut fs::File, input: &ReplayInput) -> io::Result<()> {
+    writeln!(file, "{}", serde_json::to_string(input)?)?;
+    file.flush()
+}
+
+/// Waits for the step delay and handles playback controls.
+/// Returns None if the replay ended or was stopped.
+fn next_replay_input(playback: &mut ReplayPlayback) -> io::Result<Option<ReplayInput>> {
+    loop {
+        if playback.inputs.is_empty() {
+            return Ok(None);
+        }
+        let status = if playback.paused {
+            "replay.status_paused"
+        } else {
+            "replay.status"
+        };
+        display_replay_status(&format!(
+            "{} | {}",
+            tr!(
+                status,
+                step = playback.get_step(),
+                total = playback.total_inputs,
+                speed = playback.get_speed()
+            ),
+            format_key_hints(&[
+                (KeyAction::Pause, "hint.pause"),
+                (KeyAction::Step, "hint.step"),
+                (KeyAction::SpeedUp, "hint.speed_up"),
+                (KeyAction::SpeedDown, "hint.speed_down"),
+                (KeyAction::Stop, "hint.stop"),
+            ])
+        ))?;
+
+        if !playback.paused && !poll_frontend_event(playback.get_step_delay())? {
+            return Ok(playback.inputs.pop_front());
+        }
+        let event = read_frontend_event()?;
+        if let Event::Resize(..) = event {
+            clear()?;
+            wait_for_terminal_size()?;
+        }
+        if let Event::Key(KeyEvent { code, kind, .. }) = event {
+            if kind == KeyEventKind::Press {
+                match handle_playback_control(playback, code) {
+                    PlaybackControl::Continue => {}
+                    PlaybackControl::Step => return Ok(playback.inputs.pop_front()),
+                    PlaybackControl::Stop => return Ok(None),
+                }
+            }
+        }
+    }
+}
+
+fn handle_playback_control(playback: &mut ReplayPlayback, code: KeyCode) -> PlaybackControl {
+    match code {
+        code if is_key_action(code, KeyAction::Pause) => playback.paused = !playback.paused,
+        code if is_key_action(code, KeyAction::SpeedUp)
+            && playback.speed_index < REPLAY_SPEEDS.len() - 1 =>
+        {
+            playback.speed_index += 1;
+        }
+        code if is_key_action(code, KeyAction::SpeedDown) && playback.speed_index > 0 => {
+            playback.speed_index -= 1;
+        }
+        code if is_key_action(code, KeyAction::Step) && playback.paused => {
+            return PlaybackControl::Step;
+        }
+        code if is_key_action(code, KeyAction::Stop) => return PlaybackControl::Stop,
+        _ => {}
+    }
+    PlaybackControl::Continue
+}
+
+/// Shows the replay status on the last row of the terminal.
+fn display_replay_status(text: &str) -> io::Result<()> {
+    let (_, rows) = size()?;
+    move_to(0, rows.saturating_sub(1))?;
+    clear_line()?;
+    print_text!("{}", text)?;
+    flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replay_input_round_trip() {
+        let inputs = [
+            ReplayInput::Key(ReplayKey::Enter),
+            ReplayInput::Key(ReplayKey::Char('s')),
+            ReplayInput::Line {
+                line: "Hero".to_string(),
+            },
+        ];
+        for input in inputs {
+            let json = serde_json::to_string(&input).unwrap();
+            assert_eq!(serde_json::from_str::<ReplayInput>(&json).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn replay_input_reads_recorded_keys() {
+        let json = serde_json::to_string(&ReplayKey::Char('q')).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ReplayInput>(&json).unwrap(),
+            ReplayInput::Key(ReplayKey::Char('q'))
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use rand::{rngs::StdRng, thread_rng, RngCore, SeedableRng};
+use std::cell::RefCell;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+thread_local! {
+    /// Used for the whole session if set. Recorded and replayed sessions use this.
+    static SESSION_RNG: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+    /// Temporarily used instead of the session generator if set.
+    static SEEDED_RNG: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+}
+
+/// Random number generator used by the game.
+/// Uses the seeded generator if the game RNG is seeded, then the session generator
+/// and otherwise the thread random number generator.
+pub struct GameRng;
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        with_rng(|rng| rng.next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        with_rng(|rng| rng.next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        with_rng(|rng| rng.fill_bytes(dest))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        with_rng(|rng| rng.try_fill_bytes(dest))
+    }
+}
+
+fn with_rng<T>(f: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+    SEEDED_RNG.with(|seeded| match seeded.borrow_mut().as_mut() {
+        Some(rng) => f(rng),
+        None => SESSION_RNG.with(|session| match session.borrow_mut().as_mut() {
+            Some(rng) => f(rng),
+            None => f(&mut thread_rng()),
+        }),
+    })
+}
+
+pub fn game_rng() -> GameRng {
+    GameRng
+}
+
+/// Makes all game randomness of the session reproducible.
+pub fn seed_session_rng(seed: u64) {
+    SESSION_RNG.with(|session| *session.borrow_mut() = Some(StdRng::seed_from_u64(seed)));
+}
+
+/// Makes all game randomness reproducible until the game RNG is reset.
+pub fn seed_game_rng(seed: u64) {
+    SEEDED_RNG.with(|seeded| *seeded.borrow_mut() = Some(StdRng::seed_from_u64(seed)));
+}
+
+/// Returns to using the session generator, or the thread random number generator
+/// if the session is not seeded.
+pub fn reset_game_rng() {
+    SEEDED_RNG.with(|seeded| *seeded.borrow_mut() = None);
+}
+
+/// Returns the 64-bit FNV-1a hash of the text.
+/// Used to derive seeds that stay the same between runs and platforms.
+pub fn hash_seed(text: &str) -> u64 {
+    text.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn draw_numbers() -> Vec<u32> {
+        let mut rng = game_rng();
+        (0..5).map(|_| rng.gen_range(0..1000)).collect()
+    }
+
+    #[test]
+    fn hash_seed_is_fnv1a() {
+        assert_eq!(hash_seed(""), FNV_OFFSET_BASIS);
+        assert_eq!(hash_seed("a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(hash_seed("2024-06-01"), hash_seed("2024-06-02"));
+    }
+
+    #[test]
+    fn seeded_game_rng_repeats() {
+        let seed = hash_seed("2024-06-01");
+        seed_game_rng(seed);
+        let first = draw_numbers();
+        seed_game_rng(seed);
+        assert_eq!(draw_numbers(), first);
+
+        seed_game_rng(hash_seed("2024-06-02"));
+        assert_ne!(draw_numbers(), first);
+        reset_game_rng();
+    }
+
+    #[test]
+    fn seeded_game_rng_overrides_session_rng() {
+        seed_session_rng(1);
+        let session = draw_numbers();
+
+        seed_session_rng(1);
+        seed_game_rng(2);
+        let seeded = draw_numbers();
+        reset_game_rng();
+        // the session generator continues where it was before seeding
+        assert_eq!(draw_numbers(), session);
+        assert_ne!(seeded, session);
+        SESSION_RNG.with(|session| *session.borrow_mut() = None);
+    }
+}
@@ -1,25 +1,33 @@
 use std::io;
 
 use crate::{
+    alchemy::Ingredient,
     character::{
         get_character_skill, CharacterClass, CharacterSkill, ASSASSIN_STATS_INCREASE_LEVEL_UP,
         CLASS_ASSASSIN_STARTING_STATS, CLASS_CLERIC_STARTING_STATS, CLASS_KNIGHT_STARTING_STATS,
         CLASS_MAGE_STARTING_STATS, CLASS_WARRIOR_STARTING_STATS, CLERIC_STATS_INCREASE_LEVEL_UP,
-        KNIGHT_STATS_INCREASE_LEVEL_UP, MAGE_STATS_INCREASE_LEVEL_UP, SKILL_MANA_COST,
+        KNIGHT_STATS_INCREASE_LEVEL_UP, MAGE_STATS_INCREASE_LEVEL_UP, MAX_EVASION, SKILL_MANA_COST,
         WARRIOR_STATS_INCREASE_LEVEL_UP,
     },
+    companion::Companion,
     enemy::Enemy,
     fight::is_critical_hit,
-    game_data::{CharacterData, GameData, STARTING_REQUIRED_EXP},
+    game_data::{CharacterData, GameData, Stash, STARTING_REQUIRED_EXP},
     items::{
-        create_starter_weapon, get_item_display_name, ArmorItem, CharacterItem, ConsumableItem,
-        Enchantment, ItemRarity, RingItem, WeaponItem,
+        create_starter_weapon, get_active_item_set_bonuses, ArmorItem, ConsumableEffect,
+        ConsumableItem, Enchantment, ItemRarity, ItemSet, RingItem, WeaponItem,
     },
+    quest::QuestEvent,
+    tr,
+    util::is_chance_success,
 };
 
 pub struct Player {
     pub character: Option<PlayerCharacter>,
     pub data: GameData,
+    /// True if the character is only used for a single run, like a daily challenge.
+    /// Temporary characters are not saved.
+    pub temporary_character: bool,
 }
 
 impl Player {
@@ -27,6 +35,7 @@ impl Player {
         Self {
             character: None,
             data,
+            temporary_character: false,
         }
     }
 
@@ -51,6 +60,19 @@ impl Player {
             )),
         }
     }
+
+    /// Returns mutable references to the player character and the stash.
+    pub fn get_character_and_stash_mut(
+        &mut self,
+    ) -> io::Result<(&mut PlayerCharacter, &mut Stash)> {
+        match &mut self.character {
+            Some(character) => Ok((character, &mut self.data.stash)),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "No selected character",
+            )),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -59,6 +81,11 @@ pub struct PlayerCharacter {
     pub temp_stats: TemporaryStats,
     pub temp_stat_boosts: TemporaryStatBoosts,
     pub equipped_items: EquippedItems,
+    /// Enchantments of the currently active item set bonuses.
+    /// These are included in the temporary stat boosts.
+    pub set_bonuses: Vec<Enchantment>,
+    /// Stat reductions caused by enemy skills in the current fight.
+    pub stat_reductions: TemporaryStatReductions,
 }
 
 impl PlayerCharacter {
@@ -69,19 +96,14 @@ impl PlayerCharacter {
                 current_health: data.stats.combat_stats.max_health,
                 current_mana: data.stats.combat_stats.max_mana,
             },
-            temp_stat_boosts: TemporaryStatBoosts {
-                max_health: 0,
-                max_mana: 0,
-                defense: 0,
-                damage: 0,
-                critical_damage_multiplier: 0.0,
-                critical_hit_rate: 0.0,
-            },
+            temp_stat_boosts: TemporaryStatBoosts::default(),
             equipped_items: EquippedItems {
                 weapon: None,
                 armor: None,
                 ring: None,
             },
+            set_bonuses: Vec::new(),
+            stat_reductions: TemporaryStatReductions::default(),
         }
     }
 
@@ -93,6 +115,39 @@ impl PlayerCharacter {
         self.data.inventory.ancient_ruins_keys += amount;
     }
 
+    pub fn give_crafting_materials(&mut self, amount: u32) {
+        self.data.inventory.crafting_materials += amount;
+    }
+
+    pub fn give_ingredient(&mut self, ingredient: Ingredient, amount: u32) {
+        *self
+            .data
+            .inventory
+            .ingredients
+            .entry(ingredient)
+            .or_insert(0) += amount;
+    }
+
+    /// Removes the ingredient from inventory if the amount goes to 0.
+    pub fn consume_ingredient(&mut self, ingredient: &Ingredient, amount: u32) {
+        if let Some(current_amount) = self.data.inventory.ingredients.get_mut(ingredient) {
+            if *current_amount > amount {
+                *current_amount -= amount;
+            } else {
+                self.data.inventory.ingredients.remove(ingredient);
+            }
+        }
+    }
+
+    pub fn get_ingredient_amount(&self, ingredient: &Ingredient) -> u32 {
+        *self
+            .data
+            .inventory
+            .ingredients
+            .get(ingredient)
+            .unwrap_or(&0)
+    }
+
     pub fn consume_ancient_ruins_key(&mut self) {
         if self.data.inventory.ancient_ruins_keys == 0 {
             return;
@@ -105,15 +160,16 @@ impl PlayerCharacter {
             .data
             .inventory
             .consumables
-            .get_mut(&get_item_display_name(CharacterItem::Consumable(&item)))
+            .get_mut(&item.get_inventory_key())
         {
             item.amount_in_inventory += amount;
         } else {
             self.data.inventory.consumables.insert(
-                get_item_display_name(CharacterItem::Consumable(&item)),
+                item.get_inventory_key(),
                 ConsumableItem {
                     info: item.info.clone(),
                     effect: item.effect.clone(),
+                    effect_kind: item.effect_kind.clone(),
                     rarity: item.rarity.clone(),
                     amount_in_inventory: amount,
                 },
@@ -122,24 +178,21 @@ impl PlayerCharacter {
     }
 
     pub fn give_weapon(&mut self, item: &WeaponItem) {
-        self.data
-            .inventory
-            .weapons
-            .insert(item.id.clone(), item.clone());
+        let mut item = item.clone();
+        item.sequence = self.data.inventory.next_item_sequence();
+        self.data.inventory.weapons.insert(item.id.clone(), item);
     }
 
     pub fn give_armor(&mut self, item: &ArmorItem) {
-        self.data
-            .inventory
-            .armors
-            .insert(item.id.clone(), item.clone());
+        let mut item = item.clone();
+        item.sequence = self.data.inventory.next_item_sequence();
+        self.data.inventory.armors.insert(item.id.clone(), item);
     }
 
     pub fn give_ring(&mut self, item: &RingItem) {
-        self.data
-            .inventory
-            .rings
-            .insert(item.id.clone(), item.clone());
+        let mut item = item.clone();
+        item.sequence = self.data.inventory.next_item_sequence();
+        self.data.inventory.rings.insert(item.id.clone(), item);
     }
 
     /// Returns true if the item was equipped.
@@ -152,6 +205,7 @@ impl PlayerCharacter {
                 .increase_crit_hit_rate(weapon.stats.crit_hit_rate);
             self.temp_stat_boosts
                 .give_enchantment_values(&weapon.enchantments);
+            self.refresh_set_bonuses();
             return true;
         }
         false
@@ -167,6 +221,7 @@ impl PlayerCharacter {
             self.temp_stat_boosts.increase_defense(armor.stats.defense);
             self.temp_stat_boosts
                 .give_enchantment_values(&armor.enchantments);
+            self.refresh_set_bonuses();
             return true;
         }
         false
@@ -180,6 +235,7 @@ impl PlayerCharacter {
             self.temp_stat_boosts.increase_max_mana(ring.stats.mana);
             self.temp_stat_boosts
                 .give_enchantment_values(&ring.enchantments);
+            self.refresh_set_bonuses();
             return true;
         }
         false
@@ -195,6 +251,7 @@ impl PlayerCharacter {
                 self.temp_stat_boosts
                     .remove_enchantment_values(&weapon.enchantments);
                 self.equipped_items.weapon = None;
+                self.refresh_set_bonuses();
                 return true;
             }
         }
@@ -210,9 +267,10 @@ impl PlayerCharacter {
                 self.temp_stat_boosts.decrease_defense(armor.stats.defense);
                 self.temp_stat_boosts
                     .remove_enchantment_values(&armor.enchantments);
+                self.equipped_items.armor = None;
+                self.refresh_set_bonuses();
                 self.adjust_current_health();
                 self.adjust_current_mana();
-                self.equipped_items.armor = None;
                 return true;
             }
         }
@@ -226,24 +284,87 @@ impl PlayerCharacter {
                 self.temp_stat_boosts.decrease_max_mana(ring.stats.mana);
                 self.temp_stat_boosts
                     .remove_enchantment_values(&ring.enchantments);
+                self.equipped_items.ring = None;
+                self.refresh_set_bonuses();
                 self.adjust_current_health();
                 self.adjust_current_mana();
-                self.equipped_items.ring = None;
                 return true;
             }
         }
         false
     }
 
-    pub fn gain_exp(&mut self, exp: u32) {
+    /// Returns the item sets of the equipped items
+    /// and the number of equipped pieces of each set.
+    pub fn get_equipped_item_sets(&self) -> Vec<(ItemSet, u8)> {
+        let mut equipped_sets: Vec<(ItemSet, u8)> = Vec::new();
+        let weapon_set = self
+            .equipped_items
+            .weapon
+            .as_ref()
+            .and_then(|id| self.data.inventory.weapons.get(id))
+            .and_then(|weapon| weapon.set.clone());
+        let armor_set = self
+            .equipped_items
+            .armor
+            .as_ref()
+            .and_then(|id| self.data.inventory.armors.get(id))
+            .and_then(|armor| armor.set.clone());
+        let ring_set = self
+            .equipped_items
+            .ring
+            .as_ref()
+            .and_then(|id| self.data.inventory.rings.get(id))
+            .and_then(|ring| ring.set.clone());
+
+        for set in [weapon_set, armor_set, ring_set].into_iter().flatten() {
+            match equipped_sets.iter_mut().find(|(s, _)| *s == set) {
+                Some((_, pieces)) => *pieces += 1,
+                None => equipped_sets.push((set, 1)),
+            }
+        }
+        equipped_sets
+    }
+
+    /// Returns the number of equipped pieces of an item set.
+    pub fn get_equipped_set_pieces(&self, set: &ItemSet) -> u8 {
+        self.get_equipped_item_sets()
+            .iter()
+            .find(|(s, _)| s == set)
+            .map_or(0, |(_, pieces)| *pieces)
+    }
+
+    /// Recalculates the active item set bonuses based on the equipped items.
+    fn refresh_set_bonuses(&mut self) {
+        self.temp_stat_boosts
+            .remove_enchantment_values(&self.set_bonuses);
+        self.set_bonuses = self
+            .get_equipped_item_sets()
+            .iter()
+            .flat_map(|(set, pieces)| get_active_item_set_bonuses(set, *pieces))
+            .collect();
+        self.temp_stat_boosts
+            .give_enchantment_values(&self.set_bonuses);
+        self.adjust_current_health();
+        self.adjust_current_mana();
+    }
+
+    /// EXP bonus is applied to the gained EXP. The companion gains the same amount of EXP.
+    /// Returns the amount of EXP gained.
+    pub fn gain_exp(&mut self, exp: u32) -> u32 {
+        let exp = (exp as f64 * (1.0 + self.temp_stat_boosts.exp_bonus)) as u32;
         self.data.stats.general_stats.current_exp += exp;
         self.data.stats.general_stats.total_exp += exp;
+        if let Some(companion) = &mut self.data.companion {
+            companion.gain_exp(exp);
+        }
 
         while self.data.stats.general_stats.current_exp
             >= self.data.stats.general_stats.required_exp
         {
             self.level_up();
         }
+        exp
     }
 
     pub fn level_up(&mut self) -> u32 {
@@ -305,20 +426,21 @@ impl PlayerCharacter {
     }
 
     /// Returns true if deleted.
-    pub fn delete_consumable(&mut self, display_name: &str) -> bool {
-        if let Some(_) = self.data.inventory.consumables.remove(display_name) {
-            return true;
-        }
-        false
+    pub fn delete_consumable(&mut self, inventory_key: &str) -> bool {
+        self.data
+            .inventory
+            .consumables
+            .remove(inventory_key)
+            .is_some()
     }
 
     /// Returns true if the item exists in inventory.
     pub fn decrease_consumable_inventory_amount(
         &mut self,
-        display_name: &str,
+        inventory_key: &str,
         amount: u32,
     ) -> bool {
-        if let Some(item) = self.data.inventory.consumables.get_mut(display_name) {
+        if let Some(item) = self.data.inventory.consumables.get_mut(inventory_key) {
             if amount > item.amount_in_inventory {
                 item.amount_in_inventory = 0;
             } else {
@@ -329,8 +451,12 @@ impl PlayerCharacter {
         false
     }
 
+    /// Locked items are not deleted.
     pub fn delete_weapon(&mut self, id: &str) -> bool {
         if let Some(weapon) = self.data.inventory.weapons.get(id) {
+            if weapon.locked {
+                return false;
+            }
             if weapon.is_equipped(&self) {
                 self.unequip_weapon();
             }
@@ -341,8 +467,12 @@ impl PlayerCharacter {
         false
     }
 
+    /// Locked items are not deleted.
     pub fn delete_armor(&mut self, id: &str) -> bool {
         if let Some(armor) = self.data.inventory.armors.get(id) {
+            if armor.locked {
+                return false;
+            }
             if armor.is_equipped(&self) {
                 self.unequip_armor();
             }
@@ -353,8 +483,12 @@ impl PlayerCharacter {
         false
     }
 
+    /// Locked items are not deleted.
     pub fn delete_ring(&mut self, id: &str) -> bool {
         if let Some(ring) = self.data.inventory.rings.get(id) {
+            if ring.locked {
+                return false;
+            }
             if ring.is_equipped(&self) {
                 self.unequip_ring();
             }
@@ -365,6 +499,42 @@ impl PlayerCharacter {
         false
     }
 
+    /// Locks or unlocks the item. Locked items can't be sold, salvaged or deleted.
+    /// Returns true if the item is now locked.
+    pub fn toggle_weapon_lock(&mut self, id: &str) -> bool {
+        match self.data.inventory.weapons.get_mut(id) {
+            Some(item) => {
+                item.locked = !item.locked;
+                item.locked
+            }
+            None => false,
+        }
+    }
+
+    /// Locks or unlocks the item. Locked items can't be sold, salvaged or deleted.
+    /// Returns true if the item is now locked.
+    pub fn toggle_armor_lock(&mut self, id: &str) -> bool {
+        match self.data.inventory.armors.get_mut(id) {
+            Some(item) => {
+                item.locked = !item.locked;
+                item.locked
+            }
+            None => false,
+        }
+    }
+
+    /// Locks or unlocks the item. Locked items can't be sold, salvaged or deleted.
+    /// Returns true if the item is now locked.
+    pub fn toggle_ring_lock(&mut self, id: &str) -> bool {
+        match self.data.inventory.rings.get_mut(id) {
+            Some(item) => {
+                item.locked = !item.locked;
+                item.locked
+            }
+            None => false,
+        }
+    }
+
     pub fn get_total_damage(&self) -> u32 {
         self.data.stats.combat_stats.damage + self.temp_stat_boosts.damage
     }
@@ -383,6 +553,18 @@ impl PlayerCharacter {
             + self.temp_stat_boosts.critical_damage_multiplier
     }
 
+    pub fn get_total_evasion(&self) -> f64 {
+        self.temp_stat_boosts.evasion.min(MAX_EVASION)
+    }
+
+    pub fn get_gold_find(&self) -> f64 {
+        self.temp_stat_boosts.gold_find
+    }
+
+    pub fn get_magic_find(&self) -> f64 {
+        self.temp_stat_boosts.magic_find
+    }
+
     pub fn get_total_health(&self) -> u32 {
         self.data.stats.combat_stats.max_health + self.temp_stat_boosts.max_health
     }
@@ -399,15 +581,30 @@ impl PlayerCharacter {
         (self.get_total_damage() as f64 * self.get_total_crit_damage_multiplier()) as u32
     }
 
-    /// Returns the amount of damage taken.
-    pub fn take_damage(&mut self, damage: u32) -> u32 {
+    /// Thorns damage is dealt back to the attacker.
+    /// Returns the amount of damage taken and the amount of thorns damage dealt.
+    pub fn take_damage(&mut self, damage: u32, attacker: &mut Enemy) -> (u32, u32) {
         let reduced_damage = self.get_reduced_damage_taken(damage);
         if reduced_damage >= self.temp_stats.current_health {
             self.temp_stats.current_health = 0;
         } else {
             self.temp_stats.current_health -= reduced_damage;
         }
-        reduced_damage
+        let thorns_damage = match self.temp_stat_boosts.thorns {
+            0 => 0,
+            thorns => attacker.take_pure_damage(thorns),
+        };
+        (reduced_damage, thorns_damage)
+    }
+
+    pub fn evades_attack(&self) -> bool {
+        is_chance_success(self.get_total_evasion())
+    }
+
+    /// Restores mana by the amount of mana regen.
+    /// Returns the amount of restored mana.
+    pub fn regenerate_mana(&mut self) -> u32 {
+        self.restore_mana(self.temp_stat_boosts.mana_regen)
     }
 
     /// Neglects the player's defense. Returns the amount of damage taken.
@@ -477,23 +674,124 @@ impl PlayerCharacter {
         self.temp_stats.current_health == 0
     }
 
+    /// Reduces damage because of an enemy skill.
+    /// Returns the amount the damage was actually reduced.
+    pub fn reduce_damage_by_enemy(&mut self, amount: u32) -> u32 {
+        let damage = self.temp_stat_boosts.damage;
+        self.temp_stat_boosts.decrease_damage(amount);
+        let reduced = damage - self.temp_stat_boosts.damage;
+        self.stat_reductions.damage += reduced;
+        reduced
+    }
+
+    /// Reduces defense because of an enemy skill.
+    /// Returns the amount the defense was actually reduced.
+    pub fn reduce_defense_by_enemy(&mut self, amount: u32) -> u32 {
+        let defense = self.temp_stat_boosts.defense;
+        self.temp_stat_boosts.decrease_defense(amount);
+        let reduced = defense - self.temp_stat_boosts.defense;
+        self.stat_reductions.defense += reduced;
+        reduced
+    }
+
+    /// Restores stats reduced by enemy skills.
+    /// Returns the amount of restored damage and defense.
+    pub fn cure_stat_reductions(&mut self) -> (u32, u32) {
+        let reductions = self.stat_reductions.clone();
+        self.temp_stat_boosts.increase_damage(reductions.damage);
+        self.temp_stat_boosts.increase_defense(reductions.defense);
+        self.stat_reductions = TemporaryStatReductions::default();
+        (reductions.damage, reductions.defense)
+    }
+
+    /// Uses a Resurrection Draught from the inventory if the player is dead and has one.
+    /// Returns text telling what the item did if it was used.
+    pub fn try_resurrect(&mut self, enemy: &mut Enemy) -> Option<(String, ItemRarity, String)> {
+        if !self.is_dead() {
+            return None;
+        }
+        let item = self
+            .data
+            .inventory
+            .consumables
+            .values()
+            .find(|item| item.effect_kind == ConsumableEffect::Resurrection)?
+            .clone();
+        Some(item.use_item(self, enemy))
+    }
+
     /// Returns enemy fight text.
-    pub fn attack_enemy(&self, enemy: &mut Enemy) -> (&str, String) {
+    pub fn attack_enemy(&mut self, enemy: &mut Enemy) -> (String, String) {
         if is_critical_hit(self.get_total_crit_hit_rate()) {
             let damage_taken = enemy.take_damage(self.get_crit_hit_damage());
             return (
-                "Player attacked!",
-                format!("Enemy took {} damage (Critical Hit)", damage_taken),
+                tr!("fight.player_attacked"),
+                tr!(
+                    "fight.enemy_took_attack_damage",
+                    damage = damage_taken,
+                    critical_hit = tr!("fight.critical_hit_suffix"),
+                    lifesteal = self.apply_lifesteal(damage_taken)
+                ),
             );
         }
         let damage_taken = enemy.take_damage(self.get_total_damage());
         return (
-            "Player attacked!",
-            format!("Enemy took {} damage", damage_taken),
+            tr!("fight.player_attacked"),
+            tr!(
+                "fight.enemy_took_attack_damage",
+                damage = damage_taken,
+                critical_hit = "",
+                lifesteal = self.apply_lifesteal(damage_taken)
+            ),
         );
     }
 
+    /// Restores health by a portion of the damage dealt.
+    /// Returns text to append to fight text or an empty string if no health was restored.
+    fn apply_lifesteal(&mut self, damage_dealt: u32) -> String {
+        let amount = (damage_dealt as f64 * self.temp_stat_boosts.lifesteal) as u32;
+        if amount == 0 {
+            return String::new();
+        }
+        let restored_health = self.restore_health(amount);
+        tr!("fight.lifesteal_suffix", health = restored_health)
+    }
+
+    /// Replaces the current companion if there is one.
+    pub fn give_companion(&mut self, companion: Companion) {
+        self.data.companion = Some(companion);
+    }
+
+    /// Lets the companion act on its turn if it isn't knocked out.
+    /// Returns companion fight text if the companion acted.
+    pub fn companion_act(&mut self, enemy: &mut Enemy) -> Option<(String, String)> {
+        let mut companion = self.data.companion.take()?;
+        let fight_text = if companion.is_knocked_out() {
+            None
+        } else {
+            Some(companion.act(self, enemy))
+        };
+        self.data.companion = Some(companion);
+        fight_text
+    }
+
+    /// Returns enemy fight text if the enemy attacked the companion instead of the player.
+    pub fn companion_take_enemy_attack(&mut self, enemy: &Enemy) -> Option<(String, String)> {
+        match &mut self.data.companion {
+            Some(companion) if companion.is_targeted() => Some(companion.take_enemy_attack(enemy)),
+            _ => None,
+        }
+    }
+
+    /// Progresses active quests from the event.
+    pub fn update_quests(&mut self, event: QuestEvent) {
+        for quest in self.data.quests.iter_mut() {
+            quest.handle_event(&event);
+        }
+    }
+
     pub fn dungeon_floor_completed(&mut self, next_floor: u32) {
+        self.update_quests(QuestEvent::FloorCompleted);
         self.data.stats.general_stats.current_dungeon_floor = next_floor;
         if next_floor > self.data.stats.general_stats.highest_dungeon_floor_achieved {
             self.data.stats.general_stats.highest_dungeon_floor_achieved = next_floor;
@@ -511,8 +809,12 @@ impl PlayerCharacter {
         self.data.inventory.clear_weapons();
         self.data.inventory.clear_armors();
         self.data.inventory.clear_rings();
+        self.data.inventory.clear_ingredients();
         self.data.inventory.ancient_ruins_keys = 0;
+        self.data.inventory.crafting_materials = 0;
         self.data.currency.gold = 0;
+        self.data.companion = None;
+        self.data.quests.clear();
         self.data.stats.general_stats.character_level = 1;
         self.data.stats.general_stats.current_dungeon_floor = 1;
         self.data.stats.general_stats.current_exp = 0;
@@ -553,45 +855,52 @@ impl PlayerCharacter {
                 let damage = (enemy.stats.max_health as f64 * 0.24) as u32;
                 let damage_taken = enemy.take_pure_damage(damage);
                 return (
-                    format!("Player used skill {}!", &skill),
-                    format!("Enemy took {} damage", damage_taken),
+                    tr!("fight.player_used_skill", skill = skill),
+                    tr!("fight.enemy_took_damage", damage = damage_taken),
                 );
             }
             CharacterSkill::Recover => {
                 let restored_health =
                     self.restore_health((0.45 * self.get_total_health() as f64) as u32);
                 return (
-                    format!("Player used skill {}!", &skill),
-                    format!("Player restored {} health points", restored_health),
+                    tr!("fight.player_used_skill", skill = skill),
+                    tr!("fight.player_restored_health", health = restored_health),
                 );
             }
             CharacterSkill::Stealth => {
                 self.temp_stat_boosts.increase_crit_damage_multiplier(0.4);
                 return (
-                    format!("Player used skill {}!", &skill),
-                    "Player's critical damage multiplier increased by 0.4".to_string(),
+                    tr!("fight.player_used_skill", skill = skill),
+                    tr!("fight.stealth", multiplier = 0.4),
                 );
             }
             CharacterSkill::BattleCry => {
                 let increased_damage = (0.3 * self.get_total_damage() as f64) as u32;
                 self.temp_stat_boosts.increase_damage(increased_damage);
                 return (
-                    format!("Player used skill {}!", &skill),
-                    format!("Player's damage increased by {}", increased_damage),
+                    tr!("fight.player_used_skill", skill = skill),
+                    tr!("fight.battle_cry", damage = increased_damage),
                 );
             }
             CharacterSkill::ArmorUp => {
                 let increased_defense = self.data.stats.general_stats.character_level;
                 self.temp_stat_boosts.increase_defense(increased_defense);
                 return (
-                    format!("Player used skill {}!", &skill),
-                    format!("Player's defense increased by {}", increased_defense),
+                    tr!("fight.player_used_skill", skill = skill),
+                    tr!("fight.armor_up", defense = increased_defense),
                 );
             }
         }
     }
 }
 
+/// Stat reductions caused by enemies. Tracked so they can be cured.
+#[derive(Clone, Default)]
+pub struct TemporaryStatReductions {
+    pub damage: u32,
+    pub defense: u32,
+}
+
 #[derive(Clone)]
 pub struct TemporaryStats {
     pub current_health: u32,
@@ -611,7 +920,7 @@ pub struct EquippedItems {
     pub ring: Option<String>,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct TemporaryStatBoosts {
     pub max_health: u32,
     pub max_mana: u32,
@@ -619,16 +928,18 @@ pub struct TemporaryStatBoosts {
     pub damage: u32,
     pub critical_damage_multiplier: f64,
     pub critical_hit_rate: f64,
+    pub lifesteal: f64,
+    pub thorns: u32,
+    pub evasion: f64,
+    pub mana_regen: u32,
+    pub exp_bonus: f64,
+    pub gold_find: f64,
+    pub magic_find: f64,
 }
 
 impl TemporaryStatBoosts {
     pub fn reset(&mut self) {
-        self.max_health = 0;
-        self.max_mana = 0;
-        self.defense = 0;
-        self.damage = 0;
-        self.critical_damage_multiplier = 0.0;
-        self.critical_hit_rate = 0.0;
+        *self = Self::default();
     }
 
     pub fn increase_damage(&mut self, amount: u32) {
@@ -705,6 +1016,16 @@ impl TemporaryStatBoosts {
                 Enchantment::Health(val) => self.increase_max_health(*val),
                 Enchantment::Defense(val) => self.increase_defense(*val),
                 Enchantment::Mana(val) => self.increase_max_mana(*val),
+                Enchantment::Lifesteal(val) => self.lifesteal += *val,
+                Enchantment::Thorns(val) => self.thorns += *val,
+                Enchantment::Evasion(val) => self.evasion += *val,
+                Enchantment::ManaRegen(val) => self.mana_regen += *val,
+                Enchantment::CritDamageMultiplier(val) => {
+                    self.increase_crit_damage_multiplier(*val)
+                }
+                Enchantment::ExpBonus(val) => self.exp_bonus += *val,
+                Enchantment::GoldFind(val) => self.gold_find += *val,
+                Enchantment::MagicFind(val) => self.magic_find += *val,
                 _ => {}
             }
         }
@@ -718,6 +1039,18 @@ impl TemporaryStatBoosts {
                 Enchantment::Health(val) => self.decrease_max_health(*val),
                 Enchantment::Defense(val) => self.decrease_defense(*val),
                 Enchantment::Mana(val) => self.decrease_max_mana(*val),
+                Enchantment::Lifesteal(val) => self.lifesteal = (self.lifesteal - *val).max(0.0),
+                Enchantment::Thorns(val) => self.thorns = self.thorns.saturating_sub(*val),
+                Enchantment::Evasion(val) => self.evasion = (self.evasion - *val).max(0.0),
+                Enchantment::ManaRegen(val) => {
+                    self.mana_regen = self.mana_regen.saturating_sub(*val)
+                }
+                Enchantment::CritDamageMultiplier(val) => {
+                    self.decrease_crit_damage_multiplier(*val)
+                }
+                Enchantment::ExpBonus(val) => self.exp_bonus = (self.exp_bonus - *val).max(0.0),
+                Enchantment::GoldFind(val) => self.gold_find = (self.gold_find - *val).max(0.0),
+                Enchantment::MagicFind(val) => self.magic_find = (self.magic_find - *val).max(0.0),
                 _ => {}
             }
         }
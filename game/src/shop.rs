@@ -1,62 +1,296 @@
+use rand::Rng;
+
 use crate::{
     character::CharacterClass,
+    companion::{generate_random_companion, Companion},
+    dungeon::RoomCoordinates,
+    inventory::get_item_rarity_rank,
     items::{
-        generate_random_armor, generate_random_ring, generate_random_weapon, get_item_display_name,
+        generate_random_armor, generate_random_ring, generate_random_weapon,
         get_item_purchase_value, get_item_sell_value, random_item_rarity, ArmorItem, CharacterItem,
-        ConsumableItem, ItemRarity, RingItem, WeaponItem, ARMOR_BASE_VALUES,
+        CharacterItemOwned, ConsumableItem, ItemRarity, RingItem, WeaponItem, ARMOR_BASE_VALUES,
         ITEM_RARITY_DROP_RATES, RING_BASE_VALUES, WEAPON_BASE_VALUES,
     },
+    rng::game_rng,
     session::PlayerCharacter,
 };
 
+/// Portion of the base price added per item level above 1.
+pub const PRICE_INCREASE_PER_ITEM_LEVEL: f64 = 0.15;
+pub const PRICE_PER_ENCHANTMENT: u32 = 50;
+pub const BASE_EQUIPMENT_OFFERS: usize = 3;
+pub const MAX_EQUIPMENT_OFFERS: usize = 8;
+/// Number of floors needed for one more equipment offer.
+pub const FLOORS_PER_EXTRA_OFFER: u32 = 2;
+pub const BUY_BACK_CAPACITY: usize = 5;
+pub const REROLL_STOCK_BASE_COST: u32 = 50;
+/// Cost added to the reroll after each reroll on the same floor.
+pub const REROLL_STOCK_COST_INCREASE: u32 = 50;
+/// Chance for the wandering merchant's guaranteed item to be Legendary instead of Epic.
+pub const MERCHANT_LEGENDARY_CHANCE: f64 = 0.2;
+
+/// Item sold to the shop that can be bought back.
+pub struct BuyBackItem {
+    pub item: CharacterItemOwned,
+    /// Gold received from selling the item. Also the buy-back price.
+    pub price: u32,
+}
+
 pub struct ShopItems {
     pub consumables: Vec<ConsumableItem>,
-    pub weapon: Option<WeaponItem>,
-    pub armor: Option<ArmorItem>,
-    pub ring: Option<RingItem>,
+    /// Weapons, armors and rings for sale. The number of offers grows with the dungeon floor.
+    pub equipment: Vec<CharacterItemOwned>,
+    /// Equipment sold on this floor. Oldest items are removed first when full.
+    pub buy_back: Vec<BuyBackItem>,
+    /// Companion for sale. Only one companion is sold per floor.
+    pub companion: Option<Companion>,
+    /// Number of times the equipment has been rerolled on this floor.
+    pub rerolls: u32,
+    pub floor: u32,
+    pub class: CharacterClass,
 }
 
 impl ShopItems {
     /// Returns true if player has enough gold and the item was bought.
-    pub fn buy_weapon(&mut self, character: &mut PlayerCharacter) -> bool {
-        if let Some(item) = &self.weapon {
-            let purchase_value = get_item_purchase_value(&item.rarity);
-            if character.data.currency.gold >= purchase_value {
-                character.give_weapon(&item);
-                character.data.currency.gold -= purchase_value;
-                self.weapon = None;
-                return true;
-            }
+    pub fn buy_equipment(&mut self, index: usize, character: &mut PlayerCharacter) -> bool {
+        let purchase_value = match self.equipment.get(index) {
+            Some(item) => get_equipment_purchase_value(item.as_character_item()),
+            None => return false,
+        };
+        if character.data.currency.gold < purchase_value {
+            return false;
         }
-        false
+        match self.equipment.remove(index) {
+            CharacterItemOwned::Weapon(item) => character.give_weapon(&item),
+            CharacterItemOwned::Armor(item) => character.give_armor(&item),
+            CharacterItemOwned::Ring(item) => character.give_ring(&item),
+            _ => return false,
+        }
+        character.data.currency.gold -= purchase_value;
+        true
     }
 
-    /// Returns true if player has enough gold and the item was bought.
-    pub fn buy_armor(&mut self, character: &mut PlayerCharacter) -> bool {
-        if let Some(item) = &self.armor {
-            let purchase_value = get_item_purchase_value(&item.rarity);
-            if character.data.currency.gold >= purchase_value {
-                character.give_armor(&item);
-                character.data.currency.gold -= purchase_value;
-                self.armor = None;
-                return true;
-            }
+    /// Returns true if player has enough gold and the item was bought back.
+    pub fn buy_back(&mut self, index: usize, character: &mut PlayerCharacter) -> bool {
+        match self.buy_back.get(index) {
+            Some(buy_back) if character.data.currency.gold >= buy_back.price => {}
+            _ => return false,
+        }
+        let buy_back = self.buy_back.remove(index);
+        match buy_back.item {
+            CharacterItemOwned::Weapon(item) => character.give_weapon(&item),
+            CharacterItemOwned::Armor(item) => character.give_armor(&item),
+            CharacterItemOwned::Ring(item) => character.give_ring(&item),
+            _ => return false,
+        }
+        character.data.currency.gold -= buy_back.price;
+        true
+    }
+
+    /// Replaces the current companion of the player.
+    /// Returns true if player has enough gold and the companion was bought.
+    pub fn buy_companion(&mut self, character: &mut PlayerCharacter) -> bool {
+        let purchase_value = match &self.companion {
+            Some(companion) => companion.get_purchase_value(),
+            None => return false,
+        };
+        if character.data.currency.gold < purchase_value {
+            return false;
+        }
+        if let Some(companion) = self.companion.take() {
+            character.give_companion(companion);
+            character.data.currency.gold -= purchase_value;
+            return true;
         }
         false
     }
 
-    /// Returns true if player has enough gold and the item was bought.
-    pub fn buy_ring(&mut self, character: &mut PlayerCharacter) -> bool {
-        if let Some(item) = &self.ring {
-            let purchase_value = get_item_purchase_value(&item.rarity);
-            if character.data.currency.gold >= purchase_value {
-                character.give_ring(&item);
-                character.data.currency.gold -= purchase_value;
-                self.ring = None;
-                return true;
+    pub fn get_reroll_cost(&self) -> u32 {
+        REROLL_STOCK_BASE_COST * self.floor.max(1) + REROLL_STOCK_COST_INCREASE * self.rerolls
+    }
+
+    /// Replaces the equipment offers with new ones.
+    /// Returns true if player has enough gold and the stock was rerolled.
+    pub fn reroll_stock(&mut self, character: &mut PlayerCharacter) -> bool {
+        let cost = self.get_reroll_cost();
+        if character.data.currency.gold < cost {
+            return false;
+        }
+        character.data.currency.gold -= cost;
+        self.equipment = generate_equipment_offers(self.floor, &self.class);
+        self.rerolls += 1;
+        true
+    }
+
+    fn add_buy_back(&mut self, item: CharacterItemOwned, price: u32) {
+        if price == 0 {
+            return;
+        }
+        if self.buy_back.len() >= BUY_BACK_CAPACITY {
+            self.buy_back.remove(0);
+        }
+        self.buy_back.push(BuyBackItem { item, price });
+    }
+
+    /// Sells the weapon and adds it to the buy-back list.
+    /// Returns the amount of gold received.
+    pub fn sell_weapon(&mut self, weapon: &WeaponItem, character: &mut PlayerCharacter) -> u32 {
+        let gold = sell_weapon(weapon, character);
+        self.add_buy_back(CharacterItemOwned::Weapon(weapon.clone()), gold);
+        gold
+    }
+
+    /// Sells the armor and adds it to the buy-back list.
+    /// Returns the amount of gold received.
+    pub fn sell_armor(&mut self, armor: &ArmorItem, character: &mut PlayerCharacter) -> u32 {
+        let gold = sell_armor(armor, character);
+        self.add_buy_back(CharacterItemOwned::Armor(armor.clone()), gold);
+        gold
+    }
+
+    /// Sells the ring and adds it to the buy-back list.
+    /// Returns the amount of gold received.
+    pub fn sell_ring(&mut self, ring: &RingItem, character: &mut PlayerCharacter) -> u32 {
+        let gold = sell_ring(ring, character);
+        self.add_buy_back(CharacterItemOwned::Ring(ring.clone()), gold);
+        gold
+    }
+
+    /// Sells all unlocked and unequipped weapons, armors and rings at or below the rarity.
+    /// The sold items are added to the buy-back list.
+    pub fn sell_junk(
+        &mut self,
+        character: &mut PlayerCharacter,
+        max_rarity: &ItemRarity,
+    ) -> JunkSale {
+        let mut sale = JunkSale {
+            items_sold: 0,
+            gold: 0,
+        };
+        for item in get_junk_items(character, max_rarity) {
+            let gold = match &item {
+                CharacterItemOwned::Weapon(item) => self.sell_weapon(item, character),
+                CharacterItemOwned::Armor(item) => self.sell_armor(item, character),
+                CharacterItemOwned::Ring(item) => self.sell_ring(item, character),
+                _ => 0,
+            };
+            if gold > 0 {
+                sale.items_sold += 1;
+                sale.gold += gold;
             }
         }
-        false
+        sale
+    }
+}
+
+/// Merchant that appears in a random room of a dungeon floor.
+pub struct WanderingMerchant {
+    pub room: RoomCoordinates,
+    pub shop_items: ShopItems,
+    /// True if the merchant is willing to trade an Epic or better item for an Ancient Ruins Key.
+    pub key_trade: bool,
+}
+
+impl WanderingMerchant {
+    /// Trades the item for an Ancient Ruins Key. The merchant only trades once.
+    /// Returns true if the item was traded.
+    pub fn trade_for_key(
+        &mut self,
+        item: &CharacterItemOwned,
+        character: &mut PlayerCharacter,
+    ) -> bool {
+        if !self.key_trade {
+            return false;
+        }
+        let traded = match item {
+            CharacterItemOwned::Weapon(item) => character.delete_weapon(&item.id),
+            CharacterItemOwned::Armor(item) => character.delete_armor(&item.id),
+            CharacterItemOwned::Ring(item) => character.delete_ring(&item.id),
+            _ => false,
+        };
+        if traded {
+            character.give_ancient_ruins_key(1);
+            self.key_trade = false;
+        }
+        traded
+    }
+}
+
+fn is_key_trade_item(rarity: &ItemRarity, locked: bool, equipped: bool) -> bool {
+    !locked && !equipped && get_item_rarity_rank(rarity) >= get_item_rarity_rank(&ItemRarity::Epic)
+}
+
+/// Returns the unlocked and unequipped Epic or better weapons, armors and rings
+/// that can be traded for an Ancient Ruins Key.
+pub fn get_key_trade_items(character: &PlayerCharacter) -> Vec<CharacterItemOwned> {
+    let inventory = &character.data.inventory;
+    let mut items = Vec::new();
+    for item in inventory.weapons.values() {
+        if is_key_trade_item(&item.rarity, item.locked, item.is_equipped(character)) {
+            items.push(CharacterItemOwned::Weapon(item.clone()));
+        }
+    }
+    for item in inventory.armors.values() {
+        if is_key_trade_item(&item.rarity, item.locked, item.is_equipped(character)) {
+            items.push(CharacterItemOwned::Armor(item.clone()));
+        }
+    }
+    for item in inventory.rings.values() {
+        if is_key_trade_item(&item.rarity, item.locked, item.is_equipped(character)) {
+            items.push(CharacterItemOwned::Ring(item.clone()));
+        }
+    }
+    items.sort_by_cached_key(|item| item.get_receive_order_key());
+    items
+}
+
+/// Returns the purchase value of a weapon, armor or ring in gold.
+/// The value grows with the item level and the number of enchantments.
+pub fn get_equipment_purchase_value(item: CharacterItem) -> u32 {
+    let (rarity, level, enchantments) = match item {
+        CharacterItem::Weapon(item) => (&item.rarity, item.level, item.enchantments.len()),
+        CharacterItem::Armor(item) => (&item.rarity, item.level, item.enchantments.len()),
+        CharacterItem::Ring(item) => (&item.rarity, item.level, item.enchantments.len()),
+        CharacterItem::Consumable(item) => return get_item_purchase_value(&item.rarity),
+        _ => return 0,
+    };
+    let level_multiplier = 1.0 + PRICE_INCREASE_PER_ITEM_LEVEL * level.saturating_sub(1) as f64;
+    (get_item_purchase_value(rarity) as f64 * level_multiplier) as u32
+        + PRICE_PER_ENCHANTMENT * enchantments as u32
+}
+
+pub fn get_equipment_offer_count(floor: u32) -> usize {
+    (BASE_EQUIPMENT_OFFERS + (floor / FLOORS_PER_EXTRA_OFFER) as usize).min(MAX_EQUIPMENT_OFFERS)
+}
+
+/// Generates one weapon, armor and ring and random equipment for the rest of the offers.
+pub fn generate_equipment_offers(floor: u32, class: &CharacterClass) -> Vec<CharacterItemOwned> {
+    let mut rng = game_rng();
+    let mut offers = Vec::new();
+    for i in 0..get_equipment_offer_count(floor) {
+        let kind = if i < 3 { i } else { rng.gen_range(0..3) };
+        let rarity = random_item_rarity(&ITEM_RARITY_DROP_RATES, 0.0);
+        offers.push(generate_equipment(kind, rarity, floor, class));
+    }
+    offers
+}
+
+/// Generates a weapon if kind is 0, an armor if kind is 1 and otherwise a ring.
+pub fn generate_equipment(
+    kind: usize,
+    rarity: ItemRarity,
+    floor: u32,
+    class: &CharacterClass,
+) -> CharacterItemOwned {
+    match kind {
+        0 => CharacterItemOwned::Weapon(generate_random_weapon(
+            rarity,
+            WEAPON_BASE_VALUES,
+            floor,
+            class,
+        )),
+        1 => CharacterItemOwned::Armor(generate_random_armor(rarity, ARMOR_BASE_VALUES, floor)),
+        _ => CharacterItemOwned::Ring(generate_random_ring(rarity, RING_BASE_VALUES, floor)),
     }
 }
 
@@ -74,22 +308,42 @@ pub fn generate_shop_items(floor: u32, class: &CharacterClass) -> ShopItems {
             ConsumableItem::new_mana_potion(ItemRarity::Epic),
             ConsumableItem::new_mana_potion(ItemRarity::Legendary),
         ],
-        weapon: Some(generate_random_weapon(
-            random_item_rarity(&ITEM_RARITY_DROP_RATES),
-            WEAPON_BASE_VALUES,
+        equipment: generate_equipment_offers(floor, class),
+        buy_back: Vec::new(),
+        companion: Some(generate_random_companion(floor)),
+        rerolls: 0,
+        floor,
+        class: class.clone(),
+    }
+}
+
+/// Wandering merchants sell rare consumables and one Epic or Legendary item.
+pub fn generate_merchant_items(floor: u32, class: &CharacterClass) -> ShopItems {
+    let mut rng = game_rng();
+    let rarity = if rng.gen_bool(MERCHANT_LEGENDARY_CHANCE) {
+        ItemRarity::Legendary
+    } else {
+        ItemRarity::Epic
+    };
+    ShopItems {
+        consumables: vec![
+            ConsumableItem::new_health_potion(ItemRarity::Legendary),
+            ConsumableItem::new_mana_potion(ItemRarity::Legendary),
+            ConsumableItem::new_elixir_of_strength(),
+            ConsumableItem::new_fire_bomb(),
+            ConsumableItem::new_resurrection_draught(),
+        ],
+        equipment: vec![generate_equipment(
+            rng.gen_range(0..3),
+            rarity,
             floor,
             class,
-        )),
-        armor: Some(generate_random_armor(
-            random_item_rarity(&ITEM_RARITY_DROP_RATES),
-            ARMOR_BASE_VALUES,
-            floor,
-        )),
-        ring: Some(generate_random_ring(
-            random_item_rarity(&ITEM_RARITY_DROP_RATES),
-            RING_BASE_VALUES,
-            floor,
-        )),
+        )],
+        buy_back: Vec::new(),
+        companion: None,
+        rerolls: 0,
+        floor,
+        class: class.clone(),
     }
 }
 
@@ -110,7 +364,7 @@ pub fn sell_consumable(
     amount: u32,
     character: &mut PlayerCharacter,
 ) -> u32 {
-    let name = get_item_display_name(CharacterItem::Consumable(consumable));
+    let name = consumable.get_inventory_key();
     let sell_value = get_item_sell_value(&consumable.rarity);
     if consumable.amount_in_inventory > amount {
         if character.decrease_consumable_inventory_amount(&name, amount) {
@@ -155,3 +409,141 @@ pub fn sell_ring(ring: &RingItem, character: &mut PlayerCharacter) -> u32 {
     }
     0
 }
+
+/// Result of selling junk items.
+pub struct JunkSale {
+    pub items_sold: u32,
+    pub gold: u32,
+}
+
+fn is_junk(rarity: &ItemRarity, locked: bool, equipped: bool, max_rarity: &ItemRarity) -> bool {
+    !locked && !equipped && get_item_rarity_rank(rarity) <= get_item_rarity_rank(max_rarity)
+}
+
+/// Returns the unlocked and unequipped weapons, armors and rings
+/// at or below the rarity that would be sold as junk in the order they were received.
+pub fn get_junk_items(
+    character: &PlayerCharacter,
+    max_rarity: &ItemRarity,
+) -> Vec<CharacterItemOwned> {
+    let inventory = &character.data.inventory;
+    let mut items = Vec::new();
+    for item in inventory.weapons.values() {
+        if is_junk(
+            &item.rarity,
+            item.locked,
+            item.is_equipped(character),
+            max_rarity,
+        ) {
+            items.push(CharacterItemOwned::Weapon(item.clone()));
+        }
+    }
+    for item in inventory.armors.values() {
+        if is_junk(
+            &item.rarity,
+            item.locked,
+            item.is_equipped(character),
+            max_rarity,
+        ) {
+            items.push(CharacterItemOwned::Armor(item.clone()));
+        }
+    }
+    for item in inventory.rings.values() {
+        if is_junk(
+            &item.rarity,
+            item.locked,
+            item.is_equipped(character),
+            max_rarity,
+        ) {
+            items.push(CharacterItemOwned::Ring(item.clone()));
+        }
+    }
+    items.sort_by_cached_key(|item| item.get_receive_order_key());
+    items
+}
+
+/// Returns the amount of gold received from selling the junk items.
+pub fn get_junk_sell_value(items: &[CharacterItemOwned]) -> u32 {
+    items
+        .iter()
+        .map(|item| match item {
+            CharacterItemOwned::Weapon(item) => get_item_sell_value(&item.rarity),
+            CharacterItemOwned::Armor(item) => get_item_sell_value(&item.rarity),
+            CharacterItemOwned::Ring(item) => get_item_sell_value(&item.rarity),
+            _ => 0,
+        })
+        .sum()
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        character::CharacterClass,
+        items::{ArmorItemStats, ITEM_ARMOR},
+        test_util::{test_character, test_ring},
+    };
+
+    fn get_ids(items: &[CharacterItemOwned]) -> Vec<String> {
+        items
+            .iter()
+            .map(|item| match item {
+                CharacterItemOwned::Weapon(item) => item.id.clone(),
+                CharacterItemOwned::Armor(item) => item.id.clone(),
+                CharacterItemOwned::Ring(item) => item.id.clone(),
+                _ => String::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn junk_skips_locked_equipped_and_rarer_items() {
+        let mut character = test_character(CharacterClass::Knight);
+        let common_ring = test_ring(ItemRarity::Common, 1, 10);
+        let rare_ring = test_ring(ItemRarity::Rare, 1, 10);
+        let mut locked_ring = test_ring(ItemRarity::Common, 1, 10);
+        locked_ring.locked = true;
+        let equipped_ring = test_ring(ItemRarity::Uncommon, 1, 10);
+        let armor = ArmorItem::new(
+            ITEM_ARMOR,
+            1,
+            ItemRarity::Uncommon,
+            ArmorItemStats {
+                health: 20,
+                defense: 5,
+            },
+            Vec::new(),
+        );
+        for ring in [&common_ring, &rare_ring, &locked_ring, &equipped_ring] {
+            character.give_ring(ring);
+        }
+        character.equip_ring(&equipped_ring.id);
+        character.give_armor(&armor);
+
+        // the equipped starter weapon is not junk either
+        let junk = get_junk_items(&character, &ItemRarity::Uncommon);
+        let mut ids = get_ids(&junk);
+        ids.sort();
+        let mut expected = vec![common_ring.id.clone(), armor.id.clone()];
+        expected.sort();
+        assert_eq!(ids, expected);
+        assert_eq!(
+            get_junk_sell_value(&junk),
+            get_item_sell_value(&ItemRarity::Common) + get_item_sell_value(&ItemRarity::Uncommon)
+        );
+    }
+
+    #[test]
+    fn junk_is_listed_in_receive_order() {
+        let mut character = test_character(CharacterClass::Knight);
+        let rings: Vec<RingItem> = (0..5)
+            .map(|_| test_ring(ItemRarity::Common, 1, 10))
+            .collect();
+        for ring in &rings {
+            character.give_ring(ring);
+        }
+
+        let junk = get_junk_items(&character, &ItemRarity::Common);
+        let expected: Vec<String> = rings.iter().map(|ring| ring.id.clone()).collect();
+        assert_eq!(get_ids(&junk), expected);
+    }
+}
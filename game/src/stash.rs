@@ -0,0 +1,193 @@
+use std::cmp::Reverse;
+
+use crate::{
+    game_data::Stash,
+    inventory::get_item_rarity_rank,
+    items::{get_item_display_name, CharacterItemOwned},
+    session::PlayerCharacter,
+};
+
+/// Moves the weapon from the inventory to the stash. Equipped weapons are unequipped first.
+/// Returns true if the weapon was deposited.
+pub fn deposit_weapon(stash: &mut Stash, character: &mut PlayerCharacter, id: &str) -> bool {
+    if stash.is_full() {
+        return false;
+    }
+    if character.equipped_items.weapon.as_deref() == Some(id) {
+        character.unequip_weapon();
+    }
+    match character.data.inventory.weapons.remove(id) {
+        Some(item) => {
+            stash.weapons.insert(item.id.clone(), item);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Moves the armor from the inventory to the stash. Equipped armor is unequipped first.
+/// Returns true if the armor was deposited.
+pub fn deposit_armor(stash: &mut Stash, character: &mut PlayerCharacter, id: &str) -> bool {
+    if stash.is_full() {
+        return false;
+    }
+    if character.equipped_items.armor.as_deref() == Some(id) {
+        character.unequip_armor();
+    }
+    match character.data.inventory.armors.remove(id) {
+        Some(item) => {
+            stash.armors.insert(item.id.clone(), item);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Moves the ring from the inventory to the stash. Equipped rings are unequipped first.
+/// Returns true if the ring was deposited.
+pub fn deposit_ring(stash: &mut Stash, character: &mut PlayerCharacter, id: &str) -> bool {
+    if stash.is_full() {
+        return false;
+    }
+    if character.equipped_items.ring.as_deref() == Some(id) {
+        character.unequip_ring();
+    }
+    match character.data.inventory.rings.remove(id) {
+        Some(item) => {
+            stash.rings.insert(item.id.clone(), item);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Moves the weapon from the stash to the inventory.
+/// Returns true if the weapon was withdrawn.
+pub fn withdraw_weapon(stash: &mut Stash, character: &mut PlayerCharacter, id: &str) -> bool {
+    match stash.weapons.remove(id) {
+        Some(item) => {
+            character.give_weapon(&item);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Moves the armor from the stash to the inventory.
+/// Returns true if the armor was withdrawn.
+pub fn withdraw_armor(stash: &mut Stash, character: &mut PlayerCharacter, id: &str) -> bool {
+    match stash.armors.remove(id) {
+        Some(item) => {
+            character.give_armor(&item);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Moves the ring from the stash to the inventory.
+/// Returns true if the ring was withdrawn.
+pub fn withdraw_ring(stash: &mut Stash, character: &mut PlayerCharacter, id: &str) -> bool {
+    match stash.rings.remove(id) {
+        Some(item) => {
+            character.give_ring(&item);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Returns the items in the stash sorted by rarity and name.
+pub fn get_stash_items(stash: &Stash) -> Vec<CharacterItemOwned> {
+    let mut items: Vec<CharacterItemOwned> = Vec::new();
+    for item in stash.weapons.values() {
+        items.push(CharacterItemOwned::Weapon(item.clone()));
+    }
+    for item in stash.armors.values() {
+        items.push(CharacterItemOwned::Armor(item.clone()));
+    }
+    for item in stash.rings.values() {
+        items.push(CharacterItemOwned::Ring(item.clone()));
+    }
+    sort_items(&mut items);
+    items
+}
+
+/// Returns the weapons, armors and rings in the inventory sorted by rarity and name.
+pub fn get_depositable_items(character: &PlayerCharacter) -> Vec<CharacterItemOwned> {
+    let inventory = &character.data.inventory;
+    let mut items: Vec<CharacterItemOwned> = Vec::new();
+    for item in inventory.weapons.values() {
+        items.push(CharacterItemOwned::Weapon(item.clone()));
+    }
+    for item in inventory.armors.values() {
+        items.push(CharacterItemOwned::Armor(item.clone()));
+    }
+    for item in inventory.rings.values() {
+        items.push(CharacterItemOwned::Ring(item.clone()));
+    }
+    sort_items(&mut items);
+    items
+}
+
+fn sort_items(items: &mut [CharacterItemOwned]) {
+    items.sort_by_cached_key(|item| {
+        let (rarity, id) = match item {
+            CharacterItemOwned::Weapon(item) => (&item.rarity, &item.id),
+            CharacterItemOwned::Armor(item) => (&item.rarity, &item.id),
+            CharacterItemOwned::Ring(item) => (&item.rarity, &item.id),
+            _ => return (Reverse(0), String::new(), String::new()),
+        };
+        (
+            Reverse(get_item_rarity_rank(rarity)),
+            get_item_display_name(item.as_character_item()),
+            id.clone(),
+        )
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        character::CharacterClass,
+        game_data::STASH_CAPACITY,
+        items::ItemRarity,
+        test_util::{test_character, test_ring},
+    };
+
+    #[test]
+    fn deposit_stops_at_capacity() {
+        let mut character = test_character(CharacterClass::Cleric);
+        let mut stash = Stash::default();
+        for _ in 0..STASH_CAPACITY {
+            let ring = test_ring(ItemRarity::Common, 1, 10);
+            character.give_ring(&ring);
+            assert!(deposit_ring(&mut stash, &mut character, &ring.id));
+        }
+        assert!(stash.is_full());
+
+        let ring = test_ring(ItemRarity::Common, 1, 10);
+        character.give_ring(&ring);
+        assert!(!deposit_ring(&mut stash, &mut character, &ring.id));
+        assert!(character.data.inventory.rings.contains_key(&ring.id));
+        assert_eq!(stash.len(), STASH_CAPACITY);
+
+        let stashed_id = stash.rings.keys().next().unwrap().clone();
+        assert!(withdraw_ring(&mut stash, &mut character, &stashed_id));
+        assert!(deposit_ring(&mut stash, &mut character, &ring.id));
+    }
+
+    #[test]
+    fn deposit_unequips_item() {
+        let mut character = test_character(CharacterClass::Cleric);
+        let mut stash = Stash::default();
+        let id = character.equipped_items.weapon.clone().unwrap();
+        let damage = character.get_total_damage();
+
+        assert!(deposit_weapon(&mut stash, &mut character, &id));
+        assert_eq!(character.equipped_items.weapon, None);
+        assert!(character.get_total_damage() < damage);
+        assert!(stash.weapons.contains_key(&id));
+    }
+}
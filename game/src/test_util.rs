@@ -0,0 +1,44 @@
+//! Helpers shared by the unit tests.
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+use crate::{
+    character::{create_player_character, CharacterClass},
+    config::GameConfig,
+    frontend::{set_frontend, MemoryFrames, MemoryFrontend},
+    items::{ItemRarity, RingItem, RingItemStats, ITEM_RING},
+    session::PlayerCharacter,
+};
+
+/// New level 1 character with the class's starter weapon equipped.
+pub fn test_character(class: CharacterClass) -> PlayerCharacter {
+    create_player_character("Hero", class)
+}
+
+/// Ring without enchantments.
+pub fn test_ring(rarity: ItemRarity, level: u32, mana: u32) -> RingItem {
+    RingItem::new(ITEM_RING, level, rarity, RingItemStats { mana }, Vec::new())
+}
+
+/// Config of a normal session without flags.
+pub fn test_config() -> GameConfig {
+    GameConfig {
+        dev_mode: false,
+        record_file: None,
+        replay_file: None,
+        bot_mode: false,
+        locale: None,
+        combat_log_file: false,
+    }
+}
+
+/// Key press without modifiers.
+pub fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+/// Uses the memory frontend on the current thread and returns its frames.
+pub fn install_frontend(frontend: MemoryFrontend) -> MemoryFrames {
+    let frames = frontend.frames();
+    set_frontend(Box::new(frontend));
+    frames
+}
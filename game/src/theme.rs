@@ -0,0 +1,269 @@
+use crossterm::style::Color;
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::BTreeMap, env, fs, io, path::PathBuf};
+
+use crate::{
+    game_data::{get_config_subdir, SUBDIR_NAME},
+    items::ItemRarity,
+};
+
+const THEME_FILE_NAME: &str = "theme.json";
+
+thread_local! {
+    static THEME: RefCell<Theme> = RefCell::new(Theme::from_preset(ThemePreset::Dark));
+}
+
+/// Part of the UI that has its own color in themes.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeColor {
+    Background,
+    Text,
+    /// Increases and completed things.
+    Positive,
+    /// Decreases.
+    Negative,
+    Health,
+    Mana,
+    /// Empty part of health and mana bars.
+    BarEmpty,
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+    Mythical,
+}
+
+impl ThemeColor {
+    pub fn from_rarity(rarity: &ItemRarity) -> Self {
+        match rarity {
+            ItemRarity::Common => Self::Common,
+            ItemRarity::Uncommon => Self::Uncommon,
+            ItemRarity::Rare => Self::Rare,
+            ItemRarity::Epic => Self::Epic,
+            ItemRarity::Legendary => Self::Legendary,
+            ItemRarity::Mythical => Self::Mythical,
+            ItemRarity::Unknown => Self::Text,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemePreset {
+    /// Black background.
+    #[default]
+    Dark,
+    /// Terminal's own background with darker colors.
+    Light,
+    /// Bright colors on black background with rarity tags.
+    HighContrast,
+    /// Colors that can be told apart with color blindness, with rarity tags.
+    Colorblind,
+}
+
+/// Theme file in the config directory.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ThemeFile {
+    pub preset: ThemePreset,
+    /// Colors that replace the preset's colors.
+    pub colors: BTreeMap<ThemeColor, String>,
+    /// Overrides whether the preset shows rarity tags.
+    pub rarity_tags: Option<bool>,
+}
+
+impl ThemeFile {
+    /// Builds the theme from the preset and the colors.
+    /// Fails if a color is invalid.
+    pub fn to_theme(&self) -> io::Result<Theme> {
+        let mut theme = Theme::from_preset(self.preset);
+        for (theme_color, name) in &self.colors {
+            let color = parse_color(name).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid color \"{}\" in {}", name, THEME_FILE_NAME),
+                )
+            })?;
+            theme.colors.insert(*theme_color, color);
+        }
+        if let Some(rarity_tags) = self.rarity_tags {
+            theme.rarity_tags = rarity_tags;
+        }
+        Ok(theme)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Theme {
+    colors: BTreeMap<ThemeColor, Color>,
+    /// False if colors are disabled.
+    pub use_colors: bool,
+    /// Shows item rarity as a tag before item names, e.g. `[EPIC]`.
+    pub rarity_tags: bool,
+}
+
+impl Theme {
+    pub fn from_preset(preset: ThemePreset) -> Self {
+        let rgb = |r, g, b| Color::Rgb { r, g, b };
+        let (colors, rarity_tags) = match preset {
+            ThemePreset::Dark => (
+                [
+                    (ThemeColor::Background, Color::Black),
+                    (ThemeColor::Text, Color::Reset),
+                    (ThemeColor::Positive, Color::Green),
+                    (ThemeColor::Negative, Color::Red),
+                    (ThemeColor::Health, Color::Red),
+                    (ThemeColor::Mana, Color::DarkCyan),
+                    (ThemeColor::BarEmpty, rgb(50, 50, 50)),
+                    (ThemeColor::Common, Color::White),
+                    (ThemeColor::Uncommon, Color::Green),
+                    (ThemeColor::Rare, Color::Blue),
+                    (ThemeColor::Epic, Color::DarkCyan),
+                    (ThemeColor::Legendary, Color::Yellow),
+                    (ThemeColor::Mythical, Color::Red),
+                ],
+                false,
+            ),
+            ThemePreset::Light => (
+                [
+                    (ThemeColor::Background, Color::Reset),
+                    (ThemeColor::Text, Color::Reset),
+                    (ThemeColor::Positive, Color::DarkGreen),
+                    (ThemeColor::Negative, Color::DarkRed),
+                    (ThemeColor::Health, Color::DarkRed),
+                    (ThemeColor::Mana, Color::DarkBlue),
+                    (ThemeColor::BarEmpty, Color::Grey),
+                    (ThemeColor::Common, Color::Reset),
+                    (ThemeColor::Uncommon, Color::DarkGreen),
+                    (ThemeColor::Rare, Color::DarkBlue),
+                    (ThemeColor::Epic, Color::DarkMagenta),
+                    (ThemeColor::Legendary, Color::DarkYellow),
+                    (ThemeColor::Mythical, Color::DarkRed),
+                ],
+                false,
+            ),
+            ThemePreset::HighContrast => (
+                [
+                    (ThemeColor::Background, Color::Black),
+                    (ThemeColor::Text, Color::White),
+                    (ThemeColor::Positive, Color::Green),
+                    (ThemeColor::Negative, Color::Red),
+                    (ThemeColor::Health, Color::Red),
+                    (ThemeColor::Mana, Color::Cyan),
+                    (ThemeColor::BarEmpty, Color::DarkGrey),
+                    (ThemeColor::Common, Color::White),
+                    (ThemeColor::Uncommon, Color::Green),
+                    (ThemeColor::Rare, Color::Cyan),
+                    (ThemeColor::Epic, Color::Magenta),
+                    (ThemeColor::Legendary, Color::Yellow),
+                    (ThemeColor::Mythical, Color::Red),
+                ],
+                true,
+            ),
+            // Okabe-Ito palette
+            ThemePreset::Colorblind => (
+                [
+                    (ThemeColor::Background, Color::Black),
+                    (ThemeColor::Text, Color::Reset),
+                    (ThemeColor::Positive, rgb(86, 180, 233)),
+                    (ThemeColor::Negative, rgb(230, 159, 0)),
+                    (ThemeColor::Health, rgb(213, 94, 0)),
+                    (ThemeColor::Mana, rgb(86, 180, 233)),
+                    (ThemeColor::BarEmpty, Color::DarkGrey),
+                    (ThemeColor::Common, Color::White),
+                    (ThemeColor::Uncommon, rgb(86, 180, 233)),
+                    (ThemeColor::Rare, rgb(0, 114, 178)),
+                    (ThemeColor::Epic, rgb(204, 121, 167)),
+                    (ThemeColor::Legendary, rgb(240, 228, 66)),
+                    (ThemeColor::Mythical, rgb(213, 94, 0)),
+                ],
+                true,
+            ),
+        };
+
+        Self {
+            colors: BTreeMap::from(colors),
+            use_colors: true,
+            rarity_tags,
+        }
+    }
+
+    /// Returns None if colors are disabled.
+    pub fn get_color(&self, theme_color: ThemeColor) -> Option<Color> {
+        if !self.use_colors {
+            return None;
+        }
+        self.colors.get(&theme_color).copied()
+    }
+}
+
+/// Sets the theme used to draw the UI.
+pub fn set_theme(theme: Theme) {
+    THEME.with(|current| *current.borrow_mut() = theme);
+}
+
+/// Color in the current theme. Returns None if colors are disabled.
+pub fn get_theme_color(theme_color: ThemeColor) -> Option<Color> {
+    THEME.with(|theme| theme.borrow().get_color(theme_color))
+}
+
+pub fn uses_colors() -> bool {
+    THEME.with(|theme| theme.borrow().use_colors)
+}
+
+/// Returns the rarity tag, e.g. `[EPIC]`, if the current theme shows rarity tags.
+pub fn get_rarity_tag(rarity: &ItemRarity) -> Option<String> {
+    if matches!(rarity, ItemRarity::Unknown) || !THEME.with(|theme| theme.borrow().rarity_tags) {
+        return None;
+    }
+    Some(format!("[{}]", rarity.to_string().to_uppercase()))
+}
+
+/// Returns true if the `NO_COLOR` environment variable is set to a non-empty value.
+pub fn is_no_color_set() -> bool {
+    env::var("NO_COLOR").is_ok_and(|value| !value.is_empty())
+}
+
+/// Parses a color name, e.g. `dark_cyan`, `reset` or `#ff8800`.
+pub fn parse_color(name: &str) -> Option<Color> {
+    if let Some(hex) = name.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let component = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        return Some(Color::Rgb {
+            r: component(0)?,
+            g: component(2)?,
+            b: component(4)?,
+        });
+    }
+    Color::try_from(name).ok()
+}
+
+pub fn get_theme_file_path() -> io::Result<PathBuf> {
+    Ok(get_config_subdir(SUBDIR_NAME)?.join(THEME_FILE_NAME))
+}
+
+/// Loads the theme file from the config directory.
+/// Returns the default theme if the file doesn't exist.
+pub fn load_theme_file() -> io::Result<ThemeFile> {
+    let path = get_theme_file_path()?;
+    if !path.try_exists()? {
+        return Ok(ThemeFile::default());
+    }
+    let theme_file = serde_json::from_str(&fs::read_to_string(path)?)?;
+    Ok(theme_file)
+}
+
+/// Loads the theme from the theme file.
+/// Colors are disabled and rarity tags shown if `NO_COLOR` is set.
+pub fn load_theme() -> io::Result<Theme> {
+    let mut theme = load_theme_file()?.to_theme()?;
+    if is_no_color_set() {
+        theme.use_colors = false;
+        theme.rarity_tags = true;
+    }
+    Ok(theme)
+}
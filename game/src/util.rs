@@ -1,17 +1,20 @@
 use chrono::{TimeZone, Utc};
-use crossterm::{
-    execute,
-    style::{Color, SetBackgroundColor, SetForegroundColor},
-};
-use rand::{thread_rng, Rng};
+use crossterm::style::Color;
+use rand::Rng;
 use std::{
-    env,
+    env, fmt,
     io::{self},
     thread,
     time::Duration,
 };
 
-use crate::items::ItemRarity;
+use crate::{
+    frontend::{set_background_color, set_foreground_color},
+    items::ItemRarity,
+    print_text,
+    rng::game_rng,
+    theme::{get_rarity_tag, get_theme_color, ThemeColor},
+};
 
 pub fn extract_first_word(s: &str) -> &str {
     s.split_whitespace().next().unwrap_or("")
@@ -40,31 +43,39 @@ pub fn shift_index_back(index: usize) -> usize {
 /// Returns true if the passed rate was rolled.
 /// Rate should be between 0 and 1.
 pub fn is_chance_success(rate: f64) -> bool {
-    thread_rng().gen_range(0.0..1.0) < rate
+    game_rng().gen_range(0.0..1.0) < rate
 }
 
 pub fn wait(millis: u64) {
     thread::sleep(Duration::from_millis(millis))
 }
 
+/// Sets the text color from the theme. Does nothing if colors are disabled.
+pub fn set_text_color(theme_color: ThemeColor) -> io::Result<()> {
+    match get_theme_color(theme_color) {
+        Some(color) => set_foreground_color(color),
+        None => Ok(()),
+    }
+}
+
 pub fn set_rarity_text_color(rarity: &ItemRarity) -> io::Result<()> {
-    let mut stdout = io::stdout();
-    match rarity {
-        ItemRarity::Common => execute!(stdout, SetForegroundColor(Color::White))?,
-        ItemRarity::Uncommon => execute!(stdout, SetForegroundColor(Color::Green))?,
-        ItemRarity::Rare => execute!(stdout, SetForegroundColor(Color::Blue))?,
-        ItemRarity::Epic => execute!(stdout, SetForegroundColor(Color::DarkCyan))?,
-        ItemRarity::Legendary => execute!(stdout, SetForegroundColor(Color::Yellow))?,
-        ItemRarity::Mythical => execute!(stdout, SetForegroundColor(Color::Red))?,
-        _ => execute!(stdout, SetForegroundColor(Color::Reset))?,
+    set_text_color(ThemeColor::from_rarity(rarity))
+}
+
+/// Sets the rarity text color and prints the text.
+/// The text starts with the rarity tag if the theme shows rarity tags.
+pub fn print_rarity_text(rarity: &ItemRarity, text: impl fmt::Display) -> io::Result<()> {
+    set_rarity_text_color(rarity)?;
+    match get_rarity_tag(rarity) {
+        Some(tag) => print_text!("{} {}", tag, text),
+        None => print_text!("{}", text),
     }
-    Ok(())
 }
 
 pub fn reset_text_color() -> io::Result<()> {
-    execute!(io::stdout(), SetForegroundColor(Color::Reset))
+    set_text_color(ThemeColor::Text)
 }
 
 pub fn reset_background_color() -> io::Result<()> {
-    execute!(io::stdout(), SetBackgroundColor(Color::Reset))
+    set_background_color(Color::Reset)
 }